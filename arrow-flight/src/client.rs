@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A client of Arrow Flight services that sends and receives `RecordBatch`es.
+
+use std::convert::TryInto;
+
+use arrow2::{
+    datatypes::Schema, error::Result, io::ipc::write::common::IpcWriteOptions,
+    record_batch::RecordBatch,
+};
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use futures::stream::{self, Stream, StreamExt};
+use tonic::codec::Streaming;
+use tonic::transport::{Channel, Endpoint};
+use tonic::Status;
+
+use crate::flight_service_client::FlightServiceClient;
+use crate::stream::{flight_data_stream, FlightRecordBatchStream};
+use crate::{Criteria, FlightData, FlightDescriptor, FlightInfo, PutResult, Ticket};
+
+type StdError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A client of an Arrow Flight service that converts the `FlightData` sent and received
+/// from and to `RecordBatch`es, see [`FlightClient::get`] and [`FlightClient::put`].
+#[derive(Debug, Clone)]
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+}
+
+impl FlightClient {
+    /// Connects to the Flight service at `dst`, e.g. `"http://localhost:50051"`
+    pub async fn connect<D>(dst: D) -> std::result::Result<Self, tonic::transport::Error>
+    where
+        D: TryInto<Endpoint>,
+        D::Error: Into<StdError>,
+    {
+        Ok(Self::new(FlightServiceClient::connect(dst).await?))
+    }
+
+    /// Returns a new [`FlightClient`] wrapping `inner`
+    pub fn new(inner: FlightServiceClient<Channel>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying generated client, e.g. to call `do_action`
+    pub fn inner_mut(&mut self) -> &mut FlightServiceClient<Channel> {
+        &mut self.inner
+    }
+
+    /// Returns the [`FlightInfo`] of the flight described by `descriptor`
+    pub async fn get_flight_info(
+        &mut self,
+        descriptor: FlightDescriptor,
+    ) -> std::result::Result<FlightInfo, Status> {
+        Ok(self.inner.get_flight_info(descriptor).await?.into_inner())
+    }
+
+    /// Returns the [`FlightInfo`] of the flights matching `criteria`
+    pub async fn list_flights(
+        &mut self,
+        criteria: Criteria,
+    ) -> std::result::Result<Streaming<FlightInfo>, Status> {
+        Ok(self.inner.list_flights(criteria).await?.into_inner())
+    }
+
+    /// Returns the stream of `RecordBatch`es of `ticket`. Its schema is available via
+    /// [`FlightRecordBatchStream::schema`].
+    pub async fn get(
+        &mut self,
+        ticket: Ticket,
+    ) -> std::result::Result<FlightRecordBatchStream<Streaming<FlightData>>, Status> {
+        let response = self.inner.do_get(ticket).await?.into_inner();
+        FlightRecordBatchStream::try_new(response).await
+    }
+
+    /// Uploads `batches` of `schema` to the flight described by `descriptor`, returning the
+    /// responses of the service once the upload completes. The upload is cancelled at the
+    /// first error of `batches`, which is then returned.
+    pub async fn put<S>(
+        &mut self,
+        descriptor: FlightDescriptor,
+        schema: &Schema,
+        batches: S,
+    ) -> std::result::Result<Vec<PutResult>, Status>
+    where
+        S: Stream<Item = Result<RecordBatch>> + Send + Sync + 'static,
+    {
+        let (sender, error) = oneshot::channel();
+
+        // the descriptor is sent with the first message, the schema. On error, the stream
+        // stays pending so that the service does not receive a (truncated) complete upload.
+        let state = (Some(descriptor), Some(sender));
+        let data = flight_data_stream(schema, batches, IpcWriteOptions::default())
+            .scan(state, |(descriptor, sender), data| {
+                futures::future::ready(Some(match data {
+                    Ok(mut data) => {
+                        data.flight_descriptor = descriptor.take();
+                        Either::Left(stream::once(futures::future::ready(data)))
+                    }
+                    Err(status) => {
+                        if let Some(sender) = sender.take() {
+                            let _ = sender.send(status);
+                        }
+                        Either::Right(stream::pending())
+                    }
+                }))
+            })
+            .flatten();
+
+        let inner = &mut self.inner;
+        let upload = async move {
+            let mut response = inner.do_put(data).await?.into_inner();
+            let mut results = vec![];
+            while let Some(result) = response.message().await? {
+                results.push(result);
+            }
+            Ok(results)
+        };
+        futures::pin_mut!(upload);
+
+        match future::select(upload, error).await {
+            Either::Left((results, _)) => results,
+            // dropping the upload cancels the request
+            Either::Right((Ok(status), _)) => Err(status),
+            Either::Right((Err(_), upload)) => upload.await,
+        }
+    }
+}
@@ -17,4 +17,7 @@
 
 include!("arrow.flight.protocol.rs");
 
+pub mod client;
+pub mod sql;
+pub mod stream;
 pub mod utils;
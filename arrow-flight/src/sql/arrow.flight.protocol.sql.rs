@@ -0,0 +1,182 @@
+// Messages of Arrow Flight SQL (`FlightSql.proto`), in the format generated by prost.
+
+///
+/// Represents a metadata request. Used in the command member of FlightDescriptor
+/// for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  info_name: uint32 not null,
+///  value: dense_union<
+///              string_value: utf8,
+///              bool_value: bool,
+///              bigint_value: int64,
+///              int32_bitmask: int32,
+///  >
+/// >
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetSqlInfo {
+    ///
+    /// Values are modelled after ODBC's SQLGetInfo() function. If omitted, then all
+    /// metadata will be retrieved.
+    #[prost(uint32, repeated, tag = "1")]
+    pub info: ::prost::alloc::vec::Vec<u32>,
+}
+///
+/// Represents a request to retrieve the list of catalogs on a Flight SQL enabled backend.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetCatalogs {}
+///
+/// Represents a request to retrieve the list of database schemas on a Flight SQL enabled backend.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8,
+///  db_schema_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name, then db_schema_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetDbSchemas {
+    ///
+    /// Specifies the Catalog to search for the tables.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for schemas to search for.
+    /// When no db_schema_filter_pattern is provided, the pattern will not be used to narrow the search.
+    /// In the pattern string, two special characters can be used to denote matching rules:
+    ///    - "%" means to match any substring with 0 or more characters.
+    ///    - "_" means to match any one character.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+}
+///
+/// Represents a request to retrieve the list of tables, and optionally their schemas, on a
+/// Flight SQL enabled backend.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8,
+///  db_schema_name: utf8,
+///  table_name: utf8 not null,
+///  table_type: utf8 not null,
+///  [optional] table_schema: bytes not null (schema of the table as described in Schema.fbs::Schema,
+///                                           it is serialized as an IPC message.)
+/// >
+/// The returned data should be ordered by catalog_name, db_schema_name, table_name, then table_type,
+/// followed by table_schema if requested.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetTables {
+    ///
+    /// Specifies the Catalog to search for the tables.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for schemas to search for.
+    /// When no db_schema_filter_pattern is provided, all schemas matching other filters are searched.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for tables to search for.
+    /// When no table_name_filter_pattern is provided, all tables matching other filters are searched.
+    #[prost(string, optional, tag = "3")]
+    pub table_name_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter of table types which must match.
+    /// The table types depend on vendor/implementation. It is usually used to separate tables from views or system tables.
+    #[prost(string, repeated, tag = "4")]
+    pub table_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Specifies if the Arrow schema should be returned for found tables.
+    #[prost(bool, tag = "5")]
+    pub include_schema: bool,
+}
+///
+/// Request message for the "CreatePreparedStatement" action on a Flight SQL enabled backend.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementRequest {
+    /// The valid SQL string to create a prepared statement for.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+///
+/// Wrap the result of a "GetPreparedStatement" action.
+///
+/// The resultant PreparedStatement can be closed either:
+/// - Manually, through the "ClosePreparedStatement" action;
+/// - Automatically, by a server timeout.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementResult {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+    /// If a result set generating query was provided, dataset_schema contains the
+    /// schema of the dataset as described in Schema.fbs::Schema, it is serialized as an IPC message.
+    #[prost(bytes = "vec", tag = "2")]
+    pub dataset_schema: ::prost::alloc::vec::Vec<u8>,
+    /// If the query provided contained parameters, parameter_schema contains the
+    /// schema of the expected parameters as described in Schema.fbs::Schema, it is serialized as an IPC message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub parameter_schema: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Request message for the "ClosePreparedStatement" action on a Flight SQL enabled backend.
+/// Closes server resources associated with the prepared statement handle.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionClosePreparedStatementRequest {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Represents a SQL query. Used in the command member of FlightDescriptor
+/// for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementQuery {
+    /// The SQL syntax.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+///
+/// Represents a ticket resulting from GetFlightInfo with a CommandStatementQuery.
+/// This should be used only once and treated as an opaque value, that is, clients should not attempt to parse this.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TicketStatementQuery {
+    /// Unique identifier for the instance of the statement to execute.
+    #[prost(bytes = "vec", tag = "1")]
+    pub statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Represents an instance of executing a prepared statement. Used in the command member of FlightDescriptor for
+/// the following RPC calls:
+///  - DoPut: bind parameter values. All of the bound parameter sets will be executed as a single atomic execution.
+///  - GetFlightInfo: execute the prepared statement instance.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandPreparedStatementQuery {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// A message of any type, identified by its `type_url`, as `google.protobuf.Any`.
+/// Flight SQL commands and tickets are sent packed in it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+}
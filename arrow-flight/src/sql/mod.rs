@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for Arrow Flight SQL: its protocol messages and [`FlightSqlServer`], a Flight
+//! service that dispatches Flight SQL commands to a [`FlightSqlService`].
+
+use arrow2::datatypes::{DataType, Field, Schema};
+use prost::Message;
+
+include!("arrow.flight.protocol.sql.rs");
+
+mod server;
+
+pub use server::{FlightSqlServer, FlightSqlService, RecordBatchStream};
+
+/// A Flight SQL message that can be packed in an [`Any`], as commands of `FlightDescriptor`s,
+/// tickets and bodies of actions are sent.
+pub trait ProstMessageExt: Message + Default + Sized {
+    /// The type URL of this message in an [`Any`]
+    const TYPE_URL: &'static str;
+
+    /// Packs this message in an [`Any`]
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: Self::TYPE_URL.to_string(),
+            value: self.encode_to_vec(),
+        }
+    }
+}
+
+macro_rules! prost_message_ext {
+    ($($name:ident,)*) => {
+        $(
+            impl ProstMessageExt for $name {
+                const TYPE_URL: &'static str =
+                    concat!("type.googleapis.com/arrow.flight.protocol.sql.", stringify!($name));
+            }
+        )*
+    };
+}
+
+prost_message_ext!(
+    CommandGetSqlInfo,
+    CommandGetCatalogs,
+    CommandGetDbSchemas,
+    CommandGetTables,
+    ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult,
+    ActionClosePreparedStatementRequest,
+    CommandStatementQuery,
+    TicketStatementQuery,
+    CommandPreparedStatementQuery,
+);
+
+impl Any {
+    /// Whether this message contains a `M`
+    pub fn is<M: ProstMessageExt>(&self) -> bool {
+        self.type_url == M::TYPE_URL
+    }
+
+    /// Returns the `M` packed in this message, or `None` if it contains another type.
+    /// # Errors
+    /// Errors if the message can't be decoded.
+    pub fn unpack<M: ProstMessageExt>(&self) -> Result<Option<M>, prost::DecodeError> {
+        if !self.is::<M>() {
+            return Ok(None);
+        }
+        M::decode(&self.value[..]).map(Some)
+    }
+}
+
+/// The schema of the results of [`CommandGetCatalogs`]
+pub fn catalogs_schema() -> Schema {
+    Schema::new(vec![Field::new("catalog_name", DataType::Utf8, false)])
+}
+
+/// The schema of the results of [`CommandGetDbSchemas`]
+pub fn db_schemas_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, false),
+    ])
+}
+
+/// The schema of the results of [`CommandGetTables`], with the column `table_schema` when
+/// `include_schema`.
+pub fn tables_schema(include_schema: bool) -> Schema {
+    let mut fields = vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("table_type", DataType::Utf8, false),
+    ];
+    if include_schema {
+        fields.push(Field::new("table_schema", DataType::Binary, false));
+    }
+    Schema::new(fields)
+}
+
+/// The schema of the results of [`CommandGetSqlInfo`]
+pub fn sql_info_schema() -> Schema {
+    let values = vec![
+        Field::new("string_value", DataType::Utf8, true),
+        Field::new("bool_value", DataType::Boolean, true),
+        Field::new("bigint_value", DataType::Int64, true),
+        Field::new("int32_bitmask", DataType::Int32, true),
+    ];
+    Schema::new(vec![
+        Field::new("info_name", DataType::UInt32, false),
+        Field::new("value", DataType::Union(values, None, false), false),
+    ])
+}
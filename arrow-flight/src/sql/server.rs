@@ -0,0 +1,374 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A Flight service dispatching Flight SQL commands to a [`FlightSqlService`].
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow2::{
+    datatypes::Schema, io::ipc::write::common::IpcWriteOptions, record_batch::RecordBatch,
+};
+use futures::Stream;
+use prost::Message;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::*;
+use crate::flight_service_server::FlightService;
+use crate::stream::flight_data_stream;
+use crate::utils::{flight_schema_from_arrow_schema, ipc_message_from_arrow_schema};
+use crate::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+
+type TonicStream<T> = Pin<Box<dyn Stream<Item = T> + Send + Sync + 'static>>;
+
+/// A stream of `RecordBatch`es returned by a [`FlightSqlService`]
+pub type RecordBatchStream =
+    Pin<Box<dyn Stream<Item = arrow2::error::Result<RecordBatch>> + Send + Sync + 'static>>;
+
+const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+/// The handlers of the Flight SQL commands of a [`FlightSqlServer`]. Handlers of
+/// commands not supported by the service can be left to their default implementation,
+/// which returns an `Unimplemented` status.
+///
+/// The results of the metadata commands must have the schemas of [`catalogs_schema`],
+/// [`db_schemas_schema`], [`tables_schema`] and [`sql_info_schema`].
+#[tonic::async_trait]
+pub trait FlightSqlService: Send + Sync + 'static {
+    /// Returns the schema of the results of `query`
+    async fn statement_schema(&self, query: &CommandStatementQuery) -> Result<Schema, Status>;
+
+    /// Executes `query`, returning its results
+    async fn statement_query(
+        &self,
+        query: CommandStatementQuery,
+    ) -> Result<RecordBatchStream, Status>;
+
+    /// Creates a prepared statement
+    async fn create_prepared_statement(
+        &self,
+        _request: ActionCreatePreparedStatementRequest,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Err(Status::unimplemented(
+            "Prepared statements are not supported",
+        ))
+    }
+
+    /// Closes a prepared statement created by [`FlightSqlService::create_prepared_statement`]
+    async fn close_prepared_statement(
+        &self,
+        _request: ActionClosePreparedStatementRequest,
+    ) -> Result<(), Status> {
+        Err(Status::unimplemented(
+            "Prepared statements are not supported",
+        ))
+    }
+
+    /// Returns the schema of the results of the prepared statement of `query`
+    async fn prepared_statement_schema(
+        &self,
+        _query: &CommandPreparedStatementQuery,
+    ) -> Result<Schema, Status> {
+        Err(Status::unimplemented(
+            "Prepared statements are not supported",
+        ))
+    }
+
+    /// Executes the prepared statement of `query`, returning its results
+    async fn prepared_statement_query(
+        &self,
+        _query: CommandPreparedStatementQuery,
+    ) -> Result<RecordBatchStream, Status> {
+        Err(Status::unimplemented(
+            "Prepared statements are not supported",
+        ))
+    }
+
+    /// Returns the catalogs, with the schema [`catalogs_schema`]
+    async fn get_catalogs(
+        &self,
+        _command: CommandGetCatalogs,
+    ) -> Result<RecordBatchStream, Status> {
+        Err(Status::unimplemented("GetCatalogs is not supported"))
+    }
+
+    /// Returns the database schemas, with the schema [`db_schemas_schema`]
+    async fn get_db_schemas(
+        &self,
+        _command: CommandGetDbSchemas,
+    ) -> Result<RecordBatchStream, Status> {
+        Err(Status::unimplemented("GetDbSchemas is not supported"))
+    }
+
+    /// Returns the tables, with the schema [`tables_schema`]
+    async fn get_tables(&self, _command: CommandGetTables) -> Result<RecordBatchStream, Status> {
+        Err(Status::unimplemented("GetTables is not supported"))
+    }
+
+    /// Returns information about the service, with the schema [`sql_info_schema`]
+    async fn get_sql_info(&self, _command: CommandGetSqlInfo) -> Result<RecordBatchStream, Status> {
+        Err(Status::unimplemented("GetSqlInfo is not supported"))
+    }
+}
+
+/// A Flight service implementing Flight SQL by dispatching its commands to a
+/// [`FlightSqlService`]. It is served as any other Flight service, via
+/// `FlightServiceServer::new(FlightSqlServer::new(service))`.
+///
+/// The ticket of the [`FlightInfo`] of a command contains the command itself, or a
+/// [`TicketStatementQuery`] with the query of a [`CommandStatementQuery`], so that the
+/// server is stateless.
+pub struct FlightSqlServer<T: FlightSqlService> {
+    service: Arc<T>,
+}
+
+impl<T: FlightSqlService> FlightSqlServer<T> {
+    /// Returns a new [`FlightSqlServer`] dispatching to `service`
+    pub fn new(service: T) -> Self {
+        Self {
+            service: Arc::new(service),
+        }
+    }
+
+    /// Returns the schema of the results of the command `command` and the ticket to fetch them
+    async fn command_schema(&self, command: &Any) -> Result<(Schema, Any), Status> {
+        if let Some(query) = command
+            .unpack::<CommandStatementQuery>()
+            .map_err(decode_error)?
+        {
+            let schema = self.service.statement_schema(&query).await?;
+            let ticket = TicketStatementQuery {
+                statement_handle: query.query.into_bytes(),
+            };
+            Ok((schema, ticket.as_any()))
+        } else if let Some(query) = command
+            .unpack::<CommandPreparedStatementQuery>()
+            .map_err(decode_error)?
+        {
+            let schema = self.service.prepared_statement_schema(&query).await?;
+            Ok((schema, command.clone()))
+        } else if command.is::<CommandGetCatalogs>() {
+            Ok((catalogs_schema(), command.clone()))
+        } else if command.is::<CommandGetDbSchemas>() {
+            Ok((db_schemas_schema(), command.clone()))
+        } else if let Some(tables) = command.unpack::<CommandGetTables>().map_err(decode_error)? {
+            Ok((tables_schema(tables.include_schema), command.clone()))
+        } else if command.is::<CommandGetSqlInfo>() {
+            Ok((sql_info_schema(), command.clone()))
+        } else {
+            Err(Status::invalid_argument(format!(
+                "The command {} is not supported",
+                command.type_url
+            )))
+        }
+    }
+
+    /// Returns the schema and the results of `ticket`
+    async fn ticket_results(&self, ticket: &Any) -> Result<(Schema, RecordBatchStream), Status> {
+        let service = &self.service;
+        if let Some(ticket) = ticket
+            .unpack::<TicketStatementQuery>()
+            .map_err(decode_error)?
+        {
+            let query = String::from_utf8(ticket.statement_handle)
+                .map_err(|e| Status::invalid_argument(format!("Invalid statement: {}", e)))?;
+            let query = CommandStatementQuery { query };
+            let schema = service.statement_schema(&query).await?;
+            Ok((schema, service.statement_query(query).await?))
+        } else if let Some(query) = ticket
+            .unpack::<CommandPreparedStatementQuery>()
+            .map_err(decode_error)?
+        {
+            let schema = service.prepared_statement_schema(&query).await?;
+            Ok((schema, service.prepared_statement_query(query).await?))
+        } else if let Some(command) = ticket
+            .unpack::<CommandGetCatalogs>()
+            .map_err(decode_error)?
+        {
+            Ok((catalogs_schema(), service.get_catalogs(command).await?))
+        } else if let Some(command) = ticket
+            .unpack::<CommandGetDbSchemas>()
+            .map_err(decode_error)?
+        {
+            Ok((db_schemas_schema(), service.get_db_schemas(command).await?))
+        } else if let Some(command) = ticket.unpack::<CommandGetTables>().map_err(decode_error)? {
+            let schema = tables_schema(command.include_schema);
+            Ok((schema, service.get_tables(command).await?))
+        } else if let Some(command) = ticket.unpack::<CommandGetSqlInfo>().map_err(decode_error)? {
+            Ok((sql_info_schema(), service.get_sql_info(command).await?))
+        } else {
+            Err(Status::invalid_argument(format!(
+                "The ticket {} is not supported",
+                ticket.type_url
+            )))
+        }
+    }
+}
+
+fn decode_error(error: prost::DecodeError) -> Status {
+    Status::invalid_argument(format!("Unable to decode the message: {}", error))
+}
+
+#[tonic::async_trait]
+impl<T: FlightSqlService> FlightService for FlightSqlServer<T> {
+    type HandshakeStream = TonicStream<Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = TonicStream<Result<FlightInfo, Status>>;
+    type DoGetStream = TonicStream<Result<FlightData, Status>>;
+    type DoPutStream = TonicStream<Result<PutResult, Status>>;
+    type DoActionStream = TonicStream<Result<crate::Result, Status>>;
+    type ListActionsStream = TonicStream<Result<ActionType, Status>>;
+    type DoExchangeStream = TonicStream<Result<FlightData, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let descriptor = request.into_inner();
+        let command = Any::decode(&descriptor.cmd[..]).map_err(decode_error)?;
+        let (schema, ticket) = self.command_schema(&command).await?;
+
+        let options = IpcWriteOptions::default();
+        let schema = ipc_message_from_arrow_schema(&schema, &options)
+            .map_err(|e| Status::internal(format!("Unable to encode the schema: {}", e)))?;
+        let endpoint = FlightEndpoint {
+            ticket: Some(Ticket {
+                ticket: ticket.encode_to_vec(),
+            }),
+            location: vec![],
+        };
+        Ok(Response::new(FlightInfo {
+            schema,
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![endpoint],
+            total_records: -1,
+            total_bytes: -1,
+        }))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let command = Any::decode(&request.into_inner().cmd[..]).map_err(decode_error)?;
+        let (schema, _) = self.command_schema(&command).await?;
+
+        let options = IpcWriteOptions::default();
+        Ok(Response::new(flight_schema_from_arrow_schema(
+            &schema, &options,
+        )))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let ticket = Any::decode(&request.into_inner().ticket[..]).map_err(decode_error)?;
+        let (schema, batches) = self.ticket_results(&ticket).await?;
+
+        let stream = flight_data_stream(&schema, batches, IpcWriteOptions::default());
+        Ok(Response::new(Box::pin(stream) as Self::DoGetStream))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        let action = request.into_inner();
+        let body = Any::decode(&action.body[..]).map_err(decode_error)?;
+
+        let result = match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request = body
+                    .unpack::<ActionCreatePreparedStatementRequest>()
+                    .map_err(decode_error)?
+                    .ok_or_else(|| Status::invalid_argument("Invalid CreatePreparedStatement"))?;
+                let result = self.service.create_prepared_statement(request).await?;
+                vec![Ok(crate::Result {
+                    body: result.as_any().encode_to_vec(),
+                })]
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let request = body
+                    .unpack::<ActionClosePreparedStatementRequest>()
+                    .map_err(decode_error)?
+                    .ok_or_else(|| Status::invalid_argument("Invalid ClosePreparedStatement"))?;
+                self.service.close_prepared_statement(request).await?;
+                vec![]
+            }
+            other => {
+                return Err(Status::invalid_argument(format!(
+                    "The action {} is not supported",
+                    other
+                )))
+            }
+        };
+        let stream = futures::stream::iter(result);
+        Ok(Response::new(Box::pin(stream) as Self::DoActionStream))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let actions = vec![
+            ActionType {
+                r#type: CREATE_PREPARED_STATEMENT.to_string(),
+                description: "Creates a reusable prepared statement resource on the server"
+                    .to_string(),
+            },
+            ActionType {
+                r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+                description: "Closes a reusable prepared statement resource on the server"
+                    .to_string(),
+            },
+        ];
+        let stream = futures::stream::iter(actions.into_iter().map(Ok));
+        Ok(Response::new(Box::pin(stream) as Self::ListActionsStream))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+}
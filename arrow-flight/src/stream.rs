@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions between streams of `RecordBatch`es and streams of `FlightData`, as sent
+//! and received by `do_get`, `do_put` and `do_exchange`.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow2::{
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    io::ipc,
+    io::ipc::read::MessageDecoder,
+    io::ipc::write::{IpcWriteOptions, MessageEncoder},
+    record_batch::RecordBatch,
+};
+use futures::{ready, Stream, StreamExt};
+use tonic::Status;
+
+use crate::utils::flight_data_from_arrow_schema;
+use crate::{FlightData, FlightDescriptor};
+
+/// Converts `batches` into the `FlightData` of a `do_get` response: the schema, followed by
+/// each batch preceded by the dictionaries it requires. Dictionaries are only sent when they
+/// change, as a delta when they extend the previous dictionary.
+pub fn flight_data_stream<S>(
+    schema: &Schema,
+    batches: S,
+    options: IpcWriteOptions,
+) -> impl Stream<Item = std::result::Result<FlightData, Status>> + Send + Sync + 'static
+where
+    S: Stream<Item = Result<RecordBatch>> + Send + Sync + 'static,
+{
+    let schema = flight_data_from_arrow_schema(schema, &options);
+
+    let mut encoder = MessageEncoder::new(options);
+    let batches = batches
+        .map(move |batch| {
+            let data = batch.and_then(|batch| {
+                let (dictionaries, batch) = encoder.encode_batch(&batch)?;
+                Ok(dictionaries
+                    .into_iter()
+                    .chain(std::iter::once(batch))
+                    .map(FlightData::from)
+                    .collect::<Vec<_>>())
+            });
+            let data = match data {
+                Ok(data) => data.into_iter().map(Ok).collect(),
+                Err(error) => vec![Err(to_status(error))],
+            };
+            futures::stream::iter(data)
+        })
+        .flatten();
+
+    futures::stream::once(futures::future::ready(Ok(schema))).chain(batches)
+}
+
+fn to_status(error: ArrowError) -> Status {
+    Status::internal(format!("Unable to convert record batch: {}", error))
+}
+
+/// A [`Stream`] of the `RecordBatch`es decoded from a stream of `FlightData`, such as the
+/// request of a `do_put` or the response of a `do_get`, whose first message is the schema.
+pub struct FlightRecordBatchStream<S> {
+    stream: S,
+    schema: Arc<Schema>,
+    descriptor: Option<FlightDescriptor>,
+    decoder: MessageDecoder,
+}
+
+impl<S> FlightRecordBatchStream<S>
+where
+    S: Stream<Item = std::result::Result<FlightData, Status>> + Unpin,
+{
+    /// Reads the schema of `stream` from its first message and returns a new
+    /// [`FlightRecordBatchStream`] of its batches.
+    /// # Errors
+    /// Errors if `stream` is empty or its first message is not a schema.
+    pub async fn try_new(mut stream: S) -> std::result::Result<Self, Status> {
+        let data = stream
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("The stream of FlightData is empty"))??;
+
+        let message = ipc::root_as_message(&data.data_header[..])
+            .map_err(|err| Status::invalid_argument(format!("Invalid IPC message: {:?}", err)))?;
+        let (schema, is_little_endian) = message
+            .header_as_schema()
+            .map(ipc::fb_to_schema)
+            .ok_or_else(|| {
+                Status::invalid_argument("The first FlightData of a stream must be a schema")
+            })?;
+        let schema = Arc::new(schema);
+        let decoder = MessageDecoder::with_schema(schema.clone(), is_little_endian);
+
+        Ok(Self {
+            stream,
+            schema,
+            descriptor: data.flight_descriptor,
+            decoder,
+        })
+    }
+
+    /// The schema of the batches of this stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// The descriptor sent with the schema, e.g. by the client of a `do_put`
+    pub fn descriptor(&self) -> Option<&FlightDescriptor> {
+        self.descriptor.as_ref()
+    }
+
+    /// Decodes `data`, returning `None` when it is a dictionary batch.
+    fn decode(&mut self, data: &FlightData) -> Result<Option<RecordBatch>> {
+        let message = ipc::root_as_message(&data.data_header[..])
+            .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+        if message.header_as_schema().is_some() {
+            return Err(ArrowError::Ipc(
+                "Expecting a record batch or a dictionary batch, found a schema".to_string(),
+            ));
+        }
+        self.decoder.decode(&data.data_header, &data.data_body)
+    }
+}
+
+impl<S> Stream for FlightRecordBatchStream<S>
+where
+    S: Stream<Item = std::result::Result<FlightData, Status>> + Unpin,
+{
+    type Item = std::result::Result<RecordBatch, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let data = match ready!(self.stream.poll_next_unpin(cx)) {
+                Some(Ok(data)) => data,
+                Some(Err(status)) => return Poll::Ready(Some(Err(status))),
+                None => return Poll::Ready(None),
+            };
+            match self.decode(&data) {
+                Ok(Some(batch)) => return Poll::Ready(Some(Ok(batch))),
+                Ok(None) => continue,
+                Err(error) => {
+                    return Poll::Ready(Some(Err(Status::invalid_argument(format!(
+                        "Unable to decode FlightData: {}",
+                        error
+                    )))))
+                }
+            }
+        }
+    }
+}
@@ -19,7 +19,7 @@
 
 use std::{convert::TryFrom, sync::Arc};
 
-use crate::{FlightData, SchemaResult};
+use crate::{FlightData, FlightInfo, SchemaResult};
 
 use arrow2::{
     array::*,
@@ -144,6 +144,29 @@ impl TryFrom<&SchemaResult> for Schema {
     }
 }
 
+/// Try convert `FlightInfo` into an Arrow Schema
+///
+/// Returns an error if the `FlightInfo` schema is not a valid encapsulated IPC schema message
+impl TryFrom<&FlightInfo> for Schema {
+    type Error = ArrowError;
+    fn try_from(info: &FlightInfo) -> Result<Self> {
+        // the message is prefixed by an optional continuation marker and its length
+        let bytes = &info.schema[..];
+        let prefix_len = if bytes.starts_with(&[0xff; 4]) { 8 } else { 4 };
+        if bytes.len() < prefix_len {
+            return Err(ArrowError::Ipc(
+                "Unable to convert flight info to Arrow schema: the schema is empty".to_string(),
+            ));
+        }
+        schema_from_bytes(&bytes[prefix_len..]).map_err(|err| {
+            ArrowError::Ipc(format!(
+                "Unable to convert flight info to Arrow schema: {}",
+                err
+            ))
+        })
+    }
+}
+
 /// Convert a FlightData message to a RecordBatch
 pub fn flight_data_to_arrow_batch(
     data: &FlightData,
@@ -0,0 +1,95 @@
+use std::convert::TryFrom;
+
+use arrow2::datatypes::Schema;
+use arrow2::error::ArrowError;
+use arrow_flight::client::FlightClient;
+use arrow_flight::{Criteria, Ticket};
+use futures::TryStreamExt;
+
+use super::common::{batches, path_descriptor, serve};
+
+#[tokio::test]
+async fn put_and_get() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let expected = batches();
+    let schema = expected[0].schema().clone();
+    let batches = futures::stream::iter(expected.clone().into_iter().map(Ok));
+    client
+        .put(path_descriptor("flight"), &schema, batches)
+        .await?;
+
+    let info = client.get_flight_info(path_descriptor("flight")).await?;
+    assert_eq!(&Schema::try_from(&info)?, schema.as_ref());
+    assert_eq!(info.total_records, 5);
+
+    let infos = client
+        .list_flights(Criteria::default())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(infos, vec![info.clone()]);
+
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let stream = client.get(ticket).await?;
+    assert_eq!(stream.schema(), &schema);
+    assert_eq!(stream.try_collect::<Vec<_>>().await?, expected);
+
+    let missing = Ticket {
+        ticket: b"missing".to_vec(),
+    };
+    assert!(client.get(missing).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_error() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let expected = batches();
+    let schema = expected[0].schema().clone();
+    let batches = futures::stream::iter(vec![
+        Ok(expected[0].clone()),
+        Err(ArrowError::Other("unable to compute the batch".to_string())),
+    ]);
+    let result = client
+        .put(path_descriptor("flight"), &schema, batches)
+        .await;
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_error_on_later_batch() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let expected = batches();
+    let schema = expected[0].schema().clone();
+    // the service has received many batches by the time the error happens
+    let batches = futures::stream::iter(
+        expected
+            .iter()
+            .cycle()
+            .take(100)
+            .cloned()
+            .map(Ok)
+            .chain(std::iter::once(Err(ArrowError::Other(
+                "unable to compute the batch".to_string(),
+            ))))
+            .collect::<Vec<_>>(),
+    );
+    let result = client
+        .put(path_descriptor("flight"), &schema, batches)
+        .await;
+    assert_eq!(result.unwrap_err().code(), tonic::Code::Internal);
+
+    // the truncated upload was cancelled and not stored by the service
+    assert!(client
+        .get_flight_info(path_descriptor("flight"))
+        .await
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use arrow2::array::*;
+use arrow2::datatypes::Schema;
+use arrow2::io::ipc::write::common::IpcWriteOptions;
+use arrow2::record_batch::RecordBatch;
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::stream::{flight_data_stream, FlightRecordBatchStream};
+use arrow_flight::utils::ipc_message_from_arrow_schema;
+use arrow_flight::*;
+use futures::{Stream, TryStreamExt};
+use std::net::SocketAddr;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{transport::Server, Request, Response, Status, Streaming};
+
+type Result<T, E = Status> = std::result::Result<T, E>;
+
+type TonicStream<T> = Pin<Box<dyn Stream<Item = T> + Send + Sync + 'static>>;
+
+type Flights = HashMap<String, (Arc<Schema>, Vec<RecordBatch>)>;
+
+fn flight_info(key: &str, schema: &Schema, batches: &[RecordBatch]) -> FlightInfo {
+    let options = IpcWriteOptions::default();
+    FlightInfo {
+        schema: ipc_message_from_arrow_schema(schema, &options).unwrap(),
+        flight_descriptor: Some(path_descriptor(key)),
+        endpoint: vec![FlightEndpoint {
+            ticket: Some(Ticket {
+                ticket: key.as_bytes().to_vec(),
+            }),
+            location: vec![],
+        }],
+        total_records: batches.iter().map(|batch| batch.num_rows() as i64).sum(),
+        total_bytes: -1,
+    }
+}
+
+pub fn path_descriptor(path: &str) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Path as i32,
+        path: vec![path.to_string()],
+        ..Default::default()
+    }
+}
+
+/// A server storing the batches of each `do_put` and returning them on `do_get`
+#[derive(Default)]
+pub struct Service {
+    flights: Arc<Mutex<Flights>>,
+}
+
+#[tonic::async_trait]
+impl FlightService for Service {
+    type HandshakeStream = TonicStream<Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = TonicStream<Result<FlightInfo, Status>>;
+    type DoGetStream = TonicStream<Result<FlightData, Status>>;
+    type DoPutStream = TonicStream<Result<PutResult, Status>>;
+    type DoActionStream = TonicStream<Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = TonicStream<Result<ActionType, Status>>;
+    type DoExchangeStream = TonicStream<Result<FlightData, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let flights = self.flights.lock().unwrap();
+        let infos = flights
+            .iter()
+            .map(|(key, (schema, batches))| flight_info(key, schema, batches))
+            .map(Ok)
+            .collect::<Vec<_>>();
+        Ok(Response::new(
+            Box::pin(futures::stream::iter(infos)) as Self::ListFlightsStream
+        ))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let descriptor = request.into_inner();
+        let key = descriptor
+            .path
+            .first()
+            .ok_or_else(|| Status::invalid_argument("Must specify a path"))?;
+        let flights = self.flights.lock().unwrap();
+        let (schema, batches) = flights
+            .get(key)
+            .ok_or_else(|| Status::not_found(key.clone()))?;
+        Ok(Response::new(flight_info(key, schema, batches)))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let key = String::from_utf8(request.into_inner().ticket)
+            .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {:?}", e)))?;
+        let (schema, batches) = self
+            .flights
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| Status::not_found(key))?;
+
+        let batches = futures::stream::iter(batches.into_iter().map(Ok));
+        let stream = flight_data_stream(&schema, batches, IpcWriteOptions::default());
+        Ok(Response::new(Box::pin(stream) as Self::DoGetStream))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let stream = FlightRecordBatchStream::try_new(request.into_inner()).await?;
+        let key = stream
+            .descriptor()
+            .filter(|descriptor| descriptor.r#type == DescriptorType::Path as i32)
+            .and_then(|descriptor| descriptor.path.first().cloned())
+            .ok_or_else(|| Status::invalid_argument("Must specify a path"))?;
+        let schema = stream.schema().clone();
+
+        let batches = stream.try_collect::<Vec<_>>().await?;
+        self.flights.lock().unwrap().insert(key, (schema, batches));
+
+        let output = futures::stream::empty();
+        Ok(Response::new(Box::pin(output) as Self::DoPutStream))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+}
+
+pub fn batches() -> Vec<RecordBatch> {
+    let batch = |ints: &[i32], keys: &[i32], values: &[&str]| {
+        let dictionary = DictionaryArray::<i32>::from_data(
+            Int32Array::from_slice(keys),
+            Arc::new(Utf8Array::<i32>::from_slice(values)),
+        );
+        RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from_slice(ints)) as Arc<dyn Array>,
+            ),
+            ("b", Arc::new(dictionary) as Arc<dyn Array>),
+        ])
+        .unwrap()
+    };
+    vec![
+        batch(&[1, 2], &[0, 1], &["a", "b"]),
+        // extends the dictionary, sent as a delta
+        batch(&[3], &[2], &["a", "b", "c"]),
+        // replaces the dictionary
+        batch(&[4, 5], &[0, 0], &["d"]),
+    ]
+}
+
+/// Starts a [`Service`] on a free port of localhost, returning its address
+pub async fn serve() -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(FlightServiceServer::new(Service::default()))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    addr
+}
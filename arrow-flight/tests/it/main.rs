@@ -0,0 +1,5 @@
+mod common;
+
+mod client;
+mod sql;
+mod stream;
@@ -0,0 +1,268 @@
+use std::convert::TryFrom;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::record_batch::RecordBatch;
+use arrow_flight::client::FlightClient;
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_server::FlightServiceServer;
+use arrow_flight::sql::*;
+use arrow_flight::{Action, FlightDescriptor, FlightInfo};
+use futures::TryStreamExt;
+use prost::Message;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{transport::Server, Status};
+
+const QUERY: &str = "SELECT * FROM t";
+
+fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+fn batch() -> RecordBatch {
+    RecordBatch::try_new(
+        Arc::new(schema()),
+        vec![
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+            Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None])),
+        ],
+    )
+    .unwrap()
+}
+
+fn tables_batch() -> RecordBatch {
+    RecordBatch::try_new(
+        Arc::new(tables_schema(false)),
+        vec![
+            Arc::new(Utf8Array::<i32>::from([None::<&str>])),
+            Arc::new(Utf8Array::<i32>::from([None::<&str>])),
+            Arc::new(Utf8Array::<i32>::from_slice(["t"])),
+            Arc::new(Utf8Array::<i32>::from_slice(["TABLE"])),
+        ],
+    )
+    .unwrap()
+}
+
+fn sql_info_batch() -> RecordBatch {
+    let schema = sql_info_schema();
+    let data_type = schema.field(1).data_type().clone();
+    // a dense union of the server name (a string) and whether it is read only (a bool)
+    let values = UnionArray::from_data(
+        data_type,
+        Buffer::from([0, 1]),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["test"])),
+            Arc::new(BooleanArray::from_slice([true])),
+            Arc::new(Int64Array::from_slice([])),
+            Arc::new(Int32Array::from_slice([])),
+        ],
+        Some(Buffer::from([0, 0])),
+    );
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(UInt32Array::from_slice([0, 3])), Arc::new(values)],
+    )
+    .unwrap()
+}
+
+fn stream(batch: RecordBatch) -> RecordBatchStream {
+    Box::pin(futures::stream::iter(vec![Ok(batch)]))
+}
+
+/// A service with a single table, `t`, answering the query [`QUERY`]
+struct Service;
+
+impl Service {
+    fn check(query: &str) -> Result<(), String> {
+        if query == QUERY {
+            Ok(())
+        } else {
+            Err(format!("Unknown query {}", query))
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for Service {
+    async fn statement_schema(&self, query: &CommandStatementQuery) -> Result<Schema, Status> {
+        Self::check(&query.query).map_err(Status::invalid_argument)?;
+        Ok(schema())
+    }
+
+    async fn statement_query(
+        &self,
+        query: CommandStatementQuery,
+    ) -> Result<RecordBatchStream, Status> {
+        Self::check(&query.query).map_err(Status::invalid_argument)?;
+        Ok(stream(batch()))
+    }
+
+    async fn create_prepared_statement(
+        &self,
+        request: ActionCreatePreparedStatementRequest,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Self::check(&request.query).map_err(Status::invalid_argument)?;
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: request.query.into_bytes(),
+            ..Default::default()
+        })
+    }
+
+    async fn close_prepared_statement(
+        &self,
+        _request: ActionClosePreparedStatementRequest,
+    ) -> Result<(), Status> {
+        Ok(())
+    }
+
+    async fn prepared_statement_schema(
+        &self,
+        query: &CommandPreparedStatementQuery,
+    ) -> Result<Schema, Status> {
+        Self::check(std::str::from_utf8(&query.prepared_statement_handle).unwrap())
+            .map_err(Status::invalid_argument)?;
+        Ok(schema())
+    }
+
+    async fn prepared_statement_query(
+        &self,
+        query: CommandPreparedStatementQuery,
+    ) -> Result<RecordBatchStream, Status> {
+        Self::check(std::str::from_utf8(&query.prepared_statement_handle).unwrap())
+            .map_err(Status::invalid_argument)?;
+        Ok(stream(batch()))
+    }
+
+    async fn get_tables(&self, _command: CommandGetTables) -> Result<RecordBatchStream, Status> {
+        Ok(stream(tables_batch()))
+    }
+
+    async fn get_sql_info(&self, _command: CommandGetSqlInfo) -> Result<RecordBatchStream, Status> {
+        Ok(stream(sql_info_batch()))
+    }
+}
+
+async fn serve() -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(FlightServiceServer::new(FlightSqlServer::new(Service)))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    addr
+}
+
+fn command_descriptor<M: ProstMessageExt>(command: &M) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Cmd as i32,
+        cmd: command.as_any().encode_to_vec(),
+        ..Default::default()
+    }
+}
+
+async fn get(
+    client: &mut FlightClient,
+    info: &FlightInfo,
+) -> Result<Vec<RecordBatch>, Box<dyn std::error::Error>> {
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let stream = client.get(ticket).await?;
+    Ok(stream.try_collect::<Vec<_>>().await?)
+}
+
+#[tokio::test]
+async fn statement_query() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let command = CommandStatementQuery {
+        query: QUERY.to_string(),
+    };
+    let info = client.get_flight_info(command_descriptor(&command)).await?;
+    assert_eq!(Schema::try_from(&info)?, schema());
+    assert_eq!(get(&mut client, &info).await?, vec![batch()]);
+
+    let command = CommandStatementQuery {
+        query: "SELECT * FROM missing".to_string(),
+    };
+    let error = client
+        .get_flight_info(command_descriptor(&command))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+    Ok(())
+}
+
+#[tokio::test]
+async fn prepared_statement() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let request = ActionCreatePreparedStatementRequest {
+        query: QUERY.to_string(),
+    };
+    let action = Action {
+        r#type: "CreatePreparedStatement".to_string(),
+        body: request.as_any().encode_to_vec(),
+    };
+    let results = client
+        .inner_mut()
+        .do_action(action)
+        .await?
+        .into_inner()
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(results.len(), 1);
+    let result = Any::decode(&results[0].body[..])?
+        .unpack::<ActionCreatePreparedStatementResult>()?
+        .unwrap();
+
+    let command = CommandPreparedStatementQuery {
+        prepared_statement_handle: result.prepared_statement_handle.clone(),
+    };
+    let info = client.get_flight_info(command_descriptor(&command)).await?;
+    assert_eq!(Schema::try_from(&info)?, schema());
+    assert_eq!(get(&mut client, &info).await?, vec![batch()]);
+
+    let request = ActionClosePreparedStatementRequest {
+        prepared_statement_handle: result.prepared_statement_handle,
+    };
+    let action = Action {
+        r#type: "ClosePreparedStatement".to_string(),
+        body: request.as_any().encode_to_vec(),
+    };
+    client.inner_mut().do_action(action).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+    let mut client = FlightClient::connect(format!("http://{}", addr)).await?;
+
+    let command = CommandGetTables::default();
+    let info = client.get_flight_info(command_descriptor(&command)).await?;
+    assert_eq!(Schema::try_from(&info)?, tables_schema(false));
+    assert_eq!(get(&mut client, &info).await?, vec![tables_batch()]);
+
+    let command = CommandGetSqlInfo { info: vec![0, 3] };
+    let info = client.get_flight_info(command_descriptor(&command)).await?;
+    assert_eq!(Schema::try_from(&info)?, sql_info_schema());
+    assert_eq!(get(&mut client, &info).await?, vec![sql_info_batch()]);
+
+    // not implemented by the service
+    let command = CommandGetCatalogs::default();
+    let info = client.get_flight_info(command_descriptor(&command)).await?;
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    match client.get(ticket).await {
+        Err(error) => assert_eq!(error.code(), tonic::Code::Unimplemented),
+        Ok(_) => panic!("GetCatalogs must not be implemented"),
+    }
+    Ok(())
+}
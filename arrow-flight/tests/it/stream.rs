@@ -0,0 +1,48 @@
+use arrow2::io::ipc::write::common::IpcWriteOptions;
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::stream::{flight_data_stream, FlightRecordBatchStream};
+use arrow_flight::Ticket;
+use futures::{StreamExt, TryStreamExt};
+
+use super::common::{batches, path_descriptor, serve};
+
+#[tokio::test]
+async fn put_and_get() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = serve().await;
+
+    let mut client = FlightServiceClient::connect(format!("http://{}", addr)).await?;
+
+    let expected = batches();
+    let schema = expected[0].schema().clone();
+
+    let descriptor = path_descriptor("flight");
+    let data = flight_data_stream(
+        &schema,
+        futures::stream::iter(expected.clone().into_iter().map(Ok)),
+        IpcWriteOptions::default(),
+    )
+    .enumerate()
+    .map(move |(i, data)| {
+        let mut data = data.unwrap();
+        if i == 0 {
+            data.flight_descriptor = Some(descriptor.clone());
+        }
+        data
+    });
+    client.do_put(data).await?;
+
+    let ticket = Ticket {
+        ticket: b"flight".to_vec(),
+    };
+    let response = client.do_get(ticket).await?.into_inner();
+    let stream = FlightRecordBatchStream::try_new(response).await?;
+    assert_eq!(stream.schema(), &schema);
+    let batches = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(batches, expected);
+
+    let missing = Ticket {
+        ticket: b"missing".to_vec(),
+    };
+    assert!(client.do_get(missing).await.is_err());
+    Ok(())
+}
@@ -12,7 +12,7 @@ fn main() -> Result<()> {
 
     let mut reader = TcpStream::connect(ADDRESS)?;
     let metadata = read::read_stream_metadata(&mut reader)?;
-    let mut stream = read::StreamReader::new(&mut reader, metadata);
+    let mut stream = read::StreamReader::new(&mut reader, metadata, None);
 
     let mut idx = 0;
     loop {
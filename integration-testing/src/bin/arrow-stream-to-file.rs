@@ -24,7 +24,7 @@ use arrow2::io::ipc::write::FileWriter;
 fn main() -> Result<()> {
     let mut reader = io::stdin();
     let metadata = read::read_stream_metadata(&mut reader)?;
-    let mut arrow_stream_reader = read::StreamReader::new(reader, metadata);
+    let mut arrow_stream_reader = read::StreamReader::new(reader, metadata, None);
     let schema = arrow_stream_reader.schema();
 
     let mut writer = io::stdout();
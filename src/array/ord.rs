@@ -98,9 +98,71 @@ fn compare_binary<'a, O: Offset>(left: &'a dyn Array, right: &'a dyn Array) -> D
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
+fn compare_fixed_size_binary<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+/// Compares null-aware values nested in another array (children of structs and values of
+/// dictionaries). Nulls are ordered before any value iff `nulls_first`.
+fn compare_nested<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+    nulls_first: bool,
+) -> Result<DynComparator<'a>> {
+    let comparator = build_compare_with_nulls(left, right, nulls_first)?;
+    let null = if nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    Ok(Box::new(move |i, j| {
+        match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => (comparator)(i, j),
+            (false, true) => null,
+            (true, false) => null.reverse(),
+            (false, false) => Ordering::Equal,
+        }
+    }))
+}
+
+fn compare_struct<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+    nulls_first: bool,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(lhs, rhs)| compare_nested(lhs.as_ref(), rhs.as_ref(), nulls_first))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        for comparator in comparators.iter() {
+            match (comparator)(i, j) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+        Ordering::Equal
+    }))
+}
+
 fn compare_dict<'a, K>(
     left: &'a DictionaryArray<K>,
     right: &'a DictionaryArray<K>,
+    nulls_first: bool,
 ) -> Result<DynComparator<'a>>
 where
     K: DictionaryKey,
@@ -108,7 +170,7 @@ where
     let left_keys = left.keys().values();
     let right_keys = right.keys().values();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    let comparator = compare_nested(left.values().as_ref(), right.values().as_ref(), nulls_first)?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys[i].to_usize().unwrap();
@@ -118,10 +180,10 @@ where
 }
 
 macro_rules! dyn_dict {
-    ($key:ty, $lhs:expr, $rhs:expr) => {{
+    ($key:ty, $lhs:expr, $rhs:expr, $nulls_first:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
         let rhs = $rhs.as_any().downcast_ref().unwrap();
-        compare_dict::<$key>(lhs, rhs)?
+        compare_dict::<$key>(lhs, rhs, $nulls_first)?
     }};
 }
 
@@ -147,6 +209,18 @@ macro_rules! dyn_dict {
 // This is a factory of comparisons.
 // The lifetime 'a enforces that we cannot use the closure beyond any of the array's lifetime.
 pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<DynComparator<'a>> {
+    build_compare_with_nulls(left, right, true)
+}
+
+/// Like [`build_compare`], but null values nested in the arrays (children of structs and
+/// values of dictionaries) are ordered before any value iff `nulls_first`.
+/// # Error
+/// The arrays' [`DataType`] must be equal and the types must have a natural order.
+pub fn build_compare_with_nulls<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+    nulls_first: bool,
+) -> Result<DynComparator<'a>> {
     use DataType::*;
     use IntervalUnit::*;
     use TimeUnit::*;
@@ -172,30 +246,34 @@ pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<Dy
         | (Date64, Date64)
         | (Time64(Microsecond), Time64(Microsecond))
         | (Time64(Nanosecond), Time64(Nanosecond))
-        | (Timestamp(Second, None), Timestamp(Second, None))
-        | (Timestamp(Millisecond, None), Timestamp(Millisecond, None))
-        | (Timestamp(Microsecond, None), Timestamp(Microsecond, None))
-        | (Timestamp(Nanosecond, None), Timestamp(Nanosecond, None))
         | (Duration(Second), Duration(Second))
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Timestamp(lu, ltz), Timestamp(ru, rtz)) if lu == ru && ltz == rtz => {
+            compare_primitives::<i64>(left, right)
+        }
+        (Decimal(lp, ls), Decimal(rp, rs)) if lp == rp && ls == rs => {
+            compare_primitives::<i128>(left, right)
+        }
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => compare_fixed_size_binary(left, right),
+        (Struct(_), Struct(_)) => compare_struct(left, right, nulls_first)?,
         (Dictionary(key_type_lhs, _), Dictionary(key_type_rhs, _)) => {
             match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
-                (UInt8, UInt8) => dyn_dict!(u8, left, right),
-                (UInt16, UInt16) => dyn_dict!(u16, left, right),
-                (UInt32, UInt32) => dyn_dict!(u32, left, right),
-                (UInt64, UInt64) => dyn_dict!(u64, left, right),
-                (Int8, Int8) => dyn_dict!(i8, left, right),
-                (Int16, Int16) => dyn_dict!(i16, left, right),
-                (Int32, Int32) => dyn_dict!(i32, left, right),
-                (Int64, Int64) => dyn_dict!(i64, left, right),
+                (UInt8, UInt8) => dyn_dict!(u8, left, right, nulls_first),
+                (UInt16, UInt16) => dyn_dict!(u16, left, right, nulls_first),
+                (UInt32, UInt32) => dyn_dict!(u32, left, right, nulls_first),
+                (UInt64, UInt64) => dyn_dict!(u64, left, right, nulls_first),
+                (Int8, Int8) => dyn_dict!(i8, left, right, nulls_first),
+                (Int16, Int16) => dyn_dict!(i16, left, right, nulls_first),
+                (Int32, Int32) => dyn_dict!(i32, left, right, nulls_first),
+                (Int64, Int64) => dyn_dict!(i64, left, right, nulls_first),
                 (lhs, _) => {
                     return Err(ArrowError::InvalidArgumentError(format!(
                         "Dictionaries do not support keys of type {:?}",
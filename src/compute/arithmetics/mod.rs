@@ -63,9 +63,9 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 use num_traits::{NumCast, Zero};
 
-use crate::datatypes::{DataType, TimeUnit};
+use crate::datatypes::{DataType, IntervalUnit, TimeUnit};
 use crate::error::{ArrowError, Result};
-use crate::types::NativeType;
+use crate::types::{days_ms, NativeType};
 use crate::{array::*, bitmap::Bitmap};
 
 use super::arity::unary;
@@ -85,6 +85,21 @@ macro_rules! primitive {
     }};
 }
 
+// Macro to evaluate the addition or subtraction of a calendar interval to a
+// date or timestamp, downcasting both arrays to their native types.
+macro_rules! interval {
+    ($lhs: expr, $op: expr, $rhs: expr, $time_type: ty, $interval_type: ty) => {{
+        let lhs = $lhs.as_any().downcast_ref().unwrap();
+        let rhs = $rhs.as_any().downcast_ref().unwrap();
+        let result = if $op == Operator::Add {
+            time::add_interval::<$time_type, $interval_type>(lhs, rhs)
+        } else {
+            time::subtract_interval::<$time_type, $interval_type>(lhs, rhs)
+        };
+        result.map(|x| Box::new(x) as Box<dyn Array>)
+    }};
+}
+
 /// Execute an arithmetic operation with two arrays. It uses the enum Operator
 /// to select the type of operation that is going to be performed with the two
 /// arrays
@@ -158,6 +173,36 @@ pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<
             let rhs = rhs.as_any().downcast_ref().unwrap();
             time::subtract_timestamps(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
         }
+        (Date32, Subtract, Date32) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            time::subtract_dates::<i32>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (Date64, Subtract, Date64) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            time::subtract_dates::<i64>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (Date32, Add, Interval(IntervalUnit::YearMonth))
+        | (Date32, Subtract, Interval(IntervalUnit::YearMonth)) => {
+            interval!(lhs, op, rhs, i32, i32)
+        }
+        (Date32, Add, Interval(IntervalUnit::DayTime))
+        | (Date32, Subtract, Interval(IntervalUnit::DayTime)) => {
+            interval!(lhs, op, rhs, i32, days_ms)
+        }
+        (Date64, Add, Interval(IntervalUnit::YearMonth))
+        | (Date64, Subtract, Interval(IntervalUnit::YearMonth))
+        | (Timestamp(_, _), Add, Interval(IntervalUnit::YearMonth))
+        | (Timestamp(_, _), Subtract, Interval(IntervalUnit::YearMonth)) => {
+            interval!(lhs, op, rhs, i64, i32)
+        }
+        (Date64, Add, Interval(IntervalUnit::DayTime))
+        | (Date64, Subtract, Interval(IntervalUnit::DayTime))
+        | (Timestamp(_, _), Add, Interval(IntervalUnit::DayTime))
+        | (Timestamp(_, _), Subtract, Interval(IntervalUnit::DayTime)) => {
+            interval!(lhs, op, rhs, i64, days_ms)
+        }
         (lhs, op, rhs) => Err(ArrowError::NotYetImplemented(format!(
             "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
             lhs, op, rhs
@@ -215,6 +260,20 @@ pub fn can_arithmetic(lhs: &DataType, op: Operator, rhs: &DataType) -> bool {
             | (Timestamp(_, _), Subtract, Duration(_))
             | (Timestamp(_, _), Add, Duration(_))
             | (Timestamp(_, None), Subtract, Timestamp(_, None))
+            | (Date32, Subtract, Date32)
+            | (Date64, Subtract, Date64)
+            | (Date32, Add, Interval(IntervalUnit::YearMonth))
+            | (Date32, Subtract, Interval(IntervalUnit::YearMonth))
+            | (Date32, Add, Interval(IntervalUnit::DayTime))
+            | (Date32, Subtract, Interval(IntervalUnit::DayTime))
+            | (Date64, Add, Interval(IntervalUnit::YearMonth))
+            | (Date64, Subtract, Interval(IntervalUnit::YearMonth))
+            | (Date64, Add, Interval(IntervalUnit::DayTime))
+            | (Date64, Subtract, Interval(IntervalUnit::DayTime))
+            | (Timestamp(_, _), Add, Interval(IntervalUnit::YearMonth))
+            | (Timestamp(_, _), Subtract, Interval(IntervalUnit::YearMonth))
+            | (Timestamp(_, _), Add, Interval(IntervalUnit::DayTime))
+            | (Timestamp(_, _), Subtract, Interval(IntervalUnit::DayTime))
     )
 }
 
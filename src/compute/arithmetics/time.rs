@@ -1,5 +1,6 @@
 //! Defines the arithmetic kernels for adding a Duration to a Timestamp,
-//! Time32, Time64, Date32 and Date64.
+//! Time32, Time64, Date32 and Date64, and for adding calendar intervals
+//! (`Interval(YearMonth)` and `Interval(DayTime)`) to a Timestamp, Date32 and Date64.
 //!
 //! For the purposes of Arrow Implementations, adding this value to a Timestamp
 //! ("t1") naively (i.e. simply summing the two number) is acceptable even
@@ -9,17 +10,24 @@
 //! would yield a value that is possibly a few seconds off from the true
 //! elapsed time.
 
+use std::convert::TryFrom;
 use std::ops::{Add, Sub};
 
-use num_traits::AsPrimitive;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike};
+use num_traits::{AsPrimitive, NumCast, ToPrimitive};
 
+#[cfg(feature = "chrono-tz")]
+use crate::temporal_conversions::parse_offset_tz;
 use crate::{
     array::{Array, PrimitiveArray},
-    compute::arity::binary,
-    datatypes::{DataType, TimeUnit},
+    compute::arity::{binary, binary_checked, unary},
+    datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
-    temporal_conversions::{timeunit_scale, SECONDS_IN_DAY},
-    types::NativeType,
+    temporal_conversions::{
+        parse_offset, timeunit_scale, EPOCH_DAYS_FROM_CE, MICROSECONDS, MILLISECONDS,
+        MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
+    },
+    types::{days_ms, NativeType},
 };
 
 /// Creates the scale required to add or subtract a Duration to a time array
@@ -227,11 +235,416 @@ pub fn subtract_timestamps(
     }
 }
 
+/// A calendar interval that can be added to a date or timestamp, decomposed
+/// into months, days and milliseconds.
+pub trait CalendarInterval: NativeType {
+    /// Returns the number of months, days and milliseconds of this interval.
+    fn to_parts(self) -> (i32, i32, i32);
+}
+
+impl CalendarInterval for i32 {
+    #[inline]
+    fn to_parts(self) -> (i32, i32, i32) {
+        (self, 0, 0)
+    }
+}
+
+impl CalendarInterval for days_ms {
+    #[inline]
+    fn to_parts(self) -> (i32, i32, i32) {
+        (0, self.days(), self.milliseconds())
+    }
+}
+
+// Evaluates `$body` with `$tz` bound to a reference to a [`TimeZone`] parsed from
+// the optional timezone `$timezone`. Naive values are treated as UTC.
+macro_rules! with_timezone {
+    ($timezone:expr, |$tz:ident| $body:expr) => {{
+        match $timezone {
+            None => {
+                let $tz = &chrono::Utc;
+                $body
+            }
+            Some(timezone) => match parse_offset(timezone) {
+                Ok(offset) => {
+                    let $tz = &offset;
+                    $body
+                }
+                Err(_) => {
+                    #[cfg(feature = "chrono-tz")]
+                    let result = match parse_offset_tz(timezone) {
+                        Some(tz) => {
+                            let $tz = &tz;
+                            $body
+                        }
+                        None => Err(ArrowError::InvalidArgumentError(format!(
+                            "timezone \"{}\" cannot be parsed",
+                            timezone
+                        ))),
+                    };
+                    #[cfg(not(feature = "chrono-tz"))]
+                    let result = Err(ArrowError::InvalidArgumentError(format!(
+                        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+                        timezone
+                    )));
+                    result
+                }
+            },
+        }
+    }};
+}
+
+/// Returns the number of units in a day and the timezone of a Date32, Date64 or
+/// Timestamp [`DataType`].
+fn units_per_day(data_type: &DataType) -> Result<(i64, Option<&String>)> {
+    Ok(match data_type {
+        DataType::Date32 => (1, None),
+        DataType::Date64 => (MILLISECONDS_IN_DAY, None),
+        DataType::Timestamp(time_unit, timezone) => {
+            let per_second = match time_unit {
+                TimeUnit::Second => 1,
+                TimeUnit::Millisecond => MILLISECONDS,
+                TimeUnit::Microsecond => MICROSECONDS,
+                TimeUnit::Nanosecond => NANOSECONDS,
+            };
+            (SECONDS_IN_DAY * per_second, timezone.as_ref())
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Calendar arithmetics is not supported for {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
+/// Converts `value`, in a unit of which there are `per_day` in a day, to `per_day_to` units,
+/// truncating any fractional part.
+#[inline]
+fn rescale(value: i64, per_day: i64, per_day_to: i64) -> Option<i64> {
+    i64::try_from(value as i128 * per_day_to as i128 / per_day as i128).ok()
+}
+
+#[inline]
+fn days_to_date(days: i64) -> Option<NaiveDate> {
+    let days = i32::try_from(days).ok()?.checked_add(EPOCH_DAYS_FROM_CE)?;
+    NaiveDate::from_num_days_from_ce_opt(days)
+}
+
+#[inline]
+fn date_to_days(date: NaiveDate) -> i64 {
+    (date.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i64
+}
+
+fn to_datetime(value: i64, per_day: i64) -> Option<NaiveDateTime> {
+    let date = days_to_date(value.div_euclid(per_day))?;
+    let nanoseconds = rescale(
+        value.rem_euclid(per_day),
+        per_day,
+        SECONDS_IN_DAY * NANOSECONDS,
+    )?;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanoseconds / NANOSECONDS) as u32,
+        (nanoseconds % NANOSECONDS) as u32,
+    )?;
+    Some(NaiveDateTime::new(date, time))
+}
+
+fn from_datetime(datetime: NaiveDateTime, per_day: i64) -> Option<i64> {
+    let time = datetime.time();
+    let nanoseconds =
+        time.num_seconds_from_midnight() as i64 * NANOSECONDS + time.nanosecond() as i64;
+    date_to_days(datetime.date())
+        .checked_mul(per_day)?
+        .checked_add(rescale(nanoseconds, SECONDS_IN_DAY * NANOSECONDS, per_day)?)
+}
+
+#[inline]
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let (year, month) = if month == 12 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month + 1)
+    };
+    Some(NaiveDate::from_ymd_opt(year, month, 1)?.pred_opt()?.day())
+}
+
+/// Adds `months` to `date`, clamping the day to the last day of the resulting month
+/// (e.g. 2021-01-31 + 1 month = 2021-02-28).
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let months = date.year() as i64 * 12 + date.month0() as i64 + months as i64;
+    let year = i32::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(days_in_month(year, month)?);
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Returns the local time in `timezone` of the UTC `datetime`.
+#[inline]
+fn to_local<T: TimeZone>(datetime: NaiveDateTime, timezone: &T) -> Option<NaiveDateTime> {
+    let offset = timezone.offset_from_utc_datetime(&datetime).fix();
+    datetime.checked_add_signed(chrono::Duration::seconds(offset.local_minus_utc() as i64))
+}
+
+/// Shifts `value`, in a unit of which there are `per_day` in a day, by `months` and `days`
+/// in the local time of `timezone`, and then by `milliseconds`.
+/// Returns `None` if the result is not representable.
+fn shift<T: TimeZone>(
+    value: i64,
+    per_day: i64,
+    timezone: &T,
+    (months, days, milliseconds): (i32, i32, i32),
+) -> Option<i64> {
+    let utc = to_datetime(value, per_day)?;
+    let offset = timezone.offset_from_utc_datetime(&utc).fix();
+    let local =
+        utc.checked_add_signed(chrono::Duration::seconds(offset.local_minus_utc() as i64))?;
+
+    let date = add_months(local.date(), months)?;
+    let date = days_to_date(date_to_days(date).checked_add(days as i64)?)?;
+    let local = NaiveDateTime::new(date, local.time());
+
+    // local times skipped by a transition (e.g. DST) keep the original offset; ambiguous
+    // local times use the earliest offset.
+    let offset = timezone
+        .offset_from_local_datetime(&local)
+        .earliest()
+        .map(|offset| offset.fix())
+        .unwrap_or(offset);
+    let utc =
+        local.checked_sub_signed(chrono::Duration::seconds(offset.local_minus_utc() as i64))?;
+
+    from_datetime(utc, per_day)?.checked_add(rescale(
+        milliseconds as i64,
+        MILLISECONDS_IN_DAY,
+        per_day,
+    )?)
+}
+
+fn shift_interval<T, I>(
+    time: &PrimitiveArray<T>,
+    interval: &PrimitiveArray<I>,
+    negate: bool,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + NumCast,
+    I: CalendarInterval,
+{
+    if !matches!(interval.data_type(), DataType::Interval(_)) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        ));
+    }
+    let (per_day, timezone) = units_per_day(time.data_type())?;
+
+    with_timezone!(timezone, |tz| binary_checked(
+        time,
+        interval,
+        time.data_type().clone(),
+        |time, interval| {
+            let (months, days, milliseconds) = interval.to_parts();
+            let parts = if negate {
+                (
+                    months.checked_neg()?,
+                    days.checked_neg()?,
+                    milliseconds.checked_neg()?,
+                )
+            } else {
+                (months, days, milliseconds)
+            };
+            num_traits::cast(shift(time.to_i64()?, per_day, tz, parts)?)
+        }
+    ))
+}
+
+/// Adds a calendar interval (`Interval(YearMonth)` or `Interval(DayTime)`) to a Date32,
+/// Date64 or Timestamp array.
+///
+/// Months and days are added to the calendar date in the timezone of the array, keeping
+/// the time of the day; milliseconds are added afterwards as an exact duration. When the
+/// day does not exist in the resulting month, it is clamped to the month's last day
+/// (e.g. 2021-01-31 + 1 month = 2021-02-28). For Date32, milliseconds are truncated to days.
+///
+/// Results that are not representable are null.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::add_interval;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+///
+/// // 2021-01-31 and 2020-01-31
+/// let date = PrimitiveArray::from([Some(18658i32), Some(18292), None]).to(DataType::Date32);
+/// let months = PrimitiveArray::from([Some(1i32), Some(1), Some(1)])
+///     .to(DataType::Interval(IntervalUnit::YearMonth));
+///
+/// let result = add_interval(&date, &months).unwrap();
+/// // 2021-02-28 and 2020-02-29
+/// let expected = PrimitiveArray::from([Some(18686i32), Some(18321), None]).to(DataType::Date32);
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// Errors iff the arrays do not have the same length or have unsupported data types.
+pub fn add_interval<T, I>(
+    time: &PrimitiveArray<T>,
+    interval: &PrimitiveArray<I>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + NumCast,
+    I: CalendarInterval,
+{
+    shift_interval(time, interval, false)
+}
+
+/// Subtracts a calendar interval (`Interval(YearMonth)` or `Interval(DayTime)`) from a Date32,
+/// Date64 or Timestamp array. See [`add_interval`] for the semantics.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::subtract_interval;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+/// use arrow2::types::days_ms;
+///
+/// // 2021-03-01 00:00:00 UTC in milliseconds
+/// let date = PrimitiveArray::from([Some(1_614_556_800_000i64)]).to(DataType::Date64);
+/// let interval = PrimitiveArray::from([Some(days_ms::new(1, 0))])
+///     .to(DataType::Interval(IntervalUnit::DayTime));
+///
+/// let result = subtract_interval(&date, &interval).unwrap();
+/// // 2021-02-28
+/// let expected = PrimitiveArray::from([Some(1_614_470_400_000i64)]).to(DataType::Date64);
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// Errors iff the arrays do not have the same length or have unsupported data types.
+pub fn subtract_interval<T, I>(
+    time: &PrimitiveArray<T>,
+    interval: &PrimitiveArray<I>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + NumCast,
+    I: CalendarInterval,
+{
+    shift_interval(time, interval, true)
+}
+
+/// Calculates the difference between two dates (Date32 or Date64) returning an
+/// array of type Duration: `Duration(Second)` for Date32 and `Duration(Millisecond)`
+/// for Date64.
+///
+/// As for [`add_interval`], differences that are not representable are null.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::subtract_dates;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let lhs = PrimitiveArray::from([Some(10i32), None]).to(DataType::Date32);
+/// let rhs = PrimitiveArray::from([Some(9i32), Some(1)]).to(DataType::Date32);
+///
+/// let result = subtract_dates(&lhs, &rhs).unwrap();
+/// let expected = PrimitiveArray::from([Some(86_400i64), None])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// assert_eq!(result, expected);
+/// ```
+pub fn subtract_dates<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<i64>>
+where
+    T: NativeType + AsPrimitive<i64>,
+{
+    let (scale, data_type) = match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Date32, DataType::Date32) => {
+            (SECONDS_IN_DAY, DataType::Duration(TimeUnit::Second))
+        }
+        (DataType::Date64, DataType::Date64) => (1, DataType::Duration(TimeUnit::Millisecond)),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(
+                "Incorrect data type for the arguments".to_string(),
+            ))
+        }
+    };
+    let lhs = unary(lhs, |x| x.as_() * scale, data_type.clone());
+    let rhs = unary(rhs, |x| x.as_() * scale, data_type.clone());
+
+    binary_checked(&lhs, &rhs, data_type, |a, b| a.checked_sub(b))
+}
+
+/// Returns the number of whole months between two datetimes, i.e. the largest `months`
+/// (in absolute value) such that `rhs + months` does not go beyond `lhs`.
+fn months_between_datetimes(lhs: NaiveDateTime, rhs: NaiveDateTime) -> Option<i32> {
+    let months = (lhs.year() - rhs.year()) * 12 + lhs.month() as i32 - rhs.month() as i32;
+    let shifted = NaiveDateTime::new(add_months(rhs.date(), months)?, rhs.time());
+    Some(if months > 0 && shifted > lhs {
+        months - 1
+    } else if months < 0 && shifted < lhs {
+        months + 1
+    } else {
+        months
+    })
+}
+
+/// Returns the number of whole calendar months from `rhs` to `lhs` as an array of
+/// type `Interval(YearMonth)`, such that adding the result to `rhs` with
+/// [`add_interval`] does not go beyond `lhs`. The result is negative when `lhs` is
+/// before `rhs`.
+///
+/// The arrays must be Date32, Date64 or Timestamp of the same type. Timestamps are
+/// compared in the local time of their timezone.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::months_between;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+///
+/// // 2021-02-28 and 2021-02-27
+/// let lhs = PrimitiveArray::from([Some(18686i32), Some(18685)]).to(DataType::Date32);
+/// // 2021-01-31 and 2021-01-28
+/// let rhs = PrimitiveArray::from([Some(18658i32), Some(18655)]).to(DataType::Date32);
+///
+/// let result = months_between(&lhs, &rhs).unwrap();
+/// let expected = PrimitiveArray::from([Some(1i32), Some(0)])
+///     .to(DataType::Interval(IntervalUnit::YearMonth));
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// Errors iff the arrays do not have the same length or data type, or the data type is not supported.
+pub fn months_between<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<i32>>
+where
+    T: NativeType + ToPrimitive,
+{
+    if lhs.data_type() != rhs.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        ));
+    }
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let (per_day, timezone) = units_per_day(lhs.data_type())?;
+
+    let result: Result<PrimitiveArray<i32>> = with_timezone!(timezone, |tz| {
+        let local = |x: &T| to_local(to_datetime(x.to_i64()?, per_day)?, tz);
+        Ok(lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| months_between_datetimes(local(lhs?)?, local(rhs?)?))
+            .collect::<PrimitiveArray<i32>>())
+    });
+    Ok(result?.to(DataType::Interval(IntervalUnit::YearMonth)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::array::PrimitiveArray;
-    use crate::datatypes::{DataType, TimeUnit};
+    use crate::datatypes::{DataType, IntervalUnit, TimeUnit};
+    use crate::types::days_ms;
 
     #[test]
     fn test_adding_timestamp() {
@@ -547,4 +960,175 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_add_interval_year_month_date32() {
+        // 2020-01-31, 2020-02-29, 2020-01-31, 2021-03-31
+        let date =
+            PrimitiveArray::from([Some(18292i32), Some(18321), Some(18292), Some(18717), None])
+                .to(DataType::Date32);
+        let months = PrimitiveArray::from([Some(1i32), Some(-12), Some(11), Some(-1), Some(1)])
+            .to(DataType::Interval(IntervalUnit::YearMonth));
+
+        let result = add_interval(&date, &months).unwrap();
+        // 2020-02-29, 2019-02-28, 2020-12-31, 2021-02-28
+        let expected =
+            PrimitiveArray::from([Some(18321i32), Some(17955), Some(18627), Some(18686), None])
+                .to(DataType::Date32);
+        assert_eq!(result, expected);
+
+        let months = PrimitiveArray::from([Some(-1i32), Some(12), Some(-11), Some(1), Some(-1)])
+            .to(DataType::Interval(IntervalUnit::YearMonth));
+        let result = subtract_interval(&date, &months).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_interval_day_time_date() {
+        let date = PrimitiveArray::from([Some(10i32), Some(i32::MAX)]).to(DataType::Date32);
+        let interval = PrimitiveArray::from([Some(days_ms::new(1, 86_399_999)); 2])
+            .to(DataType::Interval(IntervalUnit::DayTime));
+
+        // milliseconds are truncated to days and overflows are null
+        let result = add_interval(&date, &interval).unwrap();
+        let expected = PrimitiveArray::from([Some(11i32), None]).to(DataType::Date32);
+        assert_eq!(result, expected);
+
+        let date = PrimitiveArray::from([Some(86_400_000i64)]).to(DataType::Date64);
+        let result = subtract_interval(&date, &interval.slice(0, 1)).unwrap();
+        let expected = PrimitiveArray::from([Some(-86_399_999i64)]).to(DataType::Date64);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_interval_timestamp_timezone() {
+        // 2021-01-30T23:30:00Z
+        let timestamp = PrimitiveArray::from([Some(1_612_049_400i64)]);
+        let months =
+            PrimitiveArray::from([Some(1i32)]).to(DataType::Interval(IntervalUnit::YearMonth));
+
+        // 2021-01-31T00:30:00+01:00 + 1 month = 2021-02-28T00:30:00+01:00
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+        let result = add_interval(&timestamp.clone().to(data_type.clone()), &months).unwrap();
+        let expected = PrimitiveArray::from([Some(1_614_468_600i64)]).to(data_type);
+        assert_eq!(result, expected);
+
+        // 2021-01-30T23:30:00 + 1 month = 2021-02-28T23:30:00
+        let data_type = DataType::Timestamp(TimeUnit::Second, None);
+        let result = add_interval(&timestamp.to(data_type.clone()), &months).unwrap();
+        let expected = PrimitiveArray::from([Some(1_614_555_000i64)]).to(data_type);
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_add_interval_timestamp_dst() {
+        let data_type =
+            DataType::Timestamp(TimeUnit::Millisecond, Some("America/New_York".to_string()));
+        // 2021-03-13T12:00:00-05:00, the day before the DST transition
+        let timestamp = PrimitiveArray::from([Some(1_615_654_800_000i64); 2]).to(data_type.clone());
+        let interval =
+            PrimitiveArray::from([Some(days_ms::new(1, 0)), Some(days_ms::new(0, 86_400_000))])
+                .to(DataType::Interval(IntervalUnit::DayTime));
+
+        // a day keeps the local time, 2021-03-14T12:00:00-04:00, while 24 hours do not
+        let result = add_interval(&timestamp, &interval).unwrap();
+        let expected = PrimitiveArray::from([Some(1_615_737_600_000i64), Some(1_615_741_200_000)])
+            .to(data_type);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_subtract_dates() {
+        let lhs = PrimitiveArray::from([Some(10i32), Some(1), None]).to(DataType::Date32);
+        let rhs = PrimitiveArray::from([Some(9i32), Some(2), Some(1)]).to(DataType::Date32);
+        let result = subtract_dates(&lhs, &rhs).unwrap();
+        let expected = PrimitiveArray::from([Some(86_400i64), Some(-86_400), None])
+            .to(DataType::Duration(TimeUnit::Second));
+        assert_eq!(result, expected);
+
+        let lhs = PrimitiveArray::from([Some(10i64)]).to(DataType::Date64);
+        let rhs = PrimitiveArray::from([Some(4i64)]).to(DataType::Date64);
+        let result = subtract_dates(&lhs, &rhs).unwrap();
+        let expected =
+            PrimitiveArray::from([Some(6i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+        assert_eq!(result, expected);
+
+        assert!(subtract_dates(&lhs, &rhs.to(DataType::Int64)).is_err());
+
+        // overflows are null
+        let lhs = PrimitiveArray::from([Some(i64::MAX), Some(1)]).to(DataType::Date64);
+        let rhs = PrimitiveArray::from([Some(-1i64), Some(-1)]).to(DataType::Date64);
+        let result = subtract_dates(&lhs, &rhs).unwrap();
+        let expected =
+            PrimitiveArray::from([None, Some(2i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_months_between() {
+        // 2020-02-29, 2019-02-28, 2021-02-28, 2021-02-27
+        let lhs =
+            PrimitiveArray::from([Some(18321i32), Some(17955), Some(18686), Some(18685), None])
+                .to(DataType::Date32);
+        // 2020-01-31, 2020-02-29, 2021-01-31, 2021-01-31
+        let rhs = PrimitiveArray::from([
+            Some(18292i32),
+            Some(18321),
+            Some(18658),
+            Some(18658),
+            Some(1),
+        ])
+        .to(DataType::Date32);
+
+        let result = months_between(&lhs, &rhs).unwrap();
+        let expected = PrimitiveArray::from([Some(1i32), Some(-12), Some(1), Some(0), None])
+            .to(DataType::Interval(IntervalUnit::YearMonth));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_months_between_timestamp() {
+        // 2021-02-27T23:30:00Z and 2021-01-30T23:30:00Z
+        let lhs = PrimitiveArray::from([Some(1_614_468_600i64)]);
+        let rhs = PrimitiveArray::from([Some(1_612_049_400i64)]);
+        let expected = |months: i32| {
+            PrimitiveArray::from([Some(months)]).to(DataType::Interval(IntervalUnit::YearMonth))
+        };
+
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+        let result = months_between(
+            &lhs.clone().to(data_type.clone()),
+            &rhs.clone().to(data_type),
+        )
+        .unwrap();
+        assert_eq!(result, expected(1));
+
+        let data_type = DataType::Timestamp(TimeUnit::Second, None);
+        let result = months_between(&lhs.to(data_type.clone()), &rhs.to(data_type)).unwrap();
+        assert_eq!(result, expected(0));
+    }
+
+    #[test]
+    fn test_arithmetic_interval() {
+        use crate::compute::arithmetics::{arithmetic, can_arithmetic, Operator};
+
+        let date = PrimitiveArray::from([Some(18292i32)]).to(DataType::Date32);
+        let months =
+            PrimitiveArray::from([Some(1i32)]).to(DataType::Interval(IntervalUnit::YearMonth));
+        assert!(can_arithmetic(
+            date.data_type(),
+            Operator::Add,
+            months.data_type()
+        ));
+
+        let result = arithmetic(&date, Operator::Add, &months).unwrap();
+        let expected = PrimitiveArray::from([Some(18321i32)]).to(DataType::Date32);
+        assert_eq!(expected, result.as_ref());
+
+        let result = arithmetic(&expected, Operator::Subtract, &date).unwrap();
+        let expected =
+            PrimitiveArray::from([Some(29 * 86_400i64)]).to(DataType::Duration(TimeUnit::Second));
+        assert_eq!(expected, result.as_ref());
+    }
 }
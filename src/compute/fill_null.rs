@@ -0,0 +1,400 @@
+//! Contains kernels that replace null slots of arrays: [`fill_null`], [`coalesce`],
+//! [`fill_forward`] and [`fill_backward`].
+//!
+//! All kernels support every [`DataType`] supported by [`make_growable`] and return
+//! arrays without a validity whenever every null slot was replaced.
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        growable::{make_growable, Growable},
+        new_null_array, Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey,
+        ListArray, NullArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+    },
+    buffer::Buffer,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    scalar::{
+        BinaryScalar, BooleanScalar, ListScalar, PrimitiveScalar, Scalar, StructScalar, Utf8Scalar,
+    },
+};
+
+use super::concat::concatenate;
+
+/// Returns whether the kernels of this module support arrays of `data_type`.
+pub fn can_fill_null(data_type: &DataType) -> bool {
+    // there is no scalar of fixed-size binary to fill nulls with
+    !matches!(
+        data_type.to_physical_type(),
+        PhysicalType::FixedSizeBinary | PhysicalType::FixedSizeList | PhysicalType::Union
+    )
+}
+
+fn check_can_fill_null(data_type: &DataType) -> Result<()> {
+    if can_fill_null(data_type) {
+        Ok(())
+    } else {
+        Err(ArrowError::NotYetImplemented(format!(
+            "Filling nulls is not supported for data type {:?}",
+            data_type
+        )))
+    }
+}
+
+/// Builds an array out of `arrays` where slot `i` is taken from `sources[i] = Some((array, row))`
+/// or is null when `sources[i] = None`. Consecutive rows of the same array are copied at once.
+fn build<I: Iterator<Item = Option<(usize, usize)>>>(
+    arrays: &[&dyn Array],
+    length: usize,
+    sources: I,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(arrays, true, length);
+
+    // (array, start, len) of the pending run of values and the number of pending nulls.
+    // at most one of them is pending at any time.
+    let mut run: Option<(usize, usize, usize)> = None;
+    let mut nulls = 0;
+    for source in sources {
+        match source {
+            Some((array, row)) => {
+                if nulls > 0 {
+                    growable.extend_validity(nulls);
+                    nulls = 0;
+                }
+                match &mut run {
+                    Some((current, start, len)) if *current == array && *start + *len == row => {
+                        *len += 1
+                    }
+                    _ => {
+                        flush(growable.as_mut(), run);
+                        run = Some((array, row, 1));
+                    }
+                }
+            }
+            None => {
+                flush(growable.as_mut(), run.take());
+                nulls += 1;
+            }
+        }
+    }
+    flush(growable.as_mut(), run);
+    if nulls > 0 {
+        growable.extend_validity(nulls);
+    }
+
+    let result = growable.as_box();
+    if result.validity().is_some() && result.null_count() == 0 {
+        result.with_validity(None)
+    } else {
+        result
+    }
+}
+
+#[inline]
+fn flush(growable: &mut dyn Growable, run: Option<(usize, usize, usize)>) {
+    if let Some((array, start, len)) = run {
+        growable.extend(array, start, len)
+    }
+}
+
+fn primitive_to_array<T: crate::types::NativeType>(
+    scalar: &dyn Scalar,
+    data_type: &DataType,
+) -> Box<dyn Array> {
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap();
+    Box::new(PrimitiveArray::<T>::from_slice([scalar.value()]).to(data_type.clone()))
+}
+
+fn utf8_to_array<O: Offset>(scalar: &dyn Scalar, data_type: &DataType) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    let value = scalar.value().as_bytes();
+    let offsets = Buffer::from(&[O::zero(), O::from_usize(value.len()).unwrap()]);
+    Box::new(Utf8Array::<O>::from_data(
+        data_type.clone(),
+        offsets,
+        Buffer::from(value),
+        None,
+    ))
+}
+
+fn binary_to_array<O: Offset>(scalar: &dyn Scalar, data_type: &DataType) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    let value = scalar.value();
+    let offsets = Buffer::from(&[O::zero(), O::from_usize(value.len()).unwrap()]);
+    Box::new(BinaryArray::<O>::from_data(
+        data_type.clone(),
+        offsets,
+        Buffer::from(value),
+        None,
+    ))
+}
+
+fn list_to_array<O: Offset>(scalar: &dyn Scalar, data_type: &DataType) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<ListScalar<O>>().unwrap();
+    let values = scalar.values().clone();
+    let offsets = Buffer::from(&[O::zero(), O::from_usize(values.len()).unwrap()]);
+    Box::new(ListArray::<O>::from_data(
+        data_type.clone(),
+        offsets,
+        values,
+        None,
+    ))
+}
+
+fn struct_to_array(scalar: &dyn Scalar, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+    let values = scalar
+        .values()
+        .iter()
+        .zip(StructArray::get_fields(data_type).iter())
+        .map(|(value, field)| to_array(value.as_ref(), field.data_type()).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(StructArray::from_data(
+        data_type.clone(),
+        values,
+        None,
+    )))
+}
+
+/// Converts a [`Scalar`] into an [`Array`] of length 1 and type `data_type`, whose logical
+/// type must be the one of the scalar (e.g. an extension of the scalar's type).
+fn to_array(scalar: &dyn Scalar, data_type: &DataType) -> Result<Box<dyn Array>> {
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type.clone(), 1));
+    }
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(NullArray::from_data(data_type.clone(), 1)),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let array = BooleanArray::from_slice([scalar.value()]);
+            Box::new(BooleanArray::from_data(
+                data_type.clone(),
+                array.values().clone(),
+                None,
+            ))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_to_array::<$T>(scalar, data_type)
+        }),
+        Utf8 => utf8_to_array::<i32>(scalar, data_type),
+        LargeUtf8 => utf8_to_array::<i64>(scalar, data_type),
+        Binary => binary_to_array::<i32>(scalar, data_type),
+        LargeBinary => binary_to_array::<i64>(scalar, data_type),
+        List => list_to_array::<i32>(scalar, data_type),
+        LargeList => list_to_array::<i64>(scalar, data_type),
+        Struct => struct_to_array(scalar, data_type)?,
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Scalars of data type {:?} are not supported",
+                data_type
+            )))
+        }
+    })
+}
+
+fn fill_null_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    value: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    let values = concatenate(&[array.values().as_ref(), value])?;
+    let key = K::from_usize(values.len() - 1).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "The fill value does not fit in the dictionary's key type".to_string(),
+        )
+    })?;
+
+    let keys = array.keys();
+    let keys = keys
+        .iter()
+        .map(|k| *k.unwrap_or(&key))
+        .collect::<Buffer<K>>();
+    let keys = PrimitiveArray::<K>::from_data(K::DATA_TYPE, keys, None);
+    Ok(Box::new(DictionaryArray::<K>::from_data(
+        keys,
+        values.into(),
+    )))
+}
+
+/// Returns `array` with its null slots replaced by `value`.
+///
+/// `value` must have the same [`DataType`] as `array` or, when `array` is a dictionary, the
+/// [`DataType`] of its values. A null `value` returns a copy of `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_null;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let value = PrimitiveScalar::new(DataType::Int32, Some(0));
+///
+/// let result = fill_null(&array, &value)?;
+/// assert_eq!(Int32Array::from_slice(&[1, 0, 3]), result.as_ref());
+/// assert!(result.validity().is_none());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the types do not match or are not supported (see [`can_fill_null`]).
+pub fn fill_null(array: &dyn Array, value: &dyn Scalar) -> Result<Box<dyn Array>> {
+    check_can_fill_null(array.data_type())?;
+    // the type of the array the value is filled into
+    let target = match array.data_type().to_logical_type() {
+        DataType::Dictionary(_, values) => values.as_ref(),
+        _ => array.data_type(),
+    };
+    let expected = target.to_logical_type();
+    if value.data_type().to_logical_type() != expected {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "fill_null requires the value to be of type {:?} (got {:?})",
+            expected,
+            value.data_type()
+        )));
+    }
+    if array.null_count() == 0 || !value.is_valid() {
+        return Ok(crate::array::clone(array));
+    }
+    let value = to_array(value, target)?;
+
+    if let PhysicalType::Dictionary(key_type) = array.data_type().to_physical_type() {
+        return with_match_physical_dictionary_key_type!(key_type, |$T| {
+            fill_null_dictionary::<$T>(array.as_any().downcast_ref().unwrap(), value.as_ref())
+        });
+    }
+
+    let sources = (0..array.len()).map(|i| {
+        if array.is_valid(i) {
+            Some((0, i))
+        } else {
+            Some((1, 0))
+        }
+    });
+    Ok(build(&[array, value.as_ref()], array.len(), sources))
+}
+
+/// Returns an array whose slot `i` is the first non-null slot `i` of `arrays`, or null
+/// if slot `i` is null in all of them. This is equivalent to SQL's `COALESCE`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::coalesce;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[Some(4), Some(5), None]);
+///
+/// let result = coalesce(&[&a, &b])?;
+/// assert_eq!(Int32Array::from(&[Some(1), Some(5), None]), result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff `arrays` is empty, the arrays do not have the same [`DataType`] and length, or
+/// the [`DataType`] is not supported (see [`can_fill_null`]).
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("coalesce requires at least one array".to_string())
+    })?;
+    check_can_fill_null(first.data_type())?;
+    if arrays
+        .iter()
+        .any(|array| array.data_type() != first.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "coalesce requires all arrays to have the same data type".to_string(),
+        ));
+    }
+    if arrays.iter().any(|array| array.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "coalesce requires all arrays to have the same length".to_string(),
+        ));
+    }
+
+    let sources = (0..first.len()).map(|i| {
+        arrays
+            .iter()
+            .position(|array| array.is_valid(i))
+            .map(|array| (array, i))
+    });
+    Ok(build(arrays, first.len(), sources))
+}
+
+/// Returns `array` with every null slot replaced by the closest previous non-null slot.
+/// When `limit` is set, at most `limit` consecutive nulls are replaced after each non-null slot.
+/// Leading nulls remain null.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_forward;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from(&[None, Some(1), None, None, Some(2)]);
+///
+/// let result = fill_forward(&array, Some(1))?;
+/// assert_eq!(Int32Array::from(&[None, Some(1), Some(1), None, Some(2)]), result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the [`DataType`] is not supported (see [`can_fill_null`]).
+pub fn fill_forward(array: &dyn Array, limit: Option<usize>) -> Result<Box<dyn Array>> {
+    check_can_fill_null(array.data_type())?;
+    if array.null_count() == 0 {
+        return Ok(crate::array::clone(array));
+    }
+
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut last = None;
+    let sources = (0..array.len()).map(|i| {
+        if array.is_valid(i) {
+            last = Some(i);
+            Some((0, i))
+        } else {
+            last.filter(|last| i - last <= limit).map(|last| (0, last))
+        }
+    });
+    Ok(build(&[array], array.len(), sources))
+}
+
+/// Returns `array` with every null slot replaced by the closest next non-null slot.
+/// When `limit` is set, at most `limit` consecutive nulls are replaced before each non-null slot.
+/// Trailing nulls remain null.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_backward;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from(&[Some(1), None, None, Some(2), None]);
+///
+/// let result = fill_backward(&array, Some(1))?;
+/// assert_eq!(Int32Array::from(&[Some(1), None, Some(2), Some(2), None]), result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the [`DataType`] is not supported (see [`can_fill_null`]).
+pub fn fill_backward(array: &dyn Array, limit: Option<usize>) -> Result<Box<dyn Array>> {
+    check_can_fill_null(array.data_type())?;
+    if array.null_count() == 0 {
+        return Ok(crate::array::clone(array));
+    }
+
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut next = None;
+    let mut sources = (0..array.len())
+        .rev()
+        .map(|i| {
+            if array.is_valid(i) {
+                next = Some(i);
+                Some((0, i))
+            } else {
+                next.filter(|next| next - i <= limit).map(|next| (0, next))
+            }
+        })
+        .collect::<Vec<_>>();
+    sources.reverse();
+    Ok(build(&[array], array.len(), sources.into_iter()))
+}
@@ -0,0 +1,431 @@
+//! Contains the [`is_in`] kernel and its statically-typed variants, that check whether each
+//! value of an array is a member of a set of values.
+//!
+//! The set is hashed once, after which every value is probed against it. This makes these
+//! kernels suitable for filters of the form `x IN (...)` with many elements.
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::{
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+        Offset, PrimitiveArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{ArrowError, Result},
+    types::{days_ms, NativeType},
+};
+
+use super::{cast::cast, utils::combine_validities};
+
+#[cfg(feature = "ahash")]
+type Set<K> = HashSet<K, ahash::RandomState>;
+#[cfg(not(feature = "ahash"))]
+type Set<K> = HashSet<K>;
+
+/// Declares how [`is_in`] handles nulls, both in the values and in the set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullBehavior {
+    /// SQL's three-valued logic of `x IN (...)`:
+    /// * a null value results in null
+    /// * a value found in the set results in `true`
+    /// * a value not found in the set results in null if the set contains a null, and `false`
+    ///   otherwise.
+    ThreeValued,
+    /// Nulls in the set are ignored and null values result in `false`. The result has no nulls.
+    Skip,
+}
+
+// `#[derive(Default)]` on enums requires `#[default]`, only available from Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for NullBehavior {
+    fn default() -> Self {
+        Self::ThreeValued
+    }
+}
+
+/// A value that can be hashed and compared for membership.
+trait MemberKey: NativeType {
+    type Key: Hash + Eq;
+
+    fn key(&self) -> Self::Key;
+}
+
+macro_rules! member_key {
+    ($type:ty) => {
+        impl MemberKey for $type {
+            type Key = $type;
+
+            #[inline]
+            fn key(&self) -> Self::Key {
+                *self
+            }
+        }
+    };
+}
+
+member_key!(u8);
+member_key!(u16);
+member_key!(u32);
+member_key!(u64);
+member_key!(i8);
+member_key!(i16);
+member_key!(i32);
+member_key!(i64);
+member_key!(i128);
+member_key!(days_ms);
+
+// floats are compared by their bits, where `-0.0` is equal to `0.0` and all NaNs are equal.
+impl MemberKey for f32 {
+    type Key = u32;
+
+    #[inline]
+    fn key(&self) -> Self::Key {
+        if self.is_nan() {
+            f32::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl MemberKey for f64 {
+    type Key = u64;
+
+    #[inline]
+    fn key(&self) -> Self::Key {
+        if self.is_nan() {
+            f64::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+/// Builds the result from the bitmap of values found in the set.
+fn finish(
+    found: Bitmap,
+    validity: &Option<Bitmap>,
+    set_has_nulls: bool,
+    nulls: NullBehavior,
+) -> BooleanArray {
+    match nulls {
+        NullBehavior::ThreeValued => {
+            let validity = if set_has_nulls {
+                // values not found are unknown
+                combine_validities(validity, &Some(found.clone()))
+            } else {
+                validity.clone()
+            };
+            BooleanArray::from_data(DataType::Boolean, found, validity)
+        }
+        NullBehavior::Skip => {
+            let values = match validity {
+                Some(validity) => &found & validity,
+                None => found,
+            };
+            BooleanArray::from_data(DataType::Boolean, values, None)
+        }
+    }
+}
+
+fn check_types(values: &dyn Array, set: &dyn Array) -> Result<()> {
+    if values.data_type() != set.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "is_in requires the values and the set to be of the same logical type, but got {:?} and {:?}",
+            values.data_type(),
+            set.data_type()
+        )));
+    }
+    Ok(())
+}
+
+#[inline]
+fn is_in_primitive_impl<T: MemberKey>(
+    values: &PrimitiveArray<T>,
+    set: &PrimitiveArray<T>,
+    nulls: NullBehavior,
+) -> BooleanArray {
+    let hashed = set.iter().flatten().map(|x| x.key()).collect::<Set<_>>();
+    let found = Bitmap::from_trusted_len_iter(
+        values
+            .values()
+            .iter()
+            .map(|value| hashed.contains(&value.key())),
+    );
+    finish(found, values.validity(), set.null_count() > 0, nulls)
+}
+
+/// Returns whether each value of a [`PrimitiveArray`] is in `set`.
+/// Floats are compared by value, where `-0.0` equals `0.0` and `NaN` equals `NaN`.
+/// # Error
+/// Errors iff the arrays have different logical types.
+pub fn is_in_primitive<T: NativeType>(
+    values: &PrimitiveArray<T>,
+    set: &PrimitiveArray<T>,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    check_types(values, set)?;
+    is_in_dyn(values, set, nulls)
+}
+
+/// Returns whether each value of a [`BooleanArray`] is in `set`.
+/// # Error
+/// Errors iff the arrays have different logical types.
+pub fn is_in_boolean(
+    values: &BooleanArray,
+    set: &BooleanArray,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    check_types(values, set)?;
+    let has_true = set.iter().any(|x| x == Some(true));
+    let has_false = set.iter().any(|x| x == Some(false));
+    let found = match (has_true, has_false) {
+        (true, true) => !&Bitmap::new_zeroed(values.len()),
+        (false, false) => Bitmap::new_zeroed(values.len()),
+        (true, false) => values.values().clone(),
+        (false, true) => !values.values(),
+    };
+    Ok(finish(
+        found,
+        values.validity(),
+        set.null_count() > 0,
+        nulls,
+    ))
+}
+
+/// Returns whether each value of a [`Utf8Array`] is in `set`.
+/// # Error
+/// Errors iff the arrays have different logical types.
+pub fn is_in_utf8<O: Offset>(
+    values: &Utf8Array<O>,
+    set: &Utf8Array<O>,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    check_types(values, set)?;
+    let hashed = set.iter().flatten().collect::<Set<_>>();
+    let found =
+        Bitmap::from_trusted_len_iter(values.values_iter().map(|value| hashed.contains(value)));
+    Ok(finish(
+        found,
+        values.validity(),
+        set.null_count() > 0,
+        nulls,
+    ))
+}
+
+/// Returns whether each value of a [`BinaryArray`] is in `set`.
+/// # Error
+/// Errors iff the arrays have different logical types.
+pub fn is_in_binary<O: Offset>(
+    values: &BinaryArray<O>,
+    set: &BinaryArray<O>,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    check_types(values, set)?;
+    let hashed = set.iter().flatten().collect::<Set<_>>();
+    let found =
+        Bitmap::from_trusted_len_iter(values.values_iter().map(|value| hashed.contains(value)));
+    Ok(finish(
+        found,
+        values.validity(),
+        set.null_count() > 0,
+        nulls,
+    ))
+}
+
+/// Returns whether each value of a [`FixedSizeBinaryArray`] is in `set`.
+/// # Error
+/// Errors iff the arrays have different logical types.
+pub fn is_in_fixed_size_binary(
+    values: &FixedSizeBinaryArray,
+    set: &FixedSizeBinaryArray,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    check_types(values, set)?;
+    let hashed = set.iter().flatten().collect::<Set<_>>();
+    let found = (0..values.len())
+        .map(|i| hashed.contains(values.value(i)))
+        .collect::<Bitmap>();
+    Ok(finish(
+        found,
+        values.validity(),
+        set.null_count() > 0,
+        nulls,
+    ))
+}
+
+/// Returns whether each value of a [`DictionaryArray`] is in `set`, where `set` is an array
+/// of the dictionary's values' logical type.
+/// # Implementation
+/// Each of the dictionary's values is probed once, after which the result is taken
+/// according to the keys.
+/// # Error
+/// Errors iff the `set`'s logical type differs from the dictionary's values' logical type.
+pub fn is_in_dictionary<K: DictionaryKey>(
+    values: &DictionaryArray<K>,
+    set: &dyn Array,
+    nulls: NullBehavior,
+) -> Result<BooleanArray> {
+    let dictionary = is_in(values.values().as_ref(), set, NullBehavior::ThreeValued)?;
+    let result = values
+        .keys()
+        .iter()
+        .map(|key| {
+            key.and_then(|key| {
+                let key = key.to_usize().unwrap();
+                if dictionary.is_valid(key) {
+                    Some(dictionary.value(key))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect::<BooleanArray>();
+    Ok(match nulls {
+        NullBehavior::ThreeValued => result,
+        NullBehavior::Skip => finish(result.values().clone(), result.validity(), false, nulls),
+    })
+}
+
+/// Returns whether each value of `values` is in `set`, following the null semantics of
+/// [`NullBehavior`]. This is equivalent to SQL's `values IN (set)`.
+///
+/// `set` must have the same logical type as `values` or, when `values` is a dictionary,
+/// the logical type of the dictionary's values. `set` may itself be a dictionary.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::is_in::{is_in, NullBehavior};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let values = Int32Array::from(&[Some(1), Some(2), None]);
+/// let set = Int32Array::from(&[Some(1), Some(5)]);
+///
+/// let result = is_in(&values, &set, NullBehavior::ThreeValued)?;
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+///
+/// let result = is_in(&values, &set, NullBehavior::Skip)?;
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the types are not supported (see [`can_is_in`]) or do not match.
+pub fn is_in(values: &dyn Array, set: &dyn Array, nulls: NullBehavior) -> Result<BooleanArray> {
+    if let DataType::Dictionary(_, values_type) = set.data_type().to_logical_type() {
+        // hash the logical values of the set
+        let set = cast(set, values_type.as_ref())?;
+        return is_in(values, set.as_ref(), nulls);
+    }
+    if !can_is_in(values.data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "is_in is not supported for data type {:?}",
+            values.data_type()
+        )));
+    }
+    if !matches!(
+        values.data_type().to_physical_type(),
+        PhysicalType::Dictionary(_)
+    ) {
+        check_types(values, set)?;
+    }
+    is_in_dyn(values, set, nulls)
+}
+
+macro_rules! primitive {
+    ($type:ty, $values:expr, $set:expr, $nulls:expr) => {{
+        let values = $values.as_any().downcast_ref().unwrap();
+        let set = $set.as_any().downcast_ref().unwrap();
+        is_in_primitive_impl::<$type>(values, set, $nulls)
+    }};
+}
+
+// assumes that the types have been checked
+fn is_in_dyn(values: &dyn Array, set: &dyn Array, nulls: NullBehavior) -> Result<BooleanArray> {
+    use PhysicalType::*;
+    match values.data_type().to_physical_type() {
+        Boolean => is_in_boolean(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        Primitive(primitive) => Ok(match primitive {
+            PrimitiveType::Int8 => primitive!(i8, values, set, nulls),
+            PrimitiveType::Int16 => primitive!(i16, values, set, nulls),
+            PrimitiveType::Int32 => primitive!(i32, values, set, nulls),
+            PrimitiveType::Int64 => primitive!(i64, values, set, nulls),
+            PrimitiveType::Int128 => primitive!(i128, values, set, nulls),
+            PrimitiveType::UInt8 => primitive!(u8, values, set, nulls),
+            PrimitiveType::UInt16 => primitive!(u16, values, set, nulls),
+            PrimitiveType::UInt32 => primitive!(u32, values, set, nulls),
+            PrimitiveType::UInt64 => primitive!(u64, values, set, nulls),
+            PrimitiveType::Float32 => primitive!(f32, values, set, nulls),
+            PrimitiveType::Float64 => primitive!(f64, values, set, nulls),
+            PrimitiveType::DaysMs => primitive!(days_ms, values, set, nulls),
+            PrimitiveType::MonthDayNano => {
+                return Err(ArrowError::NotYetImplemented(
+                    "is_in is not supported for MonthDayNano intervals".to_string(),
+                ))
+            }
+        }),
+        Utf8 => is_in_utf8::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        LargeUtf8 => is_in_utf8::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        Binary => is_in_binary::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        LargeBinary => is_in_binary::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        FixedSizeBinary => is_in_fixed_size_binary(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            nulls,
+        ),
+        Dictionary(key_type) => with_match_physical_dictionary_key_type!(key_type, |$T| {
+            is_in_dictionary::<$T>(values.as_any().downcast_ref().unwrap(), set, nulls)
+        }),
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "is_in is not supported for physical type {:?}",
+            t
+        ))),
+    }
+}
+
+/// Returns whether [`is_in`] supports arrays of type `data_type`.
+/// # Examples
+/// ```
+/// use arrow2::compute::is_in::can_is_in;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_is_in(&DataType::Utf8), true);
+/// assert_eq!(can_is_in(&DataType::Null), false);
+/// ```
+pub fn can_is_in(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Boolean | Utf8 | LargeUtf8 | Binary | LargeBinary | FixedSizeBinary => true,
+        Primitive(primitive) => primitive != PrimitiveType::MonthDayNano,
+        Dictionary(_) => match data_type.to_logical_type() {
+            DataType::Dictionary(_, values) => can_is_in(values.as_ref()),
+            _ => unreachable!(),
+        },
+        _ => false,
+    }
+}
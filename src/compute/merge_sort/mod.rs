@@ -56,6 +56,7 @@
 //! To serialize slices, e.g. for checkpointing or transfer via Arrow's IPC, you can store
 //! them as 3 non-null primitive arrays (e.g. `PrimitiveArray<i64>`).
 
+use std::cell::RefCell;
 use std::iter::once;
 use std::{cmp::Ordering, collections::HashMap};
 
@@ -63,10 +64,11 @@ use itertools::Itertools;
 
 use crate::array::{
     growable::make_growable,
-    ord::{build_compare, DynComparator},
+    ord::{build_compare_with_nulls, DynComparator},
     Array,
 };
 pub use crate::compute::sort::SortOptions;
+use crate::compute::sort::{row, SortColumn};
 use crate::error::Result;
 
 /// A slice denoting `(array_index, start, len)` representing a slice from one of N arrays.
@@ -467,9 +469,21 @@ type Comparator<'a> = Box<dyn Fn(usize, usize, usize, usize) -> Ordering + 'a>;
 type IsValid<'a> = Box<dyn Fn(usize) -> bool + 'a>;
 
 /// returns a comparison function between any two arrays of each pair of arrays, according to `SortOptions`.
+/// # Implementation
+/// When there is more than one pair and all arrays can be encoded as [`row::Rows`], each set of
+/// arrays is encoded into rows and compared byte-wise, which avoids one dynamically dispatched
+/// comparison per array.
 pub fn build_comparator<'a>(
     pairs: &'a [(&'a [&'a dyn Array], &SortOptions)],
 ) -> Result<Comparator<'a>> {
+    if pairs.len() > 1
+        && pairs
+            .iter()
+            .all(|(arrays, _)| arrays.iter().all(|a| row::can_encode(a.data_type())))
+    {
+        return build_row_comparator(pairs);
+    }
+
     // prepare the comparison function of _values_ between all pairs of arrays
     let indices_pairs = (0..pairs[0].0.len())
         .combinations(2)
@@ -479,11 +493,17 @@ pub fn build_comparator<'a>(
         .map(|(lhs_index, rhs_index)| {
             let multi_column_comparator = pairs
                 .iter()
-                .map(move |(arrays, _)| {
+                .map(move |(arrays, options)| {
+                    // nested nulls are ordered before the comparison is reversed
+                    let nulls_first = options.nulls_first != options.descending;
                     Ok((
                         Box::new(move |row| arrays[lhs_index].is_valid(row)) as IsValid<'a>,
                         Box::new(move |row| arrays[rhs_index].is_valid(row)) as IsValid<'a>,
-                        build_compare(arrays[lhs_index], arrays[rhs_index])?,
+                        build_compare_with_nulls(
+                            arrays[lhs_index],
+                            arrays[rhs_index],
+                            nulls_first,
+                        )?,
                     ))
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -529,3 +549,73 @@ pub fn build_comparator<'a>(
     };
     Ok(Box::new(cmp))
 }
+
+/// The number of rows of each input encoded at a time by [`build_row_comparator`]
+const ROWS_PER_BLOCK: usize = 1024;
+
+fn sort_columns(columns: &[(Box<dyn Array>, SortOptions)]) -> Vec<SortColumn<'_>> {
+    columns
+        .iter()
+        .map(|(values, options)| SortColumn {
+            values: values.as_ref(),
+            options: Some(*options),
+        })
+        .collect()
+}
+
+/// Returns a comparator of rows encoded by [`row::encode`]. Each input is encoded in blocks of
+/// [`ROWS_PER_BLOCK`] rows when the comparator first reaches them, so that merging only a
+/// prefix of the inputs (e.g. with a `limit`) only encodes that prefix.
+fn build_row_comparator<'a>(
+    pairs: &'a [(&'a [&'a dyn Array], &SortOptions)],
+) -> Result<Comparator<'a>> {
+    // in a merge-sort, `descending` also reverses the order of nulls
+    let options = pairs
+        .iter()
+        .map(|(_, options)| SortOptions {
+            descending: options.descending,
+            nulls_first: options.nulls_first != options.descending,
+        })
+        .collect::<Vec<_>>();
+    let num_inputs = pairs[0].0.len();
+
+    // the sort columns of the rows `[start, start + length)` of input `index`
+    let columns = move |index: usize, start: usize, length: usize| {
+        pairs
+            .iter()
+            .zip(options.iter())
+            .map(|((arrays, _), options)| (arrays[index].slice(start, length), *options))
+            .collect::<Vec<_>>()
+    };
+
+    // errors (e.g. out of bounds dictionary keys) are returned here, before any comparison
+    (0..num_inputs).try_for_each(|index| {
+        let columns = columns(index, 0, pairs[0].0[index].len());
+        row::validate(&sort_columns(&columns))
+    })?;
+
+    let blocks = RefCell::new(vec![Vec::<row::Rows>::new(); num_inputs]);
+    let encode_up_to = move |blocks: &RefCell<Vec<Vec<row::Rows>>>, index: usize, row: usize| {
+        let mut blocks = blocks.borrow_mut();
+        let input = &mut blocks[index];
+        while input.len() <= row / ROWS_PER_BLOCK {
+            let start = input.len() * ROWS_PER_BLOCK;
+            let length = ROWS_PER_BLOCK.min(pairs[0].0[index].len() - start);
+            let columns = columns(index, start, length);
+            // the inputs were validated above
+            input.push(row::encode(&sort_columns(&columns)).unwrap());
+        }
+    };
+
+    Ok(Box::new(
+        move |left_index, left_row, right_index, right_row| {
+            encode_up_to(&blocks, left_index, left_row);
+            encode_up_to(&blocks, right_index, right_row);
+            let blocks = blocks.borrow();
+            let left = blocks[left_index][left_row / ROWS_PER_BLOCK].row(left_row % ROWS_PER_BLOCK);
+            let right =
+                blocks[right_index][right_row / ROWS_PER_BLOCK].row(right_row % ROWS_PER_BLOCK);
+            left.cmp(right)
+        },
+    ))
+}
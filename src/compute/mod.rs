@@ -16,9 +16,11 @@ pub mod cast;
 pub mod comparison;
 pub mod concat;
 pub mod contains;
+pub mod fill_null;
 pub mod filter;
 pub mod hash;
 pub mod if_then_else;
+pub mod is_in;
 pub mod length;
 pub mod limit;
 pub mod nullif;
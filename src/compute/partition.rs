@@ -17,7 +17,7 @@
 
 //! Defines partition kernel for `ArrayRef`
 
-use crate::compute::sort::{build_compare, Compare, SortColumn};
+use crate::compute::sort::{build_compare, row, Compare, SortColumn};
 use crate::error::{ArrowError, Result};
 use std::cmp::Ordering;
 use std::iter::Iterator;
@@ -60,21 +60,30 @@ impl<'a> LexicographicalPartitionIterator<'a> {
             ));
         };
 
-        let comparators = columns
+        let comparator: Compare<'a> = if columns
             .iter()
-            .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
-            .collect::<Result<Vec<_>>>()?;
+            .all(|column| row::can_encode(column.values.data_type()))
+        {
+            // fast path: compare rows of bytes instead of each column via dynamic dispatch
+            let rows = row::encode(columns)?;
+            Box::new(move |a_idx: usize, b_idx: usize| rows.compare(a_idx, b_idx))
+        } else {
+            let comparators = columns
+                .iter()
+                .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
+                .collect::<Result<Vec<_>>>()?;
 
-        let comparator = Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
-            for comparator in comparators.iter() {
-                match comparator(a_idx, b_idx) {
-                    Ordering::Equal => continue,
-                    other => return other,
+            Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
+                for comparator in comparators.iter() {
+                    match comparator(a_idx, b_idx) {
+                        Ordering::Equal => continue,
+                        other => return other,
+                    }
                 }
-            }
 
-            Ordering::Equal
-        });
+                Ordering::Equal
+            })
+        };
 
         let value_indices = (0..num_rows).collect::<Vec<usize>>();
         Ok(Self {
@@ -7,6 +7,9 @@ use crate::{
 use super::SortOptions;
 
 /// Returns the indices that would sort a [`BooleanArray`].
+/// # Implementation
+/// Since a boolean only has two values, this is a stable partition of the valid indices
+/// and runs in `O(N)`. When `limit` is set, only the first `limit` indices are materialized.
 pub fn sort_boolean<I: Index>(
     values: &BooleanArray,
     value_indices: Vec<I>,
@@ -16,38 +19,27 @@ pub fn sort_boolean<I: Index>(
 ) -> PrimitiveArray<I> {
     let descending = options.descending;
 
-    // create tuples that are used for sorting
-    let mut valids = value_indices
+    let (falses, trues): (Vec<I>, Vec<I>) = value_indices
         .into_iter()
-        .map(|index| (index, values.value(index.to_usize())))
-        .collect::<Vec<(I, bool)>>();
+        .partition(|index| !values.value(index.to_usize()));
 
     let mut nulls = null_indices;
 
-    if !descending {
-        valids.sort_by(|a, b| a.1.cmp(&b.1));
+    let valids = if !descending {
+        falses.into_iter().chain(trues)
     } else {
-        valids.sort_by(|a, b| b.1.cmp(&a.1));
         // reverse to keep a stable ordering
         nulls.reverse();
-    }
+        trues.into_iter().chain(falses)
+    };
 
-    let mut values = MutableBuffer::<I>::with_capacity(values.len());
+    let limit = limit.unwrap_or_else(|| values.len()).min(values.len());
 
-    if options.nulls_first {
-        values.extend_from_slice(nulls.as_slice());
-        valids.iter().for_each(|x| values.push(x.0));
+    let values: MutableBuffer<I> = if options.nulls_first {
+        nulls.into_iter().chain(valids).take(limit).collect()
     } else {
-        // nulls last
-        valids.iter().for_each(|x| values.push(x.0));
-        values.extend_from_slice(nulls.as_slice());
-    }
-
-    // un-efficient; there are much more performant ways of sorting nulls above, anyways.
-    if let Some(limit) = limit {
-        values.truncate(limit);
-        values.shrink_to_fit();
-    }
+        valids.chain(nulls).take(limit).collect()
+    };
 
     PrimitiveArray::<I>::from_data(I::DATA_TYPE, values.into(), None)
 }
@@ -0,0 +1,46 @@
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::error::Result;
+use crate::types::Index;
+
+use super::{common, sort_to_indices, SortOptions};
+
+/// Returns the indices that would sort a [`DictionaryArray`] whose values can be sorted.
+/// # Implementation
+/// The dictionary's values are sorted once and the position of each value in that order
+/// (its rank) is used to order the keys. This makes every comparison an integer comparison,
+/// irrespectively of the values' type.
+pub(super) fn indices_sorted_unstable_by<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let values = array.values();
+    // the ranks are compared in reverse when descending, so null values must be ranked
+    // accordingly for `nulls_first` to hold for them
+    let rank_options = SortOptions {
+        descending: false,
+        nulls_first: options.nulls_first != options.descending,
+    };
+    let order = sort_to_indices::<u64>(values.as_ref(), &rank_options, None)?;
+
+    let mut ranks = vec![0u64; values.len()];
+    order
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(rank, index)| ranks[*index as usize] = rank as u64);
+
+    let keys = array.keys().values();
+    // Note: there is no check that the keys are within bounds of the dictionary, which
+    // results in a panic when they are not.
+    let get = |idx: usize| ranks[keys[idx].to_usize().unwrap()];
+    let cmp = |lhs: &u64, rhs: &u64| lhs.cmp(rhs);
+    Ok(common::indices_sorted_unstable_by(
+        array.validity(),
+        get,
+        cmp,
+        array.len(),
+        options,
+        limit,
+    ))
+}
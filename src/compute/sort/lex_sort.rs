@@ -8,7 +8,7 @@ use crate::{
     types::Index,
 };
 
-use super::{sort_to_indices, SortOptions};
+use super::{row, sort_to_indices, SortOptions};
 
 type IsValid<'a> = Box<dyn Fn(usize) -> bool + 'a>;
 
@@ -77,9 +77,13 @@ fn build_is_valid(array: &dyn Array) -> IsValid {
 
 pub(crate) type Compare<'a> = Box<dyn Fn(usize, usize) -> Ordering + 'a>;
 
+type IndexCompare<'a, I> = Box<dyn Fn(&I, &I) -> Ordering + 'a>;
+
 pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Result<Compare> {
     let is_valid = build_is_valid(array);
-    let comparator = ord::build_compare(array, array)?;
+    // the comparison is reversed when descending, so must be the order of nested nulls
+    let nulls_first = sort_option.nulls_first != sort_option.descending;
+    let comparator = ord::build_compare_with_nulls(array, array, nulls_first)?;
 
     Ok(match (sort_option.descending, sort_option.nulls_first) {
         (true, true) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
@@ -145,25 +149,36 @@ pub fn lexsort_to_indices<I: Index>(
         ));
     };
 
-    // map arrays to comparators
-    let comparators = columns
+    let rows;
+    let comparators;
+    let lex_comparator: IndexCompare<'_, I> = if columns
         .iter()
-        .map(|column| -> Result<Compare> {
-            build_compare(column.values, column.options.unwrap_or_default())
-        })
-        .collect::<Result<Vec<Compare>>>()?;
+        .all(|column| row::can_encode(column.values.data_type()))
+    {
+        // fast path: compare rows of bytes instead of each column via dynamic dispatch
+        rows = row::encode(columns)?;
+        Box::new(|a_idx: &I, b_idx: &I| rows.compare(a_idx.to_usize(), b_idx.to_usize()))
+    } else {
+        // map arrays to comparators
+        comparators = columns
+            .iter()
+            .map(|column| -> Result<Compare> {
+                build_compare(column.values, column.options.unwrap_or_default())
+            })
+            .collect::<Result<Vec<Compare>>>()?;
 
-    let lex_comparator = |a_idx: &I, b_idx: &I| -> Ordering {
-        let a_idx = a_idx.to_usize();
-        let b_idx = b_idx.to_usize();
-        for comparator in comparators.iter() {
-            match comparator(a_idx, b_idx) {
-                Ordering::Equal => continue,
-                other => return other,
+        Box::new(|a_idx: &I, b_idx: &I| -> Ordering {
+            let a_idx = a_idx.to_usize();
+            let b_idx = b_idx.to_usize();
+            for comparator in comparators.iter() {
+                match comparator(a_idx, b_idx) {
+                    Ordering::Equal => continue,
+                    other => return other,
+                }
             }
-        }
 
-        Ordering::Equal
+            Ordering::Equal
+        })
     };
 
     // Safety: `0..row_count` is TrustedLen
@@ -173,14 +188,14 @@ pub fn lexsort_to_indices<I: Index>(
         )
     };
 
+    let limit = limit.filter(|limit| *limit < row_count);
     if let Some(limit) = limit {
-        let limit = limit.min(row_count);
-        let (before, _, _) = values.select_nth_unstable_by(limit, lex_comparator);
-        before.sort_unstable_by(lex_comparator);
+        let (before, _, _) = values.select_nth_unstable_by(limit, &lex_comparator);
+        before.sort_unstable_by(&lex_comparator);
         values.truncate(limit);
         values.shrink_to_fit();
     } else {
-        values.sort_unstable_by(lex_comparator);
+        values.sort_unstable_by(&lex_comparator);
     }
 
     Ok(PrimitiveArray::<I>::from_data(
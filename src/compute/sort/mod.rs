@@ -13,8 +13,12 @@ use crate::{
 mod binary;
 mod boolean;
 mod common;
+mod dictionary;
+mod fixed_size_binary;
 mod lex_sort;
 mod primitive;
+pub mod row;
+mod struct_;
 mod utf8;
 
 pub(crate) use lex_sort::{build_compare, Compare};
@@ -55,8 +59,9 @@ pub fn sort(
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_) => dyn_sort!(i64, values, ord::total_cmp, options, limit),
+        DataType::Decimal(_, _) => dyn_sort!(i128, values, ord::total_cmp, options, limit),
         DataType::UInt8 => dyn_sort!(u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
@@ -94,6 +99,10 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+/// When `limit` is set, only the first `limit` indices are returned, which are computed via
+/// a partial selection (top-k) instead of a sort of all elements.
+/// # Errors
+/// Errors if the [`DataType`] is not supported (see [`can_sort`]).
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -121,10 +130,13 @@ pub fn sort_to_indices<I: Index>(
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_) => {
             dyn_sort_indices!(I, i64, values, ord::total_cmp, options, limit)
         }
+        DataType::Decimal(_, _) => {
+            dyn_sort_indices!(I, i128, values, ord::total_cmp, options, limit)
+        }
         DataType::UInt8 => dyn_sort_indices!(I, u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
@@ -151,6 +163,16 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
+        DataType::FixedSizeBinary(_) => Ok(fixed_size_binary::indices_sorted_unstable_by::<I>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        )),
+        DataType::Struct(_) => struct_::indices_sorted_unstable_by::<I>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ),
         DataType::List(field) => {
             let (v, n) = partition_validity(values);
             match field.data_type() {
@@ -202,24 +224,9 @@ pub fn sort_to_indices<I: Index>(
                 ))),
             }
         }
-        DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(
-                values,
-                key_type.as_ref(),
-                options,
-                limit,
-            )),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(
-                values,
-                key_type.as_ref(),
-                options,
-                limit,
-            )),
-            t => Err(ArrowError::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {:?}",
-                t
-            ))),
-        },
+        DataType::Dictionary(key_type, value_type) if can_sort(value_type.as_ref()) => {
+            sort_dict::<I>(values, key_type.as_ref(), options, limit)
+        }
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
             t
@@ -227,14 +234,14 @@ pub fn sort_to_indices<I: Index>(
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
+fn sort_dict<I: Index>(
     values: &dyn Array,
     key_type: &DataType,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I> {
+) -> Result<PrimitiveArray<I>> {
     with_match_dictionary_key_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
+        dictionary::indices_sorted_unstable_by::<I, $T>(
             values.as_any().downcast_ref().unwrap(),
             options,
             limit,
@@ -267,8 +274,9 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_)
+        | DataType::Decimal(_, _)
         | DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
@@ -278,7 +286,9 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Utf8
         | DataType::LargeUtf8
         | DataType::Binary
-        | DataType::LargeBinary => true,
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
+        DataType::Struct(fields) => fields.iter().all(|field| can_sort(field.data_type())),
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
             matches!(
                 field.data_type(),
@@ -292,7 +302,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt64
             )
         }
-        DataType::Dictionary(key_type, value_type) if *value_type.as_ref() == DataType::Utf8 => {
+        DataType::Dictionary(key_type, value_type) if can_sort(value_type.as_ref()) => {
             matches!(
                 key_type.as_ref(),
                 DataType::Int8
@@ -361,10 +371,33 @@ where
             },
         );
 
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
+    let descending = options.descending;
+    let compare = |a: &(I, Box<dyn Array>), b: &(I, Box<dyn Array>)| {
+        if descending {
+            cmp_array(b.1.as_ref(), a.1.as_ref())
+        } else {
+            cmp_array(a.1.as_ref(), b.1.as_ref())
+        }
+    };
+
+    // the number of valid values that can end up in the result
+    let valid_limit = limit
+        .map(|limit| {
+            if options.nulls_first {
+                limit.saturating_sub(null_indices.len())
+            } else {
+                limit
+            }
+        })
+        .unwrap_or_else(|| valids.len());
+
+    if valid_limit < valids.len() {
+        // top-k: only the first `valid_limit` values need to be sorted
+        let (before, _, _) = valids.select_nth_unstable_by(valid_limit, compare);
+        before.sort_by(compare);
+        valids.truncate(valid_limit);
     } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
+        valids.sort_by(compare);
     }
 
     let values = valids.iter().map(|tuple| tuple.0);
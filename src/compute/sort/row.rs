@@ -0,0 +1,591 @@
+//! Contains a row-oriented, byte-comparable encoding of multiple columns.
+//!
+//! Comparing rows of multiple columns via [`crate::array::ord::build_compare`] requires one
+//! dynamically dispatched call per column per comparison. This module instead encodes each row
+//! of a set of columns into a sequence of bytes such that comparing two rows with `memcmp`
+//! (i.e. `<[u8] as Ord>::cmp`) is equivalent to comparing them lexicographically
+//! according to each column's [`SortOptions`].
+//!
+//! # Encoding
+//! Each row is the concatenation of the encoding of each of its columns. Each column's value
+//! is encoded as:
+//! * a single byte denoting validity: `0` for nulls when `nulls_first`, `2` for nulls otherwise,
+//!   and `1` for valid values. Null values are only encoded by this byte.
+//! * for valid values:
+//!     * booleans as a single byte, `0` or `1`
+//!     * unsigned integers as their big endian representation
+//!     * signed integers as their big endian representation with the sign bit flipped
+//!     * floats are first mapped to signed integers following IEEE 754 totalOrder
+//!     * binary and strings by escaping every `0` byte as `[0, 255]` followed by the
+//!       terminator `[0, 0]`, which makes the encoding prefix-free
+//!     * fixed-size binary as its bytes
+//!     * dictionaries as the encoding of the value the key points to
+//!
+//! When `descending`, the bytes of each valid value are inverted (bitwise not), which,
+//! because each encoding is either of fixed length or prefix-free, reverses its order.
+use std::cmp::Ordering;
+
+use crate::array::*;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::{SortColumn, SortOptions};
+
+/// A set of rows whose byte representations can be compared to each other to order them
+/// lexicographically by their columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rows {
+    values: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Rows {
+    /// Returns the byte representation of the row `i`.
+    /// # Panics
+    /// Panics iff `i >= self.len()`.
+    #[inline]
+    pub fn row(&self, i: usize) -> &[u8] {
+        &self.values[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns whether there are no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Compares row `i` with row `j`.
+    #[inline]
+    pub fn compare(&self, i: usize, j: usize) -> Ordering {
+        self.row(i).cmp(self.row(j))
+    }
+
+    /// Returns an iterator over the rows' byte representations.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.offsets
+            .windows(2)
+            .map(move |w| &self.values[w[0]..w[1]])
+    }
+}
+
+/// Returns whether arrays of [`DataType`] can be encoded as [`Rows`].
+pub fn can_encode(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Boolean | Utf8 | LargeUtf8 | Binary | LargeBinary | FixedSizeBinary => true,
+        Primitive(primitive) => !matches!(
+            primitive,
+            PrimitiveType::DaysMs | PrimitiveType::MonthDayNano
+        ),
+        Dictionary(_) => match data_type.to_logical_type() {
+            DataType::Dictionary(_, values) => can_encode(values.as_ref()),
+            _ => unreachable!(),
+        },
+        _ => false,
+    }
+}
+
+/// Encodes `columns` into [`Rows`] whose byte-wise order is the lexicographical order of the
+/// columns according to each column's [`SortOptions`].
+/// # Errors
+/// This function errors when:
+/// * `columns` is empty
+/// * the columns have different lengths
+/// * a column's [`DataType`] cannot be encoded (see [`can_encode`])
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::sort::{row::encode, SortColumn};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = Int32Array::from(&[Some(1), Some(1), None]);
+/// let b = Utf8Array::<i32>::from(&[Some("b"), Some("a"), Some("a")]);
+/// let rows = encode(&[
+///     SortColumn { values: &a, options: None },
+///     SortColumn { values: &b, options: None },
+/// ])?;
+/// // nulls are first by default
+/// assert!(rows.row(2) < rows.row(1));
+/// assert!(rows.row(1) < rows.row(0));
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode(columns: &[SortColumn]) -> Result<Rows> {
+    validate(columns)?;
+    let length = columns[0].values.len();
+
+    // dictionaries are encoded by first encoding their values
+    let encoders = columns
+        .iter()
+        .map(|column| Encoder::try_new(column.values, column.options.unwrap_or_default()))
+        .collect::<Result<Vec<_>>>()?;
+
+    // compute the offsets of each row
+    let mut lengths = vec![0usize; length];
+    encoders
+        .iter()
+        .for_each(|encoder| encoder.add_lengths(&mut lengths));
+
+    let mut offsets = Vec::with_capacity(length + 1);
+    offsets.push(0usize);
+    let mut total = 0;
+    offsets.extend(lengths.iter().map(|length| {
+        total += length;
+        total
+    }));
+
+    // write each column to each row
+    let mut values = vec![0u8; total];
+    let mut cursors = offsets[..length].to_vec();
+    encoders
+        .iter()
+        .for_each(|encoder| encoder.encode(&mut values, &mut cursors));
+
+    Ok(Rows { values, offsets })
+}
+
+/// Checks that `columns` can be encoded by [`encode`], without encoding them.
+/// # Errors
+/// See [`encode`]; additionally errors when a key of a dictionary is out of the bounds of
+/// its values.
+pub(crate) fn validate(columns: &[SortColumn]) -> Result<()> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Row encoding requires at least one column".to_string(),
+        ));
+    }
+    let length = columns[0].values.len();
+    if columns.iter().any(|column| column.values.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Row encoding requires columns with the same number of rows".to_string(),
+        ));
+    }
+    if let Some(column) = columns
+        .iter()
+        .find(|column| !can_encode(column.values.data_type()))
+    {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Row encoding is not supported for data type {:?}",
+            column.values.data_type()
+        )));
+    }
+    columns.iter().try_for_each(|column| {
+        if let PhysicalType::Dictionary(key) = column.values.data_type().to_physical_type() {
+            with_match_physical_dictionary_key_type!(key, |$T| {
+                let array = column
+                    .values
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                validate_keys(array)?;
+                validate(&[SortColumn {
+                    values: array.values().as_ref(),
+                    options: column.options,
+                }])
+            })
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Errors when a valid key of `array` is out of the bounds of its values.
+fn validate_keys<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<()> {
+    let length = array.values().len();
+    let keys = array.keys();
+    let in_bounds = (0..keys.len())
+        .filter(|i| keys.is_valid(*i))
+        .all(|i| matches!(keys.value(i).to_usize(), Some(key) if key < length));
+    if in_bounds {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "A key of the dictionary is out of the bounds of its {} values",
+            length
+        )))
+    }
+}
+
+macro_rules! with_match_encodable_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        DaysMs | MonthDayNano => unreachable!(),
+    }
+})}
+
+/// A column ready to be encoded
+struct Encoder<'a> {
+    array: &'a dyn Array,
+    options: SortOptions,
+    // the encoded values of a dictionary
+    dictionary: Option<Rows>,
+}
+
+impl<'a> Encoder<'a> {
+    fn try_new(array: &'a dyn Array, options: SortOptions) -> Result<Self> {
+        let dictionary = if let PhysicalType::Dictionary(key) = array.data_type().to_physical_type()
+        {
+            with_match_physical_dictionary_key_type!(key, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                Some(encode(&[SortColumn {
+                    values: array.values().as_ref(),
+                    options: Some(options),
+                }])?)
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            array,
+            options,
+            dictionary,
+        })
+    }
+
+    fn add_lengths(&self, lengths: &mut [usize]) {
+        use PhysicalType::*;
+        let array = self.array;
+        match array.data_type().to_physical_type() {
+            Boolean => add_fixed_lengths(array, 1, lengths),
+            Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                add_fixed_lengths(array, std::mem::size_of::<$T>(), lengths)
+            }),
+            FixedSizeBinary => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                add_fixed_lengths(array, array.size(), lengths)
+            }
+            Utf8 => add_binary_lengths(
+                array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+                |array, i| array.value(i).as_bytes(),
+                lengths,
+            ),
+            LargeUtf8 => add_binary_lengths(
+                array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+                |array, i| array.value(i).as_bytes(),
+                lengths,
+            ),
+            Binary => add_binary_lengths(
+                array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap(),
+                |array, i| array.value(i),
+                lengths,
+            ),
+            LargeBinary => add_binary_lengths(
+                array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap(),
+                |array, i| array.value(i),
+                lengths,
+            ),
+            Dictionary(key) => with_match_physical_dictionary_key_type!(key, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                add_dictionary_lengths(array, self.dictionary.as_ref().unwrap(), lengths)
+            }),
+            _ => unreachable!(),
+        }
+    }
+
+    fn encode(&self, values: &mut [u8], cursors: &mut [usize]) {
+        use PhysicalType::*;
+        let array = self.array;
+        let options = &self.options;
+        match array.data_type().to_physical_type() {
+            Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                encode_fixed(array, 1, options, values, cursors, |i, out| {
+                    out[0] = array.value(i) as u8
+                })
+            }
+            Primitive(primitive) => with_match_encodable_primitive_type!(primitive, |$T| {
+                let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                let array_values = array.values();
+                encode_fixed(
+                    array,
+                    std::mem::size_of::<$T>(),
+                    options,
+                    values,
+                    cursors,
+                    |i, out| array_values[i].encode(out),
+                )
+            }),
+            FixedSizeBinary => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                encode_fixed(array, array.size(), options, values, cursors, |i, out| {
+                    out.copy_from_slice(array.value(i))
+                })
+            }
+            Utf8 => encode_binary(
+                array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+                |array, i| array.value(i).as_bytes(),
+                options,
+                values,
+                cursors,
+            ),
+            LargeUtf8 => encode_binary(
+                array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+                |array, i| array.value(i).as_bytes(),
+                options,
+                values,
+                cursors,
+            ),
+            Binary => encode_binary(
+                array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap(),
+                |array, i| array.value(i),
+                options,
+                values,
+                cursors,
+            ),
+            LargeBinary => encode_binary(
+                array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap(),
+                |array, i| array.value(i),
+                options,
+                values,
+                cursors,
+            ),
+            Dictionary(key) => with_match_physical_dictionary_key_type!(key, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                encode_dictionary(
+                    array,
+                    self.dictionary.as_ref().unwrap(),
+                    options,
+                    values,
+                    cursors,
+                )
+            }),
+            _ => unreachable!(),
+        }
+    }
+}
+
+const VALID: u8 = 1;
+
+#[inline]
+fn null_byte(options: &SortOptions) -> u8 {
+    if options.nulls_first {
+        0
+    } else {
+        2
+    }
+}
+
+fn add_fixed_lengths(array: &dyn Array, width: usize, lengths: &mut [usize]) {
+    if let Some(validity) = array.validity() {
+        lengths
+            .iter_mut()
+            .zip(validity.iter())
+            .for_each(|(length, is_valid)| *length += 1 + if is_valid { width } else { 0 })
+    } else {
+        lengths.iter_mut().for_each(|length| *length += 1 + width)
+    }
+}
+
+fn encode_fixed<F: Fn(usize, &mut [u8])>(
+    array: &dyn Array,
+    width: usize,
+    options: &SortOptions,
+    values: &mut [u8],
+    cursors: &mut [usize],
+    encode: F,
+) {
+    let null = null_byte(options);
+    cursors.iter_mut().enumerate().for_each(|(i, cursor)| {
+        if array.is_valid(i) {
+            values[*cursor] = VALID;
+            let out = &mut values[*cursor + 1..*cursor + 1 + width];
+            encode(i, out);
+            if options.descending {
+                out.iter_mut().for_each(|x| *x = !*x);
+            }
+            *cursor += 1 + width;
+        } else {
+            values[*cursor] = null;
+            *cursor += 1;
+        }
+    })
+}
+
+fn add_dictionary_lengths<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    dictionary: &Rows,
+    lengths: &mut [usize],
+) {
+    let keys = array.keys();
+    lengths.iter_mut().enumerate().for_each(|(i, length)| {
+        *length += 1;
+        if keys.is_valid(i) {
+            *length += dictionary.row(keys.value(i).to_usize().unwrap()).len()
+        }
+    })
+}
+
+fn encode_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    dictionary: &Rows,
+    options: &SortOptions,
+    values: &mut [u8],
+    cursors: &mut [usize],
+) {
+    let null = null_byte(options);
+    let keys = array.keys();
+    cursors.iter_mut().enumerate().for_each(|(i, cursor)| {
+        if keys.is_valid(i) {
+            // the encoded value already takes `descending` into account
+            let row = dictionary.row(keys.value(i).to_usize().unwrap());
+            values[*cursor] = VALID;
+            values[*cursor + 1..*cursor + 1 + row.len()].copy_from_slice(row);
+            *cursor += 1 + row.len();
+        } else {
+            values[*cursor] = null;
+            *cursor += 1;
+        }
+    })
+}
+
+/// The length of a variable-length value after encoding: every `0` takes two bytes and the
+/// terminator two bytes.
+#[inline]
+fn binary_length(value: &[u8]) -> usize {
+    value.len() + value.iter().filter(|x| **x == 0).count() + 2
+}
+
+fn add_binary_lengths<A: Array, F: Fn(&A, usize) -> &[u8]>(
+    array: &A,
+    value: F,
+    lengths: &mut [usize],
+) {
+    lengths.iter_mut().enumerate().for_each(|(i, length)| {
+        *length += 1;
+        if array.is_valid(i) {
+            *length += binary_length(value(array, i))
+        }
+    })
+}
+
+fn encode_binary<A: Array, F: Fn(&A, usize) -> &[u8]>(
+    array: &A,
+    value: F,
+    options: &SortOptions,
+    values: &mut [u8],
+    cursors: &mut [usize],
+) {
+    let null = null_byte(options);
+    cursors.iter_mut().enumerate().for_each(|(i, cursor)| {
+        if array.is_valid(i) {
+            values[*cursor] = VALID;
+            let start = *cursor + 1;
+            let mut position = start;
+            for byte in value(array, i) {
+                values[position] = *byte;
+                position += 1;
+                if *byte == 0 {
+                    values[position] = u8::MAX;
+                    position += 1;
+                }
+            }
+            // terminator
+            values[position] = 0;
+            values[position + 1] = 0;
+            position += 2;
+            if options.descending {
+                values[start..position].iter_mut().for_each(|x| *x = !*x);
+            }
+            *cursor = position;
+        } else {
+            values[*cursor] = null;
+            *cursor += 1;
+        }
+    })
+}
+
+/// A [`NativeType`] that can be encoded into bytes whose order is the order of the type.
+trait Encode: NativeType {
+    /// Writes the encoded representation of `self` into `out`, of length `size_of::<Self>()`
+    fn encode(&self, out: &mut [u8]);
+}
+
+macro_rules! encode_unsigned {
+    ($type:ty) => {
+        impl Encode for $type {
+            #[inline]
+            fn encode(&self, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_be_bytes())
+            }
+        }
+    };
+}
+
+macro_rules! encode_signed {
+    ($type:ty) => {
+        impl Encode for $type {
+            #[inline]
+            fn encode(&self, out: &mut [u8]) {
+                let mut bytes = self.to_be_bytes();
+                // flipping the sign bit maps `MIN..=MAX` to `0..=unsigned::MAX`
+                bytes[0] ^= 0x80;
+                out.copy_from_slice(&bytes)
+            }
+        }
+    };
+}
+
+encode_unsigned!(u8);
+encode_unsigned!(u16);
+encode_unsigned!(u32);
+encode_unsigned!(u64);
+encode_signed!(i8);
+encode_signed!(i16);
+encode_signed!(i32);
+encode_signed!(i64);
+encode_signed!(i128);
+
+// see `crate::array::ord::total_cmp_f32`
+impl Encode for f32 {
+    #[inline]
+    fn encode(&self, out: &mut [u8]) {
+        let mut value = self.to_bits() as i32;
+        value ^= (((value >> 31) as u32) >> 1) as i32;
+        value.encode(out)
+    }
+}
+
+// see `crate::array::ord::total_cmp_f64`
+impl Encode for f64 {
+    #[inline]
+    fn encode(&self, out: &mut [u8]) {
+        let mut value = self.to_bits() as i64;
+        value ^= (((value >> 63) as u64) >> 1) as i64;
+        value.encode(out)
+    }
+}
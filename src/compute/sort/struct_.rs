@@ -0,0 +1,29 @@
+use crate::array::{ord, Array, PrimitiveArray, StructArray};
+use crate::error::Result;
+use crate::types::Index;
+
+use super::common;
+use super::SortOptions;
+
+/// Returns the indices that would sort a [`StructArray`].
+/// Structs are ordered lexicographically by their fields, where null fields are ordered
+/// according to `options`, like null structs.
+pub(super) fn indices_sorted_unstable_by<I: Index>(
+    array: &StructArray,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    // the comparison is reversed when descending, so must be the order of nested nulls
+    let nulls_first = options.nulls_first != options.descending;
+    let comparator = ord::build_compare_with_nulls(array, array, nulls_first)?;
+    let get = |idx: usize| idx;
+    let cmp = |lhs: &usize, rhs: &usize| (comparator)(*lhs, *rhs);
+    Ok(common::indices_sorted_unstable_by(
+        array.validity(),
+        get,
+        cmp,
+        array.len(),
+        options,
+        limit,
+    ))
+}
@@ -0,0 +1,60 @@
+use crate::{
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    buffer::MutableBuffer,
+};
+
+use super::Index;
+
+// take the validity of `values` at `indices`, taking into account the validity of `indices`.
+fn take_validity<I: Index>(
+    validity: &Option<Bitmap>,
+    indices: &PrimitiveArray<I>,
+) -> Option<Bitmap> {
+    match (validity, indices.validity()) {
+        (None, indices_validity) => indices_validity.clone(),
+        (Some(validity), None) => {
+            let iter = indices
+                .values()
+                .iter()
+                .map(|index| validity.get_bit(index.to_usize()));
+            MutableBitmap::from_trusted_len_iter(iter).into()
+        }
+        (Some(validity), Some(_)) => {
+            let iter = indices
+                .iter()
+                .map(|x| x.map(|index| validity.get_bit(index.to_usize())) == Some(true));
+            MutableBitmap::from_trusted_len_iter(iter).into()
+        }
+    }
+}
+
+/// `take` implementation for [`FixedSizeBinaryArray`]
+pub fn take<I: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<I>,
+) -> FixedSizeBinaryArray {
+    let size = values.size();
+    let mut buffer = MutableBuffer::<u8>::with_capacity(indices.len() * size);
+
+    match indices.validity() {
+        None => indices
+            .values()
+            .iter()
+            .for_each(|index| buffer.extend_from_slice(values.value(index.to_usize()))),
+        Some(indices_validity) => indices
+            .values()
+            .iter()
+            .zip(indices_validity.iter())
+            .for_each(|(index, is_valid)| {
+                if is_valid {
+                    buffer.extend_from_slice(values.value(index.to_usize()))
+                } else {
+                    buffer.extend_constant(size, 0)
+                }
+            }),
+    };
+
+    let validity = take_validity(values.validity(), indices);
+    FixedSizeBinaryArray::from_data(values.data_type().clone(), buffer.into(), validity)
+}
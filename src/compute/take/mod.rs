@@ -27,6 +27,7 @@ use crate::{
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod generic_binary;
 mod list;
 mod primitive;
@@ -68,6 +69,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let values = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(binary::take::<i64, _>(values, indices)))
         }
+        FixedSizeBinary => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take(values, indices)))
+        }
         Dictionary(key_type) => {
             with_match_physical_dictionary_key_type!(key_type, |$T| {
                 let values = values.as_any().downcast_ref().unwrap();
@@ -127,6 +132,7 @@ pub fn can_take(data_type: &DataType) -> bool {
         | DataType::LargeUtf8
         | DataType::Binary
         | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_)
         | DataType::Struct(_)
         | DataType::List(_)
         | DataType::LargeList(_) => true,
@@ -20,7 +20,6 @@
 use crate::datatypes::{
     get_extension, DataType, Extension, Field, IntervalUnit, Metadata, Schema, TimeUnit,
 };
-use crate::endianess::is_native_little_endian;
 use crate::io::ipc::convert::ipc::UnionMode;
 
 mod ipc {
@@ -37,6 +36,7 @@ use DataType::*;
 pub fn schema_to_fb_offset<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
     schema: &Schema,
+    is_little_endian: bool,
 ) -> WIPOffset<ipc::Schema<'a>> {
     let mut fields = vec![];
     for field in schema.fields() {
@@ -61,7 +61,7 @@ pub fn schema_to_fb_offset<'a>(
     let mut builder = ipc::SchemaBuilder::new(fbb);
     builder.add_fields(fb_field_list);
     builder.add_custom_metadata(fb_metadata_list);
-    builder.add_endianness(if is_native_little_endian() {
+    builder.add_endianness(if is_little_endian {
         ipc::Endianness::Little
     } else {
         ipc::Endianness::Big
@@ -758,12 +758,13 @@ pub(crate) fn get_fb_dictionary<'a>(
 mod tests {
     use super::*;
     use crate::datatypes::{DataType, Field, Schema};
+    use crate::endianess::is_native_little_endian;
 
     /// Serialize a schema in IPC format
     fn schema_to_fb(schema: &Schema) -> FlatBufferBuilder {
         let mut fbb = FlatBufferBuilder::new();
 
-        let root = schema_to_fb_offset(&mut fbb, schema);
+        let root = schema_to_fb_offset(&mut fbb, schema, is_native_little_endian());
 
         fbb.finish(root, None);
 
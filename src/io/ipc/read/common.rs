@@ -89,6 +89,28 @@ impl<'a, A, I: Iterator<Item = A>> Iterator for ProjectionIter<'a, A, I> {
     }
 }
 
+/// Returns `projection` together with the schema of the columns of `schema` it selects.
+/// # Panic
+/// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+pub(super) fn prepare_projection(
+    schema: &Schema,
+    projection: Vec<usize>,
+) -> (Vec<usize>, Arc<Schema>) {
+    assert!(
+        projection.windows(2).all(|x| x[0] < x[1]),
+        "The projection on IPC must be ordered and non-overlapping"
+    );
+    let fields = projection
+        .iter()
+        .map(|x| schema.fields()[*x].clone())
+        .collect();
+    let schema = Arc::new(Schema {
+        fields,
+        metadata: schema.metadata().clone(),
+    });
+    (projection, schema)
+}
+
 /// Creates a record batch from binary data using the `ipc::RecordBatch` indexes and the `Schema`
 /// # Panic
 /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
@@ -176,12 +198,6 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.isDelta() {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -217,6 +233,37 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    // a delta dictionary batch contains values to append to the current dictionary
+    let dictionary_values = if batch.isDelta() {
+        let index = schema
+            .fields()
+            .iter()
+            .position(|field| field.dict_id() == Some(id))
+            .unwrap();
+        let current = dictionaries_by_field[index].as_ref().ok_or_else(|| {
+            ArrowError::Ipc(format!(
+                "Delta dictionary batch for the dictionary with id {} without a previous dictionary",
+                id
+            ))
+        })?;
+        if current.data_type() != dictionary_values.data_type() {
+            return Err(ArrowError::Ipc(format!(
+                "Delta dictionary batch of type {:?} for the dictionary with id {} of type {:?}",
+                dictionary_values.data_type(),
+                id,
+                current.data_type()
+            )));
+        }
+        let arrays = [current.as_ref(), dictionary_values.as_ref()];
+        let mut growable =
+            growable::make_growable(&arrays, false, current.len() + dictionary_values.len());
+        growable.extend(0, 0, current.len());
+        growable.extend(1, 0, dictionary_values.len());
+        growable.as_arc()
+    } else {
+        dictionary_values
+    };
+
     // for all fields with this dictionary id, update the dictionaries vector
     // in the reader. Note that a dictionary batch may be shared between many fields.
     // We don't currently record the isOrdered field. This could be general
@@ -0,0 +1,90 @@
+//! Decoding of individual IPC messages, independent of the framing of the file and stream
+//! formats, e.g. to receive Arrow data over a custom transport.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+use super::super::gen;
+use super::super::gen::Schema::MetadataVersion;
+use super::stream::{deserialize_stream_metadata, read_message, StreamMetadata};
+
+type ArrayRef = Arc<dyn Array>;
+
+/// Decodes IPC messages, each a header (an encoded `Message` flatbuffer) and a body, into
+/// [`RecordBatch`]es. The decoder keeps the state shared between messages: the schema and
+/// the dictionaries received so far.
+///
+/// Messages are decoded in the order they were encoded by
+/// [`MessageEncoder`](crate::io::ipc::write::MessageEncoder): the schema, followed by each
+/// record batch preceded by the dictionaries it requires.
+#[derive(Debug, Default)]
+pub struct MessageDecoder {
+    metadata: Option<StreamMetadata>,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+}
+
+impl MessageDecoder {
+    /// Returns a new [`MessageDecoder`] whose first message must be a schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [`MessageDecoder`] of messages of `schema`, e.g. when the schema is
+    /// known in advance and is not sent as a message.
+    pub fn with_schema(schema: Arc<Schema>, is_little_endian: bool) -> Self {
+        let dictionaries_by_field = vec![None; schema.fields().len()];
+        Self {
+            metadata: Some(StreamMetadata {
+                schema,
+                version: MetadataVersion::V5,
+                is_little_endian,
+            }),
+            dictionaries_by_field,
+        }
+    }
+
+    /// The schema of the messages, if it is known
+    pub fn schema(&self) -> Option<&Arc<Schema>> {
+        self.metadata.as_ref().map(|metadata| metadata.schema())
+    }
+
+    /// Decodes the message whose header is `header` and body is `body`. Returns the
+    /// [`RecordBatch`] of a record batch message, or `None` for a schema or dictionary batch
+    /// message, which update the state of the decoder.
+    /// # Errors
+    /// Errors if the message is invalid, or if it is a dictionary or record batch message
+    /// and no schema is known.
+    pub fn decode(&mut self, header: &[u8], body: &[u8]) -> Result<Option<RecordBatch>> {
+        let message = gen::Message::root_as_message(header)
+            .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+
+        match message.header_type() {
+            gen::Message::MessageHeader::NONE => Ok(None),
+            gen::Message::MessageHeader::Schema => {
+                let metadata = deserialize_stream_metadata(header)?;
+                self.dictionaries_by_field = vec![None; metadata.schema.fields().len()];
+                self.metadata = Some(metadata);
+                Ok(None)
+            }
+            _ => {
+                let metadata = self.metadata.as_ref().ok_or_else(|| {
+                    ArrowError::Ipc(
+                        "A schema must be decoded before dictionaries and record batches"
+                            .to_string(),
+                    )
+                })?;
+                read_message(
+                    message,
+                    body,
+                    metadata,
+                    None,
+                    &mut self.dictionaries_by_field,
+                )
+            }
+        }
+    }
+}
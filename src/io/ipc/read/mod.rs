@@ -2,10 +2,20 @@
 mod array;
 mod common;
 mod deserialize;
+mod message;
 mod read_basic;
 mod reader;
 mod stream;
+#[cfg(feature = "io_ipc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_async")))]
+mod stream_async;
+mod tensor;
 
 pub use common::{read_dictionary, read_record_batch};
+pub use message::MessageDecoder;
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
+#[cfg(feature = "io_ipc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_async")))]
+pub use stream_async::{read_stream_metadata_async, AsyncStreamReader};
+pub use tensor::{read_sparse_tensor, read_tensor};
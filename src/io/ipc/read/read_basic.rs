@@ -10,6 +10,24 @@ use crate::{bitmap::Bitmap, buffer::MutableBuffer, types::NativeType};
 use super::super::compression;
 use super::super::gen;
 
+/// Writes the values encoded in `bytes` with a non-native endianness to `buffer`.
+fn swap_from_bytes<T: NativeType>(bytes: &[u8], buffer: &mut [T], is_little_endian: bool) {
+    let chunks = bytes.chunks_exact(std::mem::size_of::<T>());
+    buffer.iter_mut().zip(chunks).for_each(|(slot, chunk)| {
+        let a: T::Bytes = match chunk.try_into() {
+            Ok(a) => a,
+            Err(_) => unreachable!(),
+        };
+        *slot = if is_little_endian {
+            // machine is big endian, file is little endian
+            T::from_le_bytes(a)
+        } else {
+            // machine is little endian, file is big endian
+            T::from_be_bytes(a)
+        };
+    });
+}
+
 fn read_swapped<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     length: usize,
@@ -20,26 +38,7 @@ fn read_swapped<T: NativeType, R: Read + Seek>(
     let mut slice = vec![0u8; length * std::mem::size_of::<T>()];
     reader.read_exact(&mut slice)?;
 
-    let chunks = slice.chunks_exact(std::mem::size_of::<T>());
-    if !is_little_endian {
-        // machine is little endian, file is big endian
-        buffer
-            .as_mut_slice()
-            .iter_mut()
-            .zip(chunks)
-            .try_for_each(|(slot, chunk)| {
-                let a: T::Bytes = match chunk.try_into() {
-                    Ok(a) => a,
-                    Err(_) => unreachable!(),
-                };
-                *slot = T::from_be_bytes(a);
-                Result::Ok(())
-            })
-            .unwrap();
-    } else {
-        // machine is big endian, file is little endian
-        todo!("reading little endian files from big endian machines not yet implemented.")
-    }
+    swap_from_bytes(&slice, buffer.as_mut_slice(), is_little_endian);
     Ok(())
 }
 
@@ -91,12 +90,6 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     is_little_endian: bool,
     compression: BodyCompression,
 ) -> Result<MutableBuffer<T>> {
-    if is_little_endian != is_native_little_endian() {
-        return Err(ArrowError::NotYetImplemented(
-            "Reading compressed and big endian IPC".to_string(),
-        ));
-    }
-
     // it is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
     let mut buffer = MutableBuffer::<T>::from_len_zeroed(length);
@@ -106,31 +99,29 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     let mut slice = vec![0u8; buffer_length];
     reader.read_exact(&mut slice)?;
 
-    match compression.codec() {
-        CompressionType::LZ4_FRAME => {
-            // fast case where we can just copy the contents as is
-            unsafe {
-                // transmute T to bytes.
-                let out_slice = std::slice::from_raw_parts_mut(
-                    buffer.as_mut_ptr() as *mut u8,
-                    length * std::mem::size_of::<T>(),
-                );
-                compression::decompress_lz4(&slice[8..], out_slice)?
-            }
-            Ok(buffer)
-        }
-        CompressionType::ZSTD => {
-            // fast case where we can just copy the contents as is
-            unsafe {
-                // transmute T to bytes.
-                let out_slice = std::slice::from_raw_parts_mut(
-                    buffer.as_mut_ptr() as *mut u8,
-                    length * std::mem::size_of::<T>(),
-                );
-                compression::decompress_zstd(&slice[8..], out_slice)?
-            }
-            Ok(buffer)
+    if is_little_endian == is_native_little_endian() {
+        // fast case where we can decompress directly into the buffer
+        unsafe {
+            // transmute T to bytes.
+            let out_slice = std::slice::from_raw_parts_mut(
+                buffer.as_mut_ptr() as *mut u8,
+                length * std::mem::size_of::<T>(),
+            );
+            decompress(&compression, &slice[8..], out_slice)?;
         }
+    } else {
+        // slow case where we must reverse bits of the decompressed values
+        let mut out_slice = vec![0u8; length * std::mem::size_of::<T>()];
+        decompress(&compression, &slice[8..], &mut out_slice)?;
+        swap_from_bytes(&out_slice, buffer.as_mut_slice(), is_little_endian);
+    }
+    Ok(buffer)
+}
+
+fn decompress(compression: &BodyCompression, input: &[u8], output: &mut [u8]) -> Result<()> {
+    match compression.codec() {
+        CompressionType::LZ4_FRAME => compression::decompress_lz4(input, output),
+        CompressionType::ZSTD => compression::decompress_zstd(input, output),
         _ => Err(ArrowError::NotYetImplemented(
             "Non LZ4 compressed IPC".to_string(),
         )),
@@ -255,3 +246,30 @@ pub fn read_validity<R: Read + Seek>(
         None
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::days_ms;
+
+    #[test]
+    fn swap() {
+        let mut buffer = [0i32; 2];
+        swap_from_bytes(&[0, 0, 1, 2, 255, 255, 255, 254], &mut buffer, false);
+        assert_eq!(buffer, [258, -2]);
+        swap_from_bytes(&[2, 1, 0, 0, 254, 255, 255, 255], &mut buffer, true);
+        assert_eq!(buffer, [258, -2]);
+
+        let mut buffer = [days_ms::default(); 1];
+        swap_from_bytes(&[0, 0, 0, 1, 0, 0, 0, 2], &mut buffer, false);
+        assert_eq!(buffer, [days_ms::new(1, 2)]);
+        swap_from_bytes(&[1, 0, 0, 0, 2, 0, 0, 0], &mut buffer, true);
+        assert_eq!(buffer, [days_ms::new(1, 2)]);
+
+        let mut buffer = [0i128; 1];
+        let mut bytes = [0u8; 16];
+        bytes[15] = 1;
+        swap_from_bytes(&bytes, &mut buffer, false);
+        assert_eq!(buffer, [1]);
+    }
+}
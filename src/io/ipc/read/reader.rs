@@ -38,7 +38,10 @@ pub struct FileMetadata {
     /// The blocks in the file
     ///
     /// A block indicates the regions in the file to read to get data
-    blocks: Vec<gen::File::Block>,
+    pub(crate) blocks: Vec<gen::File::Block>,
+
+    /// The blocks of the dictionary batches in the file
+    pub(crate) dictionary_blocks: Vec<gen::File::Block>,
 
     /// The total number of blocks, which may contain record batches and other types
     total_blocks: usize,
@@ -46,12 +49,12 @@ pub struct FileMetadata {
     /// Optional dictionaries for each schema field.
     ///
     /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    pub(crate) dictionaries_by_field: Vec<Option<ArrayRef>>,
 
     /// FileMetadata version
-    version: gen::Schema::MetadataVersion,
+    pub(crate) version: gen::Schema::MetadataVersion,
 
-    is_little_endian: bool,
+    pub(crate) is_little_endian: bool,
 }
 
 impl FileMetadata {
@@ -59,6 +62,11 @@ impl FileMetadata {
     pub fn schema(&self) -> &Arc<Schema> {
         &self.schema
     }
+
+    /// Returns the number of record batches in the file.
+    pub fn num_batches(&self) -> usize {
+        self.total_blocks
+    }
 }
 
 /// Arrow File reader
@@ -120,9 +128,11 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     let (schema, is_little_endian) = convert::fb_to_schema(ipc_schema);
     let schema = Arc::new(schema);
 
+    let dictionary_blocks = footer.dictionaries().unwrap();
+
     // Create an array of optional dictionary value arrays, one per field.
     let mut dictionaries_by_field = vec![None; schema.fields().len()];
-    for block in footer.dictionaries().unwrap() {
+    for block in dictionary_blocks {
         // read length from end of offset
         let mut message_size: [u8; 4] = [0; 4];
         reader.seek(SeekFrom::Start(block.offset() as u64))?;
@@ -164,6 +174,7 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
         schema,
         is_little_endian,
         blocks: blocks.to_vec(),
+        dictionary_blocks: dictionary_blocks.to_vec(),
         total_blocks,
         dictionaries_by_field,
         version: footer.version(),
@@ -236,25 +247,8 @@ impl<'a, R: Read + Seek> FileReader<'a, R> {
     /// # Panic
     /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
     pub fn new(reader: &'a mut R, metadata: FileMetadata, projection: Option<Vec<usize>>) -> Self {
-        if let Some(projection) = projection.as_ref() {
-            let _ = projection.iter().fold(0, |mut acc, v| {
-                assert!(
-                    *v > acc,
-                    "The projection on IPC must be ordered and non-overlapping"
-                );
-                acc = *v;
-                acc
-            });
-        }
-        let projection = projection.map(|projection| {
-            let fields = metadata.schema().fields();
-            let fields = projection.iter().map(|x| fields[*x].clone()).collect();
-            let schema = Arc::new(Schema {
-                fields,
-                metadata: metadata.schema().metadata().clone(),
-            });
-            (projection, schema)
-        });
+        let projection =
+            projection.map(|projection| prepare_projection(metadata.schema(), projection));
         Self {
             reader,
             metadata,
@@ -270,6 +264,38 @@ impl<'a, R: Read + Seek> FileReader<'a, R> {
             .map(|x| &x.1)
             .unwrap_or(&self.metadata.schema)
     }
+
+    /// Returns the number of record batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.metadata.total_blocks
+    }
+
+    /// Reads the record batch at position `index` of the file, regardless of the batches
+    /// already read by iterating over this reader.
+    /// # Errors
+    /// Errors if `index` is out of bounds or the batch can't be read.
+    pub fn read_batch(&mut self, index: usize) -> Result<RecordBatch> {
+        if index >= self.metadata.total_blocks {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The IPC file has {} record batches but batch {} was requested",
+                self.metadata.total_blocks, index
+            )));
+        }
+        read_batch(
+            &mut self.reader,
+            &self.metadata,
+            self.projection
+                .as_ref()
+                .map(|x| (x.0.as_ref(), x.1.clone())),
+            index,
+        )?
+        .ok_or_else(|| {
+            ArrowError::Ipc(format!(
+                "The block {} of the IPC file is not a record batch",
+                index
+            ))
+        })
+    }
 }
 
 impl<'a, R: Read + Seek> Iterator for FileReader<'a, R> {
@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::io::Read;
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
 
 use gen::Schema::MetadataVersion;
@@ -28,18 +29,26 @@ use crate::record_batch::RecordBatch;
 use super::super::CONTINUATION_MARKER;
 use super::super::{convert, gen};
 use super::common::*;
+use super::deserialize::skip;
 
 type ArrayRef = Arc<dyn Array>;
 
 #[derive(Debug)]
 pub struct StreamMetadata {
     /// The schema that is read from the stream's first message
-    schema: Arc<Schema>,
+    pub(super) schema: Arc<Schema>,
 
-    version: MetadataVersion,
+    pub(super) version: MetadataVersion,
 
     /// Whether the incoming stream is little-endian
-    is_little_endian: bool,
+    pub(super) is_little_endian: bool,
+}
+
+impl StreamMetadata {
+    /// The schema of the stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
 }
 
 /// Reads the metadata of the stream
@@ -59,7 +68,12 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer)?;
 
-    let message = gen::Message::root_as_message(meta_buffer.as_slice())
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+/// Deserializes the metadata of a stream from its first message, `meta`.
+pub(super) fn deserialize_stream_metadata(meta: &[u8]) -> Result<StreamMetadata> {
+    let message = gen::Message::root_as_message(meta)
         .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
     let version = message.version();
     // message header is a Schema, so read it
@@ -91,10 +105,13 @@ impl StreamState {
     }
 }
 
-/// Reads the next item
+/// Reads the next item. Use `projection` to only read certain columns: the buffers of the
+/// remaining columns and the dictionaries only they use are skipped without being read
+/// into memory.
 pub fn read_next<R: Read>(
     reader: &mut R,
     metadata: &StreamMetadata,
+    projection: Option<(&[usize], Arc<Schema>)>,
     dictionaries_by_field: &mut Vec<Option<ArrayRef>>,
 ) -> Result<Option<StreamState>> {
     // determine metadata length
@@ -135,6 +152,208 @@ pub fn read_next<R: Read>(
     let message = gen::Message::root_as_message(vecs)
         .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
 
+    if message.header_type() == gen::Message::MessageHeader::NONE {
+        return Ok(Some(StreamState::Waiting));
+    }
+
+    if let (Some((columns, _)), Some(batch)) = (&projection, message.header_as_dictionary_batch()) {
+        if !is_dictionary_projected(batch.id(), &metadata.schema, columns) {
+            // skip the body of the dictionary without reading it into memory
+            skip_bytes(reader, message.bodyLength() as u64)?;
+            return read_next(reader, metadata, projection, dictionaries_by_field);
+        }
+    }
+
+    if let (Some((columns, _)), Some(batch)) = (&projection, message.header_as_record_batch()) {
+        // read only the buffers of the projected columns into memory
+        let ranges = projected_ranges(batch, &metadata.schema, columns)?;
+        let mut body = SparseBody::try_new(reader, &ranges, message.bodyLength() as u64)?;
+        let batch = read_record_batch(
+            batch,
+            metadata.schema.clone(),
+            projection.clone(),
+            metadata.is_little_endian,
+            dictionaries_by_field,
+            metadata.version,
+            &mut body,
+            0,
+        )?;
+        return Ok(Some(StreamState::Some(batch)));
+    }
+
+    // read the block that makes up the message's body into a buffer
+    let mut body = vec![0; message.bodyLength() as usize];
+    reader.read_exact(&mut body)?;
+
+    match read_message(
+        message,
+        &body,
+        metadata,
+        projection.clone(),
+        dictionaries_by_field,
+    )? {
+        Some(batch) => Ok(Some(StreamState::Some(batch))),
+        // read the next message until we encounter a RecordBatch
+        None => read_next(reader, metadata, projection, dictionaries_by_field),
+    }
+}
+
+/// Whether the dictionary with id `id` is used by a column of `schema` selected by `projection`
+fn is_dictionary_projected(id: i64, schema: &Schema, projection: &[usize]) -> bool {
+    projection
+        .iter()
+        .any(|i| schema.fields()[*i].dict_id() == Some(id))
+}
+
+/// Consumes `length` bytes of `reader` without reading them into memory
+fn skip_bytes<R: Read>(reader: &mut R, length: u64) -> Result<()> {
+    std::io::copy(&mut reader.by_ref().take(length), &mut std::io::sink())?;
+    Ok(())
+}
+
+/// Returns the sorted and non-overlapping ranges `(start, end)` of the body of `batch`
+/// that contain the buffers of the columns of `schema` selected by `projection`.
+fn projected_ranges(
+    batch: gen::Message::RecordBatch,
+    schema: &Schema,
+    projection: &[usize],
+) -> Result<Vec<(u64, u64)>> {
+    let all_buffers = batch
+        .buffers()
+        .ok_or_else(|| ArrowError::Ipc("Unable to get buffers from IPC RecordBatch".to_string()))?;
+    let mut buffers: VecDeque<&gen::Schema::Buffer> = all_buffers.iter().collect();
+    let field_nodes = batch.nodes().ok_or_else(|| {
+        ArrowError::Ipc("Unable to get field nodes from IPC RecordBatch".to_string())
+    })?;
+    let no_dictionary = None;
+    let mut field_nodes = field_nodes
+        .iter()
+        .zip(std::iter::repeat(&no_dictionary))
+        .collect::<VecDeque<_>>();
+
+    let mut ranges = vec![];
+    for (i, field) in schema.fields().iter().enumerate() {
+        let start = all_buffers.len() - buffers.len();
+        skip(&mut field_nodes, field.data_type(), &mut buffers);
+        if projection.contains(&i) {
+            let end = all_buffers.len() - buffers.len();
+            ranges.extend(
+                all_buffers
+                    .iter()
+                    .skip(start)
+                    .take(end - start)
+                    .filter(|buffer| buffer.length() > 0)
+                    .map(|buffer| {
+                        let offset = buffer.offset() as u64;
+                        (offset, offset + buffer.length() as u64)
+                    }),
+            );
+        }
+    }
+    ranges.sort_unstable();
+
+    // merge contiguous and overlapping ranges
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Ok(merged)
+}
+
+/// The ranges of a message's body read from a stream, addressable by their offset in the body.
+struct SparseBody {
+    chunks: Vec<(u64, Vec<u8>)>,
+    length: u64,
+    position: u64,
+}
+
+impl SparseBody {
+    /// Reads the sorted and non-overlapping `ranges` of a body with `length` bytes from
+    /// `reader`, skipping the remaining bytes without reading them into memory.
+    fn try_new<R: Read>(reader: &mut R, ranges: &[(u64, u64)], length: u64) -> Result<Self> {
+        let mut position = 0;
+        let mut chunks = Vec::with_capacity(ranges.len());
+        for &(start, end) in ranges {
+            if end > length {
+                return Err(ArrowError::Ipc(
+                    "A buffer of the IPC RecordBatch is out of the bounds of its body".to_string(),
+                ));
+            }
+            skip_bytes(reader, start - position)?;
+            let mut chunk = vec![0; (end - start) as usize];
+            reader.read_exact(&mut chunk)?;
+            chunks.push((start, chunk));
+            position = end;
+        }
+        skip_bytes(reader, length - position)?;
+        Ok(Self {
+            chunks,
+            length,
+            position: 0,
+        })
+    }
+}
+
+impl Read for SparseBody {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let position = self.position;
+        let (start, chunk) = self
+            .chunks
+            .iter()
+            .find(|(start, chunk)| position >= *start && position < start + chunk.len() as u64)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Reading a range of the body that was skipped",
+                )
+            })?;
+        let read = (&chunk[(position - start) as usize..]).read(buf)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for SparseBody {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => checked_offset(self.length, offset),
+            SeekFrom::Current(offset) => checked_offset(self.position, offset),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Seeking to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+fn checked_offset(base: u64, offset: i64) -> Option<u64> {
+    if offset >= 0 {
+        base.checked_add(offset as u64)
+    } else {
+        base.checked_sub(offset.unsigned_abs())
+    }
+}
+
+/// Reads a message of the stream whose body is `body`. Returns the [`RecordBatch`] of a
+/// record batch message, or `None` for a dictionary batch message, which updates
+/// `dictionaries_by_field`.
+pub(super) fn read_message(
+    message: gen::Message::Message,
+    body: &[u8],
+    metadata: &StreamMetadata,
+    projection: Option<(&[usize], Arc<Schema>)>,
+    dictionaries_by_field: &mut Vec<Option<ArrayRef>>,
+) -> Result<Option<RecordBatch>> {
     match message.header_type() {
         gen::Message::MessageHeader::Schema => Err(ArrowError::Ipc(
             "Not expecting a schema when messages are read".to_string(),
@@ -143,33 +362,25 @@ pub fn read_next<R: Read>(
             let batch = message.header_as_record_batch().ok_or_else(|| {
                 ArrowError::Ipc("Unable to read IPC message as record batch".to_string())
             })?;
-            // read the block that makes up the record batch into a buffer
-            let mut buf = vec![0; message.bodyLength() as usize];
-            reader.read_exact(&mut buf)?;
-
-            let mut reader = std::io::Cursor::new(buf);
+            let mut reader = std::io::Cursor::new(body);
 
             read_record_batch(
                 batch,
                 metadata.schema.clone(),
-                None,
+                projection,
                 metadata.is_little_endian,
                 dictionaries_by_field,
                 metadata.version,
                 &mut reader,
                 0,
             )
-            .map(|x| Some(StreamState::Some(x)))
+            .map(Some)
         }
         gen::Message::MessageHeader::DictionaryBatch => {
             let batch = message.header_as_dictionary_batch().ok_or_else(|| {
                 ArrowError::Ipc("Unable to read IPC message as dictionary batch".to_string())
             })?;
-            // read the block that makes up the dictionary batch into a buffer
-            let mut buf = vec![0; message.bodyLength() as usize];
-            reader.read_exact(&mut buf)?;
-
-            let mut dict_reader = std::io::Cursor::new(buf);
+            let mut dict_reader = std::io::Cursor::new(body);
 
             read_dictionary(
                 batch,
@@ -179,11 +390,8 @@ pub fn read_next<R: Read>(
                 &mut dict_reader,
                 0,
             )?;
-
-            // read the next message until we encounter a RecordBatch
-            read_next(reader, metadata, dictionaries_by_field)
+            Ok(None)
         }
-        gen::Message::MessageHeader::NONE => Ok(Some(StreamState::Waiting)),
         t => Err(ArrowError::Ipc(format!(
             "Reading types other than record batches not yet supported, unable to read {:?} ",
             t
@@ -197,6 +405,7 @@ pub struct StreamReader<R: Read> {
     metadata: StreamMetadata,
     dictionaries_by_field: Vec<Option<ArrayRef>>,
     finished: bool,
+    projection: Option<(Vec<usize>, Arc<Schema>)>,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -205,19 +414,29 @@ impl<R: Read> StreamReader<R> {
     /// The first message in the stream is the schema, the reader will fail if it does not
     /// encounter a schema.
     /// To check if the reader is done, use `is_finished(self)`
-    pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+    ///
+    /// Use `projection` to only take certain columns.
+    /// # Panic
+    /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+    pub fn new(reader: R, metadata: StreamMetadata, projection: Option<Vec<usize>>) -> Self {
         let fields = metadata.schema.fields().len();
+        let projection =
+            projection.map(|projection| prepare_projection(&metadata.schema, projection));
         Self {
             reader,
             metadata,
             dictionaries_by_field: vec![None; fields],
             finished: false,
+            projection,
         }
     }
 
-    /// Return the schema of the stream
+    /// Return the schema of the stream, after projection
     pub fn schema(&self) -> &Arc<Schema> {
-        &self.metadata.schema
+        self.projection
+            .as_ref()
+            .map(|x| &x.1)
+            .unwrap_or(&self.metadata.schema)
     }
 
     /// Check if the stream is finished
@@ -232,6 +451,9 @@ impl<R: Read> StreamReader<R> {
         let batch = read_next(
             &mut self.reader,
             &self.metadata,
+            self.projection
+                .as_ref()
+                .map(|x| (x.0.as_ref(), x.1.clone())),
             &mut self.dictionaries_by_field,
         )?;
         if batch.is_none() {
@@ -0,0 +1,124 @@
+//! Asynchronous reading of the Arrow IPC streaming format.
+use std::sync::Arc;
+
+use futures::{AsyncRead, AsyncReadExt, Stream};
+
+use crate::array::*;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+use super::super::{gen, CONTINUATION_MARKER};
+use super::stream::{deserialize_stream_metadata, read_message, StreamMetadata};
+
+type ArrayRef = Arc<dyn Array>;
+
+/// Reads the length of the next message, or `None` if the reader reached its end.
+async fn read_message_len<R: AsyncRead + Unpin + Send>(reader: &mut R) -> Result<Option<usize>> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_size).await {
+        Ok(()) => (),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(ArrowError::from(e)),
+    }
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    Ok(Some(i32::from_le_bytes(meta_size) as usize))
+}
+
+/// Reads the metadata of the stream
+pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+) -> Result<StreamMetadata> {
+    let meta_len = read_message_len(reader)
+        .await?
+        .ok_or_else(|| ArrowError::Ipc("The stream has no schema message".to_string()))?;
+
+    let mut meta_buffer = vec![0; meta_len];
+    reader.read_exact(&mut meta_buffer).await?;
+
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+/// Asynchronous reader of the Arrow IPC streaming format, see [`AsyncStreamReader::into_stream`].
+/// Unlike [`super::StreamReader`], the end of `reader` is the end of the stream, whether or not
+/// it ends with the end-of-stream marker.
+pub struct AsyncStreamReader<R: AsyncRead + Unpin + Send> {
+    reader: R,
+    metadata: StreamMetadata,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin + Send> AsyncStreamReader<R> {
+    /// Reads the schema message of the stream from `reader` and returns a new
+    /// [`AsyncStreamReader`] of its batches.
+    pub async fn try_new(mut reader: R) -> Result<Self> {
+        let metadata = read_stream_metadata_async(&mut reader).await?;
+        let fields = metadata.schema().fields().len();
+        Ok(Self {
+            reader,
+            metadata,
+            dictionaries_by_field: vec![None; fields],
+            finished: false,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        self.metadata.schema()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the next batch of the stream, or `None` when the stream ended.
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        while !self.finished {
+            let meta_len = match read_message_len(&mut self.reader).await? {
+                Some(meta_len) if meta_len > 0 => meta_len,
+                // the end-of-stream marker or the end of the reader
+                _ => {
+                    self.finished = true;
+                    break;
+                }
+            };
+
+            let mut meta_buffer = vec![0; meta_len];
+            self.reader.read_exact(&mut meta_buffer).await?;
+            let message = gen::Message::root_as_message(&meta_buffer).map_err(|err| {
+                ArrowError::Ipc(format!("Unable to get root as message: {:?}", err))
+            })?;
+            if message.header_type() == gen::Message::MessageHeader::NONE {
+                continue;
+            }
+
+            let mut body = vec![0; message.bodyLength() as usize];
+            self.reader.read_exact(&mut body).await?;
+
+            if let Some(batch) = read_message(
+                message,
+                &body,
+                &self.metadata,
+                None,
+                &mut self.dictionaries_by_field,
+            )? {
+                return Ok(Some(batch));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Converts this reader into a [`Stream`] of [`RecordBatch`]es. The stream ends after
+    /// the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch>> {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok::<_, ArrowError>(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+    }
+}
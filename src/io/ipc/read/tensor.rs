@@ -0,0 +1,226 @@
+//! Reading of [`Tensor`] and [`SparseTensor`] from Arrow IPC messages.
+use std::collections::VecDeque;
+use std::io::{Cursor, Read};
+
+use super::super::{gen, CONTINUATION_MARKER};
+use super::read_basic::read_buffer;
+
+use crate::datatypes::DataType;
+use crate::endianess::is_native_little_endian;
+use crate::error::{ArrowError, Result};
+use crate::tensor::{SparseTensor, Tensor};
+use crate::types::NativeType;
+
+/// Reads an encapsulated message from `reader`, returning its metadata and its body.
+fn read_encapsulated<R: Read>(reader: &mut R) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    reader.read_exact(&mut meta_size)?;
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size)?;
+    }
+    let meta_len = i32::from_le_bytes(meta_size);
+
+    let mut meta_buffer = vec![0; meta_len as usize];
+    reader.read_exact(&mut meta_buffer)?;
+
+    let message = gen::Message::root_as_message(&meta_buffer)
+        .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+    let mut body = vec![0; message.bodyLength() as usize];
+    reader.read_exact(&mut body)?;
+
+    Ok((meta_buffer, body))
+}
+
+/// The [`DataType`] of the values of a tensor
+fn data_type(
+    type_type: gen::Schema::Type,
+    int: Option<gen::Schema::Int>,
+    float: Option<gen::Schema::FloatingPoint>,
+) -> Result<DataType> {
+    Ok(match (type_type, int, float) {
+        (gen::Schema::Type::Int, Some(int), _) => match (int.bitWidth(), int.is_signed()) {
+            (8, true) => DataType::Int8,
+            (8, false) => DataType::UInt8,
+            (16, true) => DataType::Int16,
+            (16, false) => DataType::UInt16,
+            (32, true) => DataType::Int32,
+            (32, false) => DataType::UInt32,
+            (64, true) => DataType::Int64,
+            (64, false) => DataType::UInt64,
+            (bit_width, _) => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Tensors of integers with bit width {} are not supported",
+                    bit_width
+                )))
+            }
+        },
+        (gen::Schema::Type::FloatingPoint, _, Some(float)) => match float.precision() {
+            gen::Schema::Precision::SINGLE => DataType::Float32,
+            gen::Schema::Precision::DOUBLE => DataType::Float64,
+            precision => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Tensors of floating points with precision {:?} are not supported",
+                    precision
+                )))
+            }
+        },
+        (type_type, _, _) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Tensors of type {:?} are not supported",
+                type_type
+            )))
+        }
+    })
+}
+
+/// The size and the name of each dimension of a tensor
+fn shape(
+    dims: flatbuffers::Vector<flatbuffers::ForwardsUOffset<gen::Tensor::TensorDim>>,
+) -> (Vec<usize>, Option<Vec<String>>) {
+    let shape = dims.iter().map(|dim| dim.size_() as usize).collect();
+    let names = if dims.iter().any(|dim| dim.name().is_some()) {
+        Some(
+            dims.iter()
+                .map(|dim| dim.name().unwrap_or_default().to_string())
+                .collect(),
+        )
+    } else {
+        None
+    };
+    (shape, names)
+}
+
+/// Reads an encapsulated Arrow IPC `Tensor` message from `reader` into a [`Tensor`].
+/// # Errors
+/// Errors if the message is not a tensor or if its values are not of type `T`.
+pub fn read_tensor<R: Read, T: NativeType>(reader: &mut R) -> Result<Tensor<T>> {
+    let (meta, body) = read_encapsulated(reader)?;
+    let message = gen::Message::root_as_message(&meta)
+        .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+    let tensor = message
+        .header_as_tensor()
+        .ok_or_else(|| ArrowError::Ipc("Unable to read IPC message as tensor".to_string()))?;
+
+    let data_type = data_type(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )?;
+    let (shape, names) = shape(tensor.shape());
+
+    let size = std::mem::size_of::<T>();
+    let strides = tensor
+        .strides()
+        .map(|strides| {
+            strides
+                .iter()
+                .map(|stride| {
+                    if stride < 0 || stride as usize % size != 0 {
+                        Err(ArrowError::Ipc(format!(
+                            "The stride {} of a tensor is not a multiple of the size of its values",
+                            stride
+                        )))
+                    } else {
+                        Ok(stride as usize / size)
+                    }
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+
+    // the buffer may be padded: only read the values reachable from the shape and strides
+    let length = match (&strides, shape.contains(&0)) {
+        (_, true) => 0,
+        (Some(strides), false) => {
+            1 + shape
+                .iter()
+                .zip(strides.iter())
+                .map(|(size, stride)| (size - 1) * stride)
+                .sum::<usize>()
+        }
+        (None, false) => shape.iter().product(),
+    };
+    let values = read_buffer(
+        &mut VecDeque::from(vec![tensor.data()]),
+        length,
+        &mut Cursor::new(body),
+        0,
+        is_native_little_endian(),
+        None,
+    )?;
+
+    Tensor::try_new(data_type, values, shape, strides, names)
+}
+
+/// Reads an encapsulated Arrow IPC `SparseTensor` message with a COO index from `reader`
+/// into a [`SparseTensor`].
+/// # Errors
+/// Errors if the message is not a sparse tensor with a COO index of 64 bit integers, or if its
+/// values are not of type `T`.
+pub fn read_sparse_tensor<R: Read, T: NativeType>(reader: &mut R) -> Result<SparseTensor<T>> {
+    let (meta, body) = read_encapsulated(reader)?;
+    let message = gen::Message::root_as_message(&meta)
+        .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+    let tensor = message.header_as_sparse_tensor().ok_or_else(|| {
+        ArrowError::Ipc("Unable to read IPC message as sparse tensor".to_string())
+    })?;
+
+    let data_type = data_type(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )?;
+    let (shape, names) = shape(tensor.shape());
+
+    let index = tensor
+        .sparseIndex_as_sparse_tensor_index_coo()
+        .ok_or_else(|| {
+            ArrowError::NotYetImplemented(
+                "Only sparse tensors with a COO index are supported".to_string(),
+            )
+        })?;
+    let indices_type = index.indicesType();
+    if indices_type.bitWidth() != 64 || !indices_type.is_signed() {
+        return Err(ArrowError::NotYetImplemented(
+            "Only sparse tensors with indices of 64 bit signed integers are supported".to_string(),
+        ));
+    }
+    let ndim = shape.len() as i64;
+    if let Some(strides) = index.indicesStrides() {
+        if strides.len() == 2 && (strides.get(0) != ndim * 8 || strides.get(1) != 8) {
+            return Err(ArrowError::NotYetImplemented(
+                "Only sparse tensors with row-major indices are supported".to_string(),
+            ));
+        }
+    }
+
+    let length = tensor.non_zero_length() as usize;
+    let mut body = Cursor::new(body);
+    let indices = read_buffer(
+        &mut VecDeque::from(vec![index.indicesBuffer()]),
+        length * shape.len(),
+        &mut body,
+        0,
+        is_native_little_endian(),
+        None,
+    )?;
+    let values = read_buffer(
+        &mut VecDeque::from(vec![tensor.data()]),
+        length,
+        &mut body,
+        0,
+        is_native_little_endian(),
+        None,
+    )?;
+
+    SparseTensor::try_new(
+        data_type,
+        values,
+        indices,
+        shape,
+        names,
+        index.isCanonical(),
+    )
+}
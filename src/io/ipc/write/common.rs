@@ -21,7 +21,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use super::super::gen;
 use super::super::CONTINUATION_MARKER;
-use super::{write, write_dictionary};
+use super::write;
 use flatbuffers::FlatBufferBuilder;
 
 use crate::array::Array;
@@ -47,6 +47,9 @@ pub struct IpcWriteOptions {
     /// version 2.0.0: V4, with legacy format enabled
     /// version 4.0.0: V5
     metadata_version: gen::Schema::MetadataVersion,
+    /// Whether the buffers are written in little endian. Defaults to the endianness of the
+    /// machine.
+    is_little_endian: bool,
 }
 
 impl IpcWriteOptions {
@@ -71,6 +74,7 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                is_little_endian: is_native_little_endian(),
             }),
             gen::Schema::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -82,6 +86,7 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        is_little_endian: is_native_little_endian(),
                     })
                 }
             }
@@ -92,6 +97,18 @@ impl IpcWriteOptions {
     pub fn metadata_version(&self) -> &gen::Schema::MetadataVersion {
         &self.metadata_version
     }
+
+    /// Sets whether the buffers are written in little endian (the default on most machines)
+    /// or in big endian, independently of the endianness of the machine.
+    pub fn with_little_endian(mut self, is_little_endian: bool) -> Self {
+        self.is_little_endian = is_little_endian;
+        self
+    }
+
+    /// Whether the buffers are written in little endian
+    pub fn is_little_endian(&self) -> bool {
+        self.is_little_endian
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -100,6 +117,7 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: false,
             metadata_version: gen::Schema::MetadataVersion::V5,
+            is_little_endian: is_native_little_endian(),
         }
     }
 }
@@ -121,16 +139,17 @@ pub fn encoded_batch(
                 .dict_id()
                 .expect("All Dictionary types have `dict_id`");
 
-            let emit = dictionary_tracker.insert(dict_id, column)?;
-
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes(
-                    dict_id,
-                    column.as_ref(),
-                    write_options,
-                    is_native_little_endian(),
-                ));
-            }
+            let (values, is_delta) = match dictionary_tracker.insert_with_delta(dict_id, column)? {
+                DictionaryEmit::Skip => continue,
+                DictionaryEmit::Full(values) => (values, false),
+                DictionaryEmit::Delta(values) => (values, true),
+            };
+            encoded_dictionaries.push(dictionary_batch_to_bytes(
+                dict_id,
+                values.as_ref(),
+                is_delta,
+                write_options,
+            ));
         }
     }
 
@@ -155,7 +174,7 @@ fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -
             &mut arrow_data,
             &mut nodes,
             &mut offset,
-            is_native_little_endian(),
+            write_options.is_little_endian,
         )
     }
 
@@ -188,12 +207,13 @@ fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (gen::Schema::Message) and the
-/// other for the data
+/// other for the data. When `is_delta`, `values` are appended to the dictionary previously sent
+/// with the same id.
 fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &dyn Array,
+    values: &dyn Array,
+    is_delta: bool,
     write_options: &IpcWriteOptions,
-    is_little_endian: bool,
 ) -> EncodedData {
     let mut fbb = FlatBufferBuilder::new();
 
@@ -201,15 +221,15 @@ fn dictionary_batch_to_bytes(
     let mut buffers: Vec<gen::Schema::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
-        is_little_endian,
-        false,
+        write_options.is_little_endian,
     );
+    let length = values.len();
 
     // write data
     let buffers = fbb.create_vector(&buffers);
@@ -227,6 +247,7 @@ fn dictionary_batch_to_bytes(
         let mut batch_builder = gen::Message::DictionaryBatchBuilder::new(&mut fbb);
         batch_builder.add_id(dict_id);
         batch_builder.add_data(root);
+        batch_builder.add_isDelta(is_delta);
         batch_builder.finish().as_union_value()
     };
 
@@ -248,6 +269,18 @@ fn dictionary_batch_to_bytes(
     }
 }
 
+/// The dictionary batch to emit for a dictionary, as decided by
+/// [`DictionaryTracker::insert_with_delta`].
+#[derive(Debug, Clone)]
+pub enum DictionaryEmit {
+    /// The dictionary was already emitted
+    Skip,
+    /// The values of the dictionary must be emitted, replacing any previous dictionary
+    Full(Arc<dyn Array>),
+    /// Only these values, appended to the previously emitted dictionary, must be emitted
+    Delta(Arc<dyn Array>),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
@@ -264,6 +297,12 @@ impl DictionaryTracker {
         }
     }
 
+    /// Marks `values` as the dictionary with the given ID already written, e.g. to a file
+    /// being appended to.
+    pub(crate) fn insert_written(&mut self, dict_id: i64, values: Arc<dyn Array>) {
+        self.written.insert(dict_id, values);
+    }
+
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
@@ -274,6 +313,26 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<bool> {
+        self.insert_with_delta(dict_id, array)
+            .map(|emit| !matches!(emit, DictionaryEmit::Skip))
+    }
+
+    /// Keep track of the dictionary with the given ID and values, like [`Self::insert`], but
+    /// returning which dictionary batch must be emitted:
+    ///
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryEmit::Skip`] to indicate that the dictionary does not need to be emitted.
+    /// * If this ID has been written already but with different data, and this tracker is
+    ///   configured to return an error, return an error.
+    /// * If this ID has been written already and the new values start with the written ones,
+    ///   return [`DictionaryEmit::Delta`] with the values that were added.
+    /// * Otherwise, return [`DictionaryEmit::Full`] to indicate that the whole dictionary must
+    ///   be emitted.
+    pub fn insert_with_delta(
+        &mut self,
+        dict_id: i64,
+        array: &Arc<dyn Array>,
+    ) -> Result<DictionaryEmit> {
         let values = match array.data_type() {
             DataType::Dictionary(key_type, _) => {
                 with_match_dictionary_key_type!(key_type.as_ref(), |$T| {
@@ -288,10 +347,10 @@ impl DictionaryTracker {
         };
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
+        let emit = if let Some(last) = self.written.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryEmit::Skip);
             } else if self.error_on_replacement {
                 return Err(ArrowError::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
@@ -299,11 +358,20 @@ impl DictionaryTracker {
                      across all batches."
                         .to_string(),
                 ));
+            } else if values.len() > last.len()
+                && values.slice(0, last.len()).as_ref() == last.as_ref()
+            {
+                let added = values.slice(last.len(), values.len() - last.len());
+                DictionaryEmit::Delta(added.into())
+            } else {
+                DictionaryEmit::Full(values.clone())
             }
+        } else {
+            DictionaryEmit::Full(values.clone())
         };
 
         self.written.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(emit)
     }
 }
 
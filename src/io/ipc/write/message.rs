@@ -0,0 +1,47 @@
+//! Encoding of individual IPC messages, independent of the framing of the file and stream
+//! formats, e.g. to send Arrow data over a custom transport.
+use super::common::{encoded_batch, DictionaryTracker, EncodedData, IpcWriteOptions};
+use super::schema::schema_to_bytes_with_endianness;
+
+use crate::datatypes::Schema;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+
+/// Encodes schemas and [`RecordBatch`]es into IPC messages, each an [`EncodedData`] with
+/// a header (`ipc_message`, an encoded `Message` flatbuffer) and a body (`arrow_data`).
+/// The encoder keeps track of the dictionaries already encoded, so that each is only
+/// encoded again when it changes, as a delta when it extends the previous one.
+///
+/// The messages are decoded by [`MessageDecoder`](crate::io::ipc::read::MessageDecoder).
+pub struct MessageEncoder {
+    options: IpcWriteOptions,
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl MessageEncoder {
+    /// Returns a new [`MessageEncoder`] encoding messages with `options`
+    pub fn new(options: IpcWriteOptions) -> Self {
+        Self {
+            options,
+            dictionary_tracker: DictionaryTracker::new(false),
+        }
+    }
+
+    /// Encodes `schema` into a message, whose body is empty
+    pub fn encode_schema(&self, schema: &Schema) -> EncodedData {
+        EncodedData {
+            ipc_message: schema_to_bytes_with_endianness(
+                schema,
+                *self.options.metadata_version(),
+                self.options.is_little_endian(),
+            ),
+            arrow_data: vec![],
+        }
+    }
+
+    /// Encodes `batch` into a message, returning it together with the messages of the
+    /// dictionaries it requires, which must be sent before it.
+    pub fn encode_batch(&mut self, batch: &RecordBatch) -> Result<(Vec<EncodedData>, EncodedData)> {
+        encoded_batch(batch, &mut self.dictionary_tracker, &self.options)
+    }
+}
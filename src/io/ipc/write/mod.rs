@@ -1,13 +1,23 @@
 //! APIs to write to Arrow's IPC format.
 pub mod common;
+mod message;
 mod schema;
 mod serialize;
 mod stream;
+#[cfg(feature = "io_ipc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_async")))]
+mod stream_async;
+mod tensor;
 mod writer;
 
 pub use super::gen::Schema::MetadataVersion;
-pub use common::IpcWriteOptions;
+pub use common::{EncodedData, IpcWriteOptions};
+pub use message::MessageEncoder;
 pub use schema::schema_to_bytes;
 pub use serialize::{write, write_dictionary};
 pub use stream::StreamWriter;
+#[cfg(feature = "io_ipc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_async")))]
+pub use stream_async::AsyncStreamWriter;
+pub use tensor::{write_sparse_tensor, write_tensor};
 pub use writer::FileWriter;
@@ -1,15 +1,25 @@
 use flatbuffers::FlatBufferBuilder;
 
 use crate::datatypes::*;
+use crate::endianess::is_native_little_endian;
 
 use super::super::{convert, gen};
 use super::MetadataVersion;
 
 /// Converts
 pub fn schema_to_bytes(schema: &Schema, version: MetadataVersion) -> Vec<u8> {
+    schema_to_bytes_with_endianness(schema, version, is_native_little_endian())
+}
+
+/// Converts a [`Schema`] to an IPC message whose buffers have the given endianness
+pub(super) fn schema_to_bytes_with_endianness(
+    schema: &Schema,
+    version: MetadataVersion,
+    is_little_endian: bool,
+) -> Vec<u8> {
     let mut fbb = FlatBufferBuilder::new();
     let schema = {
-        let fb = convert::schema_to_fb_offset(&mut fbb, schema);
+        let fb = convert::schema_to_fb_offset(&mut fbb, schema, is_little_endian);
         fb.as_union_value()
     };
 
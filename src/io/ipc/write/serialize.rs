@@ -484,7 +484,7 @@ fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_littl
 }
 
 /// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
-fn write_buffer<T: NativeType>(
+pub(super) fn write_buffer<T: NativeType>(
     buffer: &[T],
     buffers: &mut Vec<Schema::Buffer>,
     arrow_data: &mut Vec<u8>,
@@ -26,7 +26,7 @@ use super::common::{
     encoded_batch, write_continuation, write_message, DictionaryTracker, EncodedData,
     IpcWriteOptions,
 };
-use super::schema_to_bytes;
+use super::schema::schema_to_bytes_with_endianness;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -58,7 +58,11 @@ impl<W: Write> StreamWriter<W> {
         let mut writer = BufWriter::new(writer);
         // write the schema, set the written bytes to the schema
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, *write_options.metadata_version()),
+            ipc_message: schema_to_bytes_with_endianness(
+                schema,
+                *write_options.metadata_version(),
+                write_options.is_little_endian(),
+            ),
             arrow_data: vec![],
         };
         write_message(&mut writer, encoded_message, &write_options)?;
@@ -0,0 +1,101 @@
+//! Asynchronous writing of the Arrow IPC streaming format.
+use futures::{AsyncWrite, AsyncWriteExt};
+
+use super::common::{
+    encoded_batch, write_continuation, write_message, DictionaryTracker, EncodedData,
+    IpcWriteOptions,
+};
+use super::schema::schema_to_bytes_with_endianness;
+
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// Asynchronous writer of the Arrow IPC streaming format. Each message is encoded in memory
+/// and written with a single write to `writer`.
+///
+/// Unlike [`super::StreamWriter`], this writer can't finish the stream when dropped:
+/// [`AsyncStreamWriter::finish`] must be called to write the end-of-stream marker.
+pub struct AsyncStreamWriter<W: AsyncWrite + Unpin + Send> {
+    /// The object to write to
+    writer: W,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// Whether the end-of-stream marker has been written, and the writer is finished
+    finished: bool,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncStreamWriter<W> {
+    /// Try create a new writer, with the schema written as part of the header
+    pub async fn try_new(writer: W, schema: &Schema) -> Result<Self> {
+        let write_options = IpcWriteOptions::default();
+        Self::try_new_with_options(writer, schema, write_options).await
+    }
+
+    pub async fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self> {
+        let mut writer = Self {
+            writer,
+            write_options,
+            finished: false,
+            dictionary_tracker: DictionaryTracker::new(false),
+        };
+        let encoded_message = EncodedData {
+            ipc_message: schema_to_bytes_with_endianness(
+                schema,
+                *writer.write_options.metadata_version(),
+                writer.write_options.is_little_endian(),
+            ),
+            arrow_data: vec![],
+        };
+        writer.write_encoded(encoded_message).await?;
+        Ok(writer)
+    }
+
+    async fn write_encoded(&mut self, encoded: EncodedData) -> Result<()> {
+        let mut data = vec![];
+        write_message(&mut data, encoded, &self.write_options)?;
+        self.writer.write_all(&data).await?;
+        Ok(())
+    }
+
+    /// Write a record batch to the stream, preceded by the dictionaries it requires
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::Ipc(
+                "Cannot write record batch to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_message) =
+            encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+                .expect("AsyncStreamWriter is configured to not error on dictionary replacement");
+
+        for encoded_dictionary in encoded_dictionaries {
+            self.write_encoded(encoded_dictionary).await?;
+        }
+        self.write_encoded(encoded_message).await
+    }
+
+    /// Write the end-of-stream marker, mark the stream as done and flush the writer
+    pub async fn finish(&mut self) -> Result<()> {
+        let mut data = vec![];
+        write_continuation(&mut data, &self.write_options, 0)?;
+        self.writer.write_all(&data).await?;
+        self.writer.flush().await?;
+
+        self.finished = true;
+
+        Ok(())
+    }
+
+    /// Returns the inner writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -0,0 +1,177 @@
+//! Writing of [`Tensor`] and [`SparseTensor`] as Arrow IPC messages.
+use std::io::Write;
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use super::super::{convert, gen};
+use super::common::{write_message, EncodedData, IpcWriteOptions};
+use super::serialize::write_buffer;
+
+use crate::endianess::is_native_little_endian;
+use crate::error::Result;
+use crate::tensor::{SparseTensor, Tensor};
+use crate::types::NativeType;
+
+fn write_shape<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    shape: &[usize],
+    names: Option<&[String]>,
+) -> WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<gen::Tensor::TensorDim<'a>>>> {
+    let dims = shape
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let name = names.map(|names| fbb.create_string(&names[i]));
+            let mut builder = gen::Tensor::TensorDimBuilder::new(fbb);
+            builder.add_size_(*size as i64);
+            if let Some(name) = name {
+                builder.add_name(name);
+            }
+            builder.finish()
+        })
+        .collect::<Vec<_>>();
+    fbb.create_vector(&dims)
+}
+
+fn finish_message(
+    mut fbb: FlatBufferBuilder,
+    header_type: gen::Message::MessageHeader,
+    header: WIPOffset<flatbuffers::UnionWIPOffset>,
+    arrow_data: Vec<u8>,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let root = {
+        let mut message = gen::Message::MessageBuilder::new(&mut fbb);
+        message.add_version(*write_options.metadata_version());
+        message.add_header_type(header_type);
+        message.add_bodyLength(arrow_data.len() as i64);
+        message.add_header(header);
+        message.finish()
+    };
+    fbb.finish(root, None);
+
+    EncodedData {
+        ipc_message: fbb.finished_data().to_vec(),
+        arrow_data,
+    }
+}
+
+/// Writes `tensor` to `writer` as an encapsulated Arrow IPC `Tensor` message.
+pub fn write_tensor<W: Write, T: NativeType>(
+    writer: &mut W,
+    tensor: &Tensor<T>,
+    write_options: &IpcWriteOptions,
+) -> Result<()> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    write_buffer(
+        tensor.values().as_slice(),
+        &mut buffers,
+        &mut arrow_data,
+        &mut 0,
+        is_native_little_endian(),
+    );
+
+    let type_ = convert::get_fb_field_type(tensor.data_type(), false, &mut fbb);
+    let shape = write_shape(&mut fbb, tensor.shape(), tensor.names());
+    // IPC strides are in bytes
+    let strides = tensor
+        .strides()
+        .iter()
+        .map(|stride| (stride * std::mem::size_of::<T>()) as i64)
+        .collect::<Vec<_>>();
+    let strides = fbb.create_vector(&strides);
+
+    let header = {
+        let mut builder = gen::Tensor::TensorBuilder::new(&mut fbb);
+        builder.add_type_type(type_.type_type);
+        builder.add_type_(type_.type_);
+        builder.add_shape(shape);
+        builder.add_strides(strides);
+        builder.add_data(&buffers[0]);
+        builder.finish().as_union_value()
+    };
+
+    let encoded = finish_message(
+        fbb,
+        gen::Message::MessageHeader::Tensor,
+        header,
+        arrow_data,
+        write_options,
+    );
+    write_message(writer, encoded, write_options)?;
+    Ok(())
+}
+
+/// Writes `tensor` to `writer` as an encapsulated Arrow IPC `SparseTensor` message with a
+/// COO index.
+pub fn write_sparse_tensor<W: Write, T: NativeType>(
+    writer: &mut W,
+    tensor: &SparseTensor<T>,
+    write_options: &IpcWriteOptions,
+) -> Result<()> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut offset = 0;
+    write_buffer(
+        tensor.indices().as_slice(),
+        &mut buffers,
+        &mut arrow_data,
+        &mut offset,
+        is_native_little_endian(),
+    );
+    write_buffer(
+        tensor.values().as_slice(),
+        &mut buffers,
+        &mut arrow_data,
+        &mut offset,
+        is_native_little_endian(),
+    );
+
+    let type_ = convert::get_fb_field_type(tensor.data_type(), false, &mut fbb);
+    let shape = write_shape(&mut fbb, tensor.shape(), tensor.names());
+
+    let indices_type = {
+        let mut builder = gen::Schema::IntBuilder::new(&mut fbb);
+        builder.add_bitWidth(64);
+        builder.add_is_signed(true);
+        builder.finish()
+    };
+    // the indices are a row-major matrix of i64, with strides in bytes
+    let ndim = tensor.ndim() as i64;
+    let indices_strides = fbb.create_vector(&[ndim * 8, 8]);
+    let index = {
+        let mut builder = gen::SparseTensor::SparseTensorIndexCOOBuilder::new(&mut fbb);
+        builder.add_indicesType(indices_type);
+        builder.add_indicesStrides(indices_strides);
+        builder.add_indicesBuffer(&buffers[0]);
+        builder.add_isCanonical(tensor.is_canonical());
+        builder.finish().as_union_value()
+    };
+
+    let header = {
+        let mut builder = gen::SparseTensor::SparseTensorBuilder::new(&mut fbb);
+        builder.add_type_type(type_.type_type);
+        builder.add_type_(type_.type_);
+        builder.add_shape(shape);
+        builder.add_non_zero_length(tensor.values().len() as i64);
+        builder.add_sparseIndex_type(gen::SparseTensor::SparseTensorIndex::SparseTensorIndexCOO);
+        builder.add_sparseIndex(index);
+        builder.add_data(&buffers[1]);
+        builder.finish().as_union_value()
+    };
+
+    let encoded = finish_message(
+        fbb,
+        gen::Message::MessageHeader::SparseTensor,
+        header,
+        arrow_data,
+        write_options,
+    );
+    write_message(writer, encoded, write_options)?;
+    Ok(())
+}
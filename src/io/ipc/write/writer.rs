@@ -20,16 +20,17 @@
 //! The `FileWriter` and `StreamWriter` have similar interfaces,
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
-use std::io::Write;
+use std::io::{Read, Seek, SeekFrom, Write};
 
-use super::super::ARROW_MAGIC;
+use super::super::read::read_file_metadata;
+use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::{
     super::{convert, gen},
     common::{
         encoded_batch, write_continuation, write_message, DictionaryTracker, EncodedData,
         IpcWriteOptions,
     },
-    schema_to_bytes,
+    schema::schema_to_bytes_with_endianness,
 };
 use flatbuffers::FlatBufferBuilder;
 
@@ -54,6 +55,8 @@ pub struct FileWriter<'a, W: Write> {
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// Truncates the writer to the length of the file when finished, when appending
+    truncate: Option<fn(&mut W, u64) -> std::io::Result<()>>,
 }
 
 impl<'a, W: Write> FileWriter<'a, W> {
@@ -75,7 +78,11 @@ impl<'a, W: Write> FileWriter<'a, W> {
         writer.write_all(&[0, 0])?;
         // write the schema, set the written bytes to the schema
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, *write_options.metadata_version()),
+            ipc_message: schema_to_bytes_with_endianness(
+                schema,
+                *write_options.metadata_version(),
+                write_options.is_little_endian(),
+            ),
             arrow_data: vec![],
         };
         let (meta, data) = write_message(writer, encoded_message, &write_options)?;
@@ -88,6 +95,7 @@ impl<'a, W: Write> FileWriter<'a, W> {
             record_blocks: vec![],
             finished: false,
             dictionary_tracker: DictionaryTracker::new(true),
+            truncate: None,
         })
     }
 
@@ -126,12 +134,16 @@ impl<'a, W: Write> FileWriter<'a, W> {
     /// Write footer and closing tag, then mark the writer as done
     pub fn finish(&mut self) -> Result<()> {
         // write EOS
-        write_continuation(&mut self.writer, &self.write_options, 0)?;
+        let eos = write_continuation(&mut self.writer, &self.write_options, 0)?;
 
         let mut fbb = FlatBufferBuilder::new();
         let dictionaries = fbb.create_vector(&self.dictionary_blocks);
         let record_batches = fbb.create_vector(&self.record_blocks);
-        let schema = convert::schema_to_fb_offset(&mut fbb, &self.schema);
+        let schema = convert::schema_to_fb_offset(
+            &mut fbb,
+            &self.schema,
+            self.write_options.is_little_endian(),
+        );
 
         let root = {
             let mut footer_builder = gen::File::FooterBuilder::new(&mut fbb);
@@ -147,13 +159,102 @@ impl<'a, W: Write> FileWriter<'a, W> {
         self.writer
             .write_all(&(footer_data.len() as i32).to_le_bytes())?;
         self.writer.write_all(&ARROW_MAGIC)?;
-        self.writer.flush()?;
         self.finished = true;
 
+        // an appended file may end before its original end
+        if let Some(truncate) = self.truncate {
+            let length = self.block_offsets + eos + footer_data.len() + 4 + ARROW_MAGIC.len();
+            truncate(self.writer, length as u64)?;
+        }
+        self.writer.flush()?;
         Ok(())
     }
 }
 
+impl<'a> FileWriter<'a, std::fs::File> {
+    /// Opens the Arrow file `file` to append record batches to it. The batches must have the
+    /// schema of the file, and use the same dictionaries as the batches already in the file.
+    /// The footer of the file is overwritten and rewritten by [`FileWriter::finish`]
+    /// with the blocks of both the existing and the appended batches, and the file is
+    /// truncated to its new end.
+    /// # Errors
+    /// Errors if `file` is not a valid Arrow file or if its metadata version can't be written.
+    pub fn try_new_append(file: &'a mut std::fs::File) -> Result<Self> {
+        Self::try_new_append_with(file, |file, length| file.set_len(length))
+    }
+}
+
+impl<'a, W: Read + Write + Seek> FileWriter<'a, W> {
+    /// Opens the Arrow file `writer` to append record batches to it, like
+    /// [`FileWriter::try_new_append`] for writers other than [`std::fs::File`].
+    ///
+    /// The rewritten end of the file may be shorter than the original one, e.g. when the
+    /// original footer was written by another producer. Since readers read the footer from
+    /// the end of the file, [`FileWriter::finish`] calls `truncate` with the new length of
+    /// the file, which must truncate `writer` to it.
+    /// # Errors
+    /// Errors if `writer` is not a valid Arrow file or if its metadata version can't be written.
+    pub fn try_new_append_with(
+        writer: &'a mut W,
+        truncate: fn(&mut W, u64) -> std::io::Result<()>,
+    ) -> Result<Self> {
+        writer.seek(SeekFrom::Start(0))?;
+        let metadata = read_file_metadata(writer)?;
+        let write_options = IpcWriteOptions::try_new(8, false, metadata.version)?
+            .with_little_endian(metadata.is_little_endian);
+
+        // the new messages are written right after the last block, over the footer
+        let blocks = metadata
+            .dictionary_blocks
+            .iter()
+            .chain(metadata.blocks.iter());
+        let end = blocks
+            .map(|block| block.offset() + block.metaDataLength() as i64 + block.bodyLength())
+            .max();
+        let end = match end {
+            Some(end) => end as u64,
+            // no blocks: the end of the schema message, after the header
+            None => {
+                writer.seek(SeekFrom::Start(8))?;
+                let mut meta_size: [u8; 4] = [0; 4];
+                writer.read_exact(&mut meta_size)?;
+                let prefix_size = if meta_size == CONTINUATION_MARKER {
+                    writer.read_exact(&mut meta_size)?;
+                    8
+                } else {
+                    4
+                };
+                8 + prefix_size + i32::from_le_bytes(meta_size) as u64
+            }
+        };
+        writer.seek(SeekFrom::Start(end))?;
+
+        let mut dictionary_tracker = DictionaryTracker::new(true);
+        for (field, values) in metadata
+            .schema()
+            .fields()
+            .iter()
+            .zip(metadata.dictionaries_by_field.iter())
+        {
+            if let (Some(dict_id), Some(values)) = (field.dict_id(), values) {
+                dictionary_tracker.insert_written(dict_id, values.clone());
+            }
+        }
+
+        Ok(Self {
+            writer,
+            write_options,
+            schema: metadata.schema().as_ref().clone(),
+            block_offsets: end as usize,
+            dictionary_blocks: metadata.dictionary_blocks,
+            record_blocks: metadata.blocks,
+            finished: false,
+            dictionary_tracker,
+            truncate: Some(truncate),
+        })
+    }
+}
+
 /// Finish the file if it is not 'finished' when it goes out of scope
 impl<'a, W: Write> Drop for FileWriter<'a, W> {
     fn drop(&mut self) {
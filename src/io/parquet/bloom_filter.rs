@@ -0,0 +1,415 @@
+//! Split block bloom filters, as declared by the
+//! [parquet specification](https://github.com/apache/parquet-format/blob/master/BloomFilter.md).
+//!
+//! Values are hashed from their PLAIN encoding (without the length prefix of byte arrays)
+//! with xxHash64, after the same casts done when writing them (e.g. `u8` is written as `i32`).
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::{BinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut value = [0; 8];
+    value.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(value)
+}
+
+#[inline]
+fn read_u32(bytes: &[u8]) -> u32 {
+    let mut value = [0; 4];
+    value.copy_from_slice(&bytes[..4]);
+    u32::from_le_bytes(value)
+}
+
+#[inline]
+fn round(accumulator: u64, input: u64) -> u64 {
+    accumulator
+        .wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+#[inline]
+fn merge_round(accumulator: u64, value: u64) -> u64 {
+    (accumulator ^ round(0, value))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+/// The [xxHash64](https://github.com/Cyan4973/xxHash) of `bytes`, with seed 0.
+pub fn hash(bytes: &[u8]) -> u64 {
+    let mut remaining = bytes;
+    let mut hash = if bytes.len() >= 32 {
+        let mut v1 = PRIME64_1.wrapping_add(PRIME64_2);
+        let mut v2 = PRIME64_2;
+        let mut v3 = 0u64;
+        let mut v4 = 0u64.wrapping_sub(PRIME64_1);
+        while remaining.len() >= 32 {
+            v1 = round(v1, read_u64(remaining));
+            v2 = round(v2, read_u64(&remaining[8..]));
+            v3 = round(v3, read_u64(&remaining[16..]));
+            v4 = round(v4, read_u64(&remaining[24..]));
+            remaining = &remaining[32..];
+        }
+        let hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        [v1, v2, v3, v4]
+            .iter()
+            .fold(hash, |hash, value| merge_round(hash, *value))
+    } else {
+        PRIME64_5
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while remaining.len() >= 8 {
+        hash ^= round(0, read_u64(remaining));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        remaining = &remaining[8..];
+    }
+    if remaining.len() >= 4 {
+        hash ^= (read_u32(remaining) as u64).wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        remaining = &remaining[4..];
+    }
+    for byte in remaining {
+        hash ^= (*byte as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^ (hash >> 32)
+}
+
+const SALT: [u32; 8] = [
+    0x47b6_137b,
+    0x4497_4d91,
+    0x8824_ad5b,
+    0xa2b7_289d,
+    0x7054_95c7,
+    0x2df1_424b,
+    0x9efc_4947,
+    0x5c6b_fb31,
+];
+
+/// The smallest and largest bitsets of the filters built by [`BloomFilter::from_ndv_fpp`].
+const MIN_SIZE: usize = 32;
+const MAX_SIZE: usize = 128 * 1024 * 1024;
+
+/// Options of the bloom filter of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The expected number of distinct values of a column chunk
+    pub ndv: u64,
+    /// The target false positive probability, in `(0, 1)`
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: 1024 * 1024,
+            fpp: 0.05,
+        }
+    }
+}
+
+/// A split block bloom filter: a bitset of blocks of 8 words of 32 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    blocks: Vec<[u32; 8]>,
+}
+
+#[inline]
+fn mask(key: u32) -> [u32; 8] {
+    let mut mask = [0; 8];
+    mask.iter_mut()
+        .zip(SALT.iter())
+        .for_each(|(mask, salt)| *mask = 1 << (key.wrapping_mul(*salt) >> 27));
+    mask
+}
+
+impl BloomFilter {
+    /// Returns an empty filter whose bitset has `num_bytes` bytes, rounded up to a whole block.
+    pub fn new(num_bytes: usize) -> Self {
+        Self {
+            blocks: vec![[0; 8]; ((num_bytes + 31) / 32).max(1)],
+        }
+    }
+
+    /// Returns an empty filter sized so that `ndv` distinct values have a false positive
+    /// probability of at most `fpp`. Its size is a power of two between 32 bytes and 128MB.
+    pub fn from_ndv_fpp(ndv: u64, fpp: f64) -> Self {
+        let bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+        let num_bytes = ((bits / 8.0).ceil() as usize)
+            .next_power_of_two()
+            .clamp(MIN_SIZE, MAX_SIZE);
+        Self::new(num_bytes)
+    }
+
+    /// Returns a filter from its bitset, as stored in parquet files.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let blocks = bytes
+            .chunks_exact(32)
+            .map(|block| {
+                let mut words = [0; 8];
+                words
+                    .iter_mut()
+                    .zip(block.chunks_exact(4))
+                    .for_each(|(word, bytes)| *word = read_u32(bytes));
+                words
+            })
+            .collect();
+        Self { blocks }
+    }
+
+    /// Returns the bitset of this filter, as stored in parquet files.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.iter())
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    #[inline]
+    fn block(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize
+    }
+
+    /// Inserts the hash of a value.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let block = self.block(hash);
+        let mask = mask(hash as u32);
+        self.blocks[block]
+            .iter_mut()
+            .zip(mask.iter())
+            .for_each(|(word, mask)| *word |= mask);
+    }
+
+    /// Returns whether the value of `hash` may have been inserted. `false` guarantees that
+    /// it was not.
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block(hash)];
+        let mask = mask(hash as u32);
+        block
+            .iter()
+            .zip(mask.iter())
+            .all(|(word, mask)| word & mask != 0)
+    }
+
+    /// Inserts the non-null values of `array`.
+    /// # Errors
+    /// Iff `array`'s data type is not supported by [`can_filter`].
+    pub fn insert_array(&mut self, array: &dyn Array) -> Result<()> {
+        macro_rules! insert_primitive {
+            ($from:ty, $to:ty) => {{
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$from>>()
+                    .unwrap();
+                array
+                    .iter()
+                    .flatten()
+                    .for_each(|x| self.insert_hash(hash(&(*x as $to).to_le_bytes())))
+            }};
+        }
+        macro_rules! insert_binary {
+            ($array:ty) => {{
+                let array = array.as_any().downcast_ref::<$array>().unwrap();
+                array
+                    .iter()
+                    .flatten()
+                    .for_each(|x| self.insert_hash(hash(x.as_ref())))
+            }};
+        }
+        match array.data_type().to_logical_type() {
+            DataType::Int8 => insert_primitive!(i8, i32),
+            DataType::Int16 => insert_primitive!(i16, i32),
+            DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+                insert_primitive!(i32, i32)
+            }
+            DataType::UInt8 => insert_primitive!(u8, i32),
+            DataType::UInt16 => insert_primitive!(u16, i32),
+            DataType::UInt32 => insert_primitive!(u32, i32),
+            DataType::Int64
+            | DataType::Date64
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_) => insert_primitive!(i64, i64),
+            DataType::UInt64 => insert_primitive!(u64, i64),
+            DataType::Float32 => insert_primitive!(f32, f32),
+            DataType::Float64 => insert_primitive!(f64, f64),
+            DataType::Utf8 => insert_binary!(Utf8Array<i32>),
+            DataType::LargeUtf8 => insert_binary!(Utf8Array<i64>),
+            DataType::Binary => insert_binary!(BinaryArray<i32>),
+            DataType::LargeBinary => insert_binary!(BinaryArray<i64>),
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Bloom filters do not support the data type {:?}",
+                    other
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether `scalar` may be in the filter. Returns `true` for null scalars and
+    /// scalars of data types not supported by [`can_filter`], since they can't be checked.
+    pub fn contains_scalar(&self, scalar: &dyn Scalar) -> bool {
+        if !scalar.is_valid() {
+            return true;
+        }
+        macro_rules! primitive_hash {
+            ($from:ty, $to:ty) => {{
+                let scalar = scalar
+                    .as_any()
+                    .downcast_ref::<PrimitiveScalar<$from>>()
+                    .unwrap();
+                hash(&(scalar.value() as $to).to_le_bytes())
+            }};
+        }
+        macro_rules! binary_hash {
+            ($scalar:ty) => {{
+                let scalar = scalar.as_any().downcast_ref::<$scalar>().unwrap();
+                hash(scalar.value().as_ref())
+            }};
+        }
+        let hash = match scalar.data_type().to_logical_type() {
+            DataType::Int8 => primitive_hash!(i8, i32),
+            DataType::Int16 => primitive_hash!(i16, i32),
+            DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+                primitive_hash!(i32, i32)
+            }
+            DataType::UInt8 => primitive_hash!(u8, i32),
+            DataType::UInt16 => primitive_hash!(u16, i32),
+            DataType::UInt32 => primitive_hash!(u32, i32),
+            DataType::Int64
+            | DataType::Date64
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_) => primitive_hash!(i64, i64),
+            DataType::UInt64 => primitive_hash!(u64, i64),
+            DataType::Float32 => primitive_hash!(f32, f32),
+            DataType::Float64 => primitive_hash!(f64, f64),
+            DataType::Utf8 => binary_hash!(Utf8Scalar<i32>),
+            DataType::LargeUtf8 => binary_hash!(Utf8Scalar<i64>),
+            DataType::Binary => binary_hash!(BinaryScalar<i32>),
+            DataType::LargeBinary => binary_hash!(BinaryScalar<i64>),
+            _ => return true,
+        };
+        self.contains_hash(hash)
+    }
+}
+
+/// Returns whether columns of `data_type` can have a bloom filter.
+pub fn can_filter(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_)
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xxhash64() {
+        assert_eq!(hash(b""), 0xEF46_DB37_51D8_E999);
+        assert_eq!(hash(b"a"), 0xD24E_C4F1_A98C_6E5B);
+        assert_eq!(hash(b"abc"), 0x44BC_2CF5_AD77_0999);
+        assert_eq!(
+            hash(b"Nobody inspects the spammish repetition"),
+            0xFBCE_A83C_8A37_8BF1
+        );
+    }
+
+    #[test]
+    fn insert_contains() {
+        let mut filter = BloomFilter::from_ndv_fpp(100, 0.01);
+        (0..100u64).for_each(|x| filter.insert_hash(hash(&x.to_le_bytes())));
+        assert!((0..100u64).all(|x| filter.contains_hash(hash(&x.to_le_bytes()))));
+        let false_positives = (100..10100u64)
+            .filter(|x| filter.contains_hash(hash(&x.to_le_bytes())))
+            .count();
+        assert!(false_positives < 300);
+
+        let filter = BloomFilter::from_bytes(&filter.to_bytes());
+        assert!((0..100u64).all(|x| filter.contains_hash(hash(&x.to_le_bytes()))));
+    }
+
+    #[test]
+    fn array_scalar() {
+        let mut filter = BloomFilter::from_ndv_fpp(10, 0.01);
+        filter
+            .insert_array(&UInt8Array::from(&[Some(1), None, Some(200)]))
+            .unwrap();
+        assert!(filter.contains_scalar(&PrimitiveScalar::<u8>::from(Some(200))));
+        assert!(!filter.contains_scalar(&PrimitiveScalar::<u8>::from(Some(2))));
+        assert!(filter.contains_scalar(&PrimitiveScalar::<u8>::from(None)));
+
+        let mut filter = BloomFilter::from_ndv_fpp(10, 0.01);
+        filter
+            .insert_array(&Utf8Array::<i64>::from_slice(&["a", "bb"]))
+            .unwrap();
+        assert!(filter.contains_scalar(&Utf8Scalar::<i64>::from(Some("bb"))));
+        assert!(!filter.contains_scalar(&Utf8Scalar::<i64>::from(Some("b"))));
+    }
+
+    #[test]
+    fn array_not_supported() {
+        let mut filter = BloomFilter::from_ndv_fpp(10, 0.01);
+        assert!(filter
+            .insert_array(&BooleanArray::from_slice(&[true, false]))
+            .is_err());
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(BloomFilter::from_ndv_fpp(0, 0.05).to_bytes().len(), 32);
+        assert_eq!(
+            BloomFilter::from_ndv_fpp(1024 * 1024, 0.05)
+                .to_bytes()
+                .len(),
+            1024 * 1024
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! APIs to read from and write to Parquet format.
 use crate::error::ArrowError;
 
+pub mod bloom_filter;
 pub mod read;
 pub mod write;
 
@@ -1,6 +1,6 @@
 use futures::{pin_mut, Stream, StreamExt};
 use parquet2::{
-    encoding::{delta_length_byte_array, hybrid_rle, Encoding},
+    encoding::{hybrid_rle, Encoding},
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
     page::{BinaryPageDict, DataPage},
     read::StreamingIterator,
@@ -14,6 +14,7 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use super::super::encoding::decode_binary;
 use super::super::utils;
 
 /// Assumptions: No rep levels
@@ -80,57 +81,33 @@ fn read_dict_buffer<O: Offset>(
     }
 }
 
-fn read_delta_optional<O: Offset>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
+fn read_dict_required<O: Offset>(
+    indices_buffer: &[u8],
     additional: usize,
+    dict: &BinaryPageDict,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
-    validity: &mut MutableBitmap,
 ) {
-    let length = (offsets.len() - 1) + additional;
+    let dict_values = dict.values();
+    let dict_offsets = dict.offsets();
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = delta_length_byte_array::Decoder::new(values_buffer);
+    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
+    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
+    let bit_width = indices_buffer[0];
+    let indices_buffer = &indices_buffer[1..];
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
 
-    // offsets:
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                // the pack may contain more items than needed.
-                let remaining = length - (offsets.len() - 1);
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    if is_valid {
-                        let value = values_iterator.next().unwrap() as isize;
-                        last_offset += O::from_isize(value).unwrap();
-                    }
-                    offsets.push(last_offset);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap() as isize;
-                        last_offset += O::from_isize(value).unwrap();
-                        offsets.push(last_offset);
-                    })
-                } else {
-                    offsets.extend_constant(additional, last_offset)
-                }
-            }
-        }
+    for index in indices {
+        let index = index as usize;
+        let dict_offset_i = dict_offsets[index] as usize;
+        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
+        let length = dict_offset_ip1 - dict_offset_i;
+        last_offset += O::from_usize(length).unwrap();
+        offsets.push(last_offset);
+        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
     }
-
-    // values:
-    let new_values = values_iterator.into_values();
-    values.extend_from_slice(new_values);
 }
 
 fn read_plain_optional<O: Offset>(
@@ -213,12 +190,13 @@ fn extend_from_page<O: Offset>(
     let is_optional = descriptor.max_def_level() == 1;
 
     let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let values_buffer = decode_binary(page.encoding(), values_buffer, page.num_values())?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
             read_dict_buffer::<O>(
                 validity_buffer,
-                values_buffer,
+                &values_buffer,
                 additional,
                 dict.as_any().downcast_ref().unwrap(),
                 offsets,
@@ -226,24 +204,27 @@ fn extend_from_page<O: Offset>(
                 validity,
             )
         }
-        (Encoding::DeltaLengthByteArray, None, true) => read_delta_optional::<O>(
-            validity_buffer,
-            values_buffer,
-            additional,
-            offsets,
-            values,
-            validity,
-        ),
-        (Encoding::Plain, _, true) => read_plain_optional::<O>(
-            validity_buffer,
-            values_buffer,
-            additional,
-            offsets,
-            values,
-            validity,
-        ),
-        (Encoding::Plain, _, false) => {
-            read_plain_required::<O>(page.buffer(), page.num_values(), offsets, values)
+        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
+            read_dict_required::<O>(
+                &values_buffer,
+                additional,
+                dict.as_any().downcast_ref().unwrap(),
+                offsets,
+                values,
+            )
+        }
+        (Encoding::Plain | Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray, _, true) => {
+            read_plain_optional::<O>(
+                validity_buffer,
+                &values_buffer,
+                additional,
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::Plain | Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray, _, false) => {
+            read_plain_required::<O>(&values_buffer, page.num_values(), offsets, values)
         }
         _ => {
             return Err(utils::not_implemented(
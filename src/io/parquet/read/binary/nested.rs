@@ -7,6 +7,7 @@ use parquet2::{
     read::{levels::get_bit_width, StreamingIterator},
 };
 
+use super::super::encoding::decode_binary;
 use super::super::nested_utils::*;
 use super::super::utils;
 use super::basic::read_plain_required;
@@ -21,6 +22,7 @@ use crate::{
 fn read_values<'a, O, D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
@@ -36,7 +38,7 @@ fn read_values<'a, O, D, G>(
             values.extend_from_slice(v);
             offsets.push(*offsets.last().unwrap() + O::from_usize(v.len()).unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             offsets.push(*offsets.last().unwrap());
             validity.push(false);
         }
@@ -51,20 +53,19 @@ fn read<O: Offset>(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
 ) {
-    let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
+    let min_def_level = leaf_def_level(nested);
+    let length = offsets.len() - 1;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
+            let rep_levels = LevelsIter::new(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
                 let def_levels = HybridRleDecoder::new(
                     def_levels,
                     get_bit_width(def_level_encoding.1),
@@ -74,6 +75,7 @@ fn read<O: Offset>(
                 read_values(
                     def_levels,
                     max_def_level,
+                    min_def_level,
                     new_values,
                     offsets,
                     values,
@@ -83,17 +85,9 @@ fn read<O: Offset>(
                 read_plain_required(values_buffer, additional, offsets, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = LevelsIter::new(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested, length)
         }
         _ => todo!(),
     }
@@ -111,27 +105,29 @@ fn extend_from_page<O: Offset>(
     let additional = page.num_values();
 
     let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let values_buffer = decode_binary(page.encoding(), values_buffer, page.num_values())?;
 
     match (&page.encoding(), page.dictionary_page()) {
-        (Encoding::Plain, None) => read(
-            rep_levels,
-            def_levels,
-            values_buffer,
-            additional,
-            (
-                &page.repetition_level_encoding(),
-                descriptor.max_rep_level(),
-            ),
-            (
-                &page.definition_level_encoding(),
-                descriptor.max_def_level(),
-            ),
-            is_nullable,
-            nested,
-            offsets,
-            values,
-            validity,
-        ),
+        (Encoding::Plain | Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray, None) => {
+            read(
+                rep_levels,
+                def_levels,
+                &values_buffer,
+                additional,
+                (
+                    &page.repetition_level_encoding(),
+                    descriptor.max_rep_level(),
+                ),
+                (
+                    &page.definition_level_encoding(),
+                    descriptor.max_def_level(),
+                ),
+                nested,
+                offsets,
+                values,
+                validity,
+            )
+        }
         _ => {
             return Err(utils::not_implemented(
                 &page.encoding(),
@@ -149,7 +145,8 @@ pub fn iter_to_array<O, I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
     data_type: DataType,
-) -> Result<Box<dyn Array>>
+    nested: &mut Vec<Box<dyn Nested>>,
+) -> Result<Arc<dyn Array>>
 where
     O: Offset,
     ArrowError: From<E>,
@@ -162,46 +159,34 @@ where
     offsets.push(O::default());
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
+    let is_nullable = leaf_def_level(nested) < metadata.descriptor().max_def_level() as u32;
 
     while let Some(page) = iter.next() {
         extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
             is_nullable,
-            &mut nested,
+            nested,
             &mut offsets,
             &mut values,
             &mut validity,
         )?
     }
+    close(nested, offsets.len() - 1);
 
-    let inner_data_type = match data_type {
-        DataType::List(ref inner) => inner.data_type(),
-        DataType::LargeList(ref inner) => inner.data_type(),
-        _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
-        }
-    };
-
-    let values = match inner_data_type {
+    Ok(match data_type {
         DataType::LargeBinary | DataType::Binary => Arc::new(BinaryArray::from_data(
-            inner_data_type.clone(),
+            data_type,
             offsets.into(),
             values.into(),
             validity.into(),
         )) as Arc<dyn Array>,
         DataType::LargeUtf8 | DataType::Utf8 => Arc::new(Utf8Array::from_data(
-            inner_data_type.clone(),
+            data_type,
             offsets.into(),
             values.into(),
             validity.into(),
         )) as Arc<dyn Array>,
         _ => unreachable!(),
-    };
-
-    create_list(data_type, &mut nested, values)
+    })
 }
@@ -0,0 +1,61 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_async_temp::{thrift::protocol::TCompactInputProtocol, BloomFilterHeader};
+
+use super::{ParquetError, RowGroupMetaData};
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::bloom_filter::BloomFilter;
+
+/// The maximum size in bytes of the bitset of a bloom filter that is read, so that a corrupt
+/// header does not allocate an arbitrarily large buffer.
+const MAX_BLOOM_FILTER_SIZE: u64 = 128 * 1024 * 1024;
+
+/// Reads the bloom filter of the column `column` of `row_group`.
+/// Returns `None` when the column chunk has no bloom filter.
+pub fn read_bloom_filter<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    column: usize,
+) -> Result<Option<BloomFilter>> {
+    let chunk = row_group.column(column).clone().into_thrift();
+    let offset = match chunk
+        .meta_data
+        .and_then(|metadata| metadata.bloom_filter_offset)
+    {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    if offset < 0 || offset as u64 >= file_length {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The bloom filter of a parquet column starts at {}, outside of the file",
+            offset
+        )));
+    }
+    reader.seek(SeekFrom::Start(offset as u64))?;
+
+    let mut protocol = TCompactInputProtocol::new(&mut *reader);
+    let header =
+        BloomFilterHeader::read_from_in_protocol(&mut protocol).map_err(ParquetError::from)?;
+    // a split block bloom filter has at least one block of 32 bytes
+    if header.num_bytes < 32 {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The bloom filter of a parquet column must have at least 32 bytes, but it declares {}",
+            header.num_bytes
+        )));
+    }
+
+    let start = reader.stream_position()?;
+    if header.num_bytes as u64 > MAX_BLOOM_FILTER_SIZE
+        || start + header.num_bytes as u64 > file_length
+    {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The bloom filter of a parquet column declares {} bytes, more than the file holds or the maximum of {}",
+            header.num_bytes, MAX_BLOOM_FILTER_SIZE
+        )));
+    }
+
+    let mut bitset = vec![0; header.num_bytes as usize];
+    reader.read_exact(&mut bitset)?;
+    Ok(Some(BloomFilter::from_bytes(&bitset)))
+}
@@ -5,6 +5,7 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use super::super::encoding::decode_boolean;
 use super::super::utils;
 
 use futures::{pin_mut, Stream, StreamExt};
@@ -132,16 +133,19 @@ fn extend_from_page(
     let is_optional = descriptor.max_def_level() == 1;
 
     let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let values_buffer = decode_boolean(page.encoding(), values_buffer)?;
 
     match (page.encoding(), page.dictionary_page(), is_optional) {
-        (Encoding::Plain, None, true) => read_optional(
+        (Encoding::Plain | Encoding::Rle, None, true) => read_optional(
             validity_buffer,
-            values_buffer,
+            &values_buffer,
             page.num_values(),
             values,
             validity,
         ),
-        (Encoding::Plain, None, false) => read_required(page.buffer(), page.num_values(), values),
+        (Encoding::Plain | Encoding::Rle, None, false) => {
+            read_required(&values_buffer, page.num_values(), values)
+        }
         _ => {
             return Err(utils::not_implemented(
                 &page.encoding(),
@@ -7,6 +7,7 @@ use parquet2::{
     read::{levels::get_bit_width, StreamingIterator},
 };
 
+use super::super::encoding::decode_boolean;
 use super::super::nested_utils::*;
 use super::super::utils;
 use super::basic::read_required;
@@ -20,6 +21,7 @@ use crate::{
 fn read_values<D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
@@ -31,7 +33,7 @@ fn read_values<D, G>(
         if def == max_def {
             values.push(new_values.next().unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             values.push(false);
             validity.push(false);
         }
@@ -46,41 +48,39 @@ fn read(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
 ) {
-    let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
+    let min_def_level = leaf_def_level(nested);
+    let length = values.len();
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
+            let rep_levels = LevelsIter::new(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
                 let def_levels = HybridRleDecoder::new(
                     def_levels,
                     get_bit_width(def_level_encoding.1),
                     additional,
                 );
                 let new_values = BitmapIter::new(values_buffer, 0, additional);
-                read_values(def_levels, max_def_level, new_values, values, validity)
+                read_values(
+                    def_levels,
+                    max_def_level,
+                    min_def_level,
+                    new_values,
+                    values,
+                    validity,
+                )
             } else {
                 read_required(values_buffer, additional, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = LevelsIter::new(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested, length)
         }
         _ => todo!(),
     }
@@ -97,12 +97,13 @@ fn extend_from_page(
     let additional = page.num_values();
 
     let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let values_buffer = decode_boolean(page.encoding(), values_buffer)?;
 
     match (&page.encoding(), page.dictionary_page()) {
-        (Encoding::Plain, None) => read(
+        (Encoding::Plain | Encoding::Rle, None) => read(
             rep_levels,
             def_levels,
-            values_buffer,
+            &values_buffer,
             additional,
             (
                 &page.repetition_level_encoding(),
@@ -112,7 +113,6 @@ fn extend_from_page(
                 &page.definition_level_encoding(),
                 descriptor.max_def_level(),
             ),
-            is_nullable,
             nested,
             values,
             validity,
@@ -134,7 +134,8 @@ pub fn iter_to_array<I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
     data_type: DataType,
-) -> Result<Box<dyn Array>>
+    nested: &mut Vec<Box<dyn Nested>>,
+) -> Result<Arc<dyn Array>>
 where
     ArrowError: From<E>,
     E: Clone,
@@ -144,35 +145,23 @@ where
     let mut values = MutableBitmap::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
+    let is_nullable = leaf_def_level(nested) < metadata.descriptor().max_def_level() as u32;
 
     while let Some(page) = iter.next() {
         extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
             is_nullable,
-            &mut nested,
+            nested,
             &mut values,
             &mut validity,
         )?
     }
+    close(nested, values.len());
 
-    let inner_data_type = match data_type {
-        DataType::List(ref inner) => inner.data_type(),
-        DataType::LargeList(ref inner) => inner.data_type(),
-        _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
-        }
-    };
-
-    let values = Arc::new(BooleanArray::from_data(
-        inner_data_type.clone(),
+    Ok(Arc::new(BooleanArray::from_data(
+        data_type,
         values.into(),
         validity.into(),
-    ));
-
-    create_list(data_type, &mut nested, values)
+    )))
 }
@@ -0,0 +1,408 @@
+//! Decoders of parquet encodings that are read by first converting the values of a page
+//! to their PLAIN representation, so that the (optional, required and nested) readers
+//! only need to handle PLAIN-encoded values.
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use parquet2::encoding::{get_length, hybrid_rle, uleb128, zigzag_leb128, Encoding};
+use parquet2::schema::types::PhysicalType;
+
+use crate::bitmap::MutableBitmap;
+use crate::error::{ArrowError, Result};
+
+fn out_of_spec(encoding: &str) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "The values of a page encoded with {} are out of spec",
+        encoding
+    ))
+}
+
+fn read_uleb128(values: &[u8], offset: &mut usize) -> Result<u64> {
+    let (value, consumed) = uleb128::decode(values.get(*offset..).unwrap_or_default());
+    if consumed == 0 {
+        return Err(out_of_spec("DELTA_BINARY_PACKED"));
+    }
+    *offset += consumed;
+    Ok(value)
+}
+
+fn read_zigzag_leb128(values: &[u8], offset: &mut usize) -> Result<i64> {
+    let (value, consumed) = zigzag_leb128::decode(values.get(*offset..).unwrap_or_default());
+    if consumed == 0 {
+        return Err(out_of_spec("DELTA_BINARY_PACKED"));
+    }
+    *offset += consumed;
+    Ok(value)
+}
+
+/// Returns the `index`th value of `packed`, LSB bit-packed with `bit_width` bits (at most 64).
+/// Values past the end of `packed` are zero.
+#[inline]
+fn unpack(packed: &[u8], bit_width: usize, index: usize) -> u64 {
+    let start = index * bit_width;
+    let first = start / 8;
+    if bit_width == 0 || first >= packed.len() {
+        return 0;
+    }
+    // a value spans at most 9 bytes
+    let last = std::cmp::min(first + 16, packed.len());
+    let mut bytes = [0u8; 16];
+    bytes[..last - first].copy_from_slice(&packed[first..last]);
+    let value = (u128::from_le_bytes(bytes) >> (start % 8)) as u64;
+    if bit_width == 64 {
+        value
+    } else {
+        value & ((1u64 << bit_width) - 1)
+    }
+}
+
+/// Decodes a [DELTA_BINARY_PACKED](https://github.com/apache/parquet-format/blob/master/Encodings.md#delta-encoding-delta_binary_packed--5)-encoded
+/// buffer of at most `num_values` values, returning its values and the number of bytes
+/// they occupy in `values`.
+/// Arithmetic wraps, so that both 32 and 64 bit columns are decoded by truncation.
+pub fn decode_delta(values: &[u8], num_values: usize) -> Result<(Vec<i64>, usize)> {
+    let mut offset = 0;
+    let block_size = read_uleb128(values, &mut offset)? as usize;
+    let num_miniblocks = read_uleb128(values, &mut offset)? as usize;
+    let total = read_uleb128(values, &mut offset)? as usize;
+    let first = read_zigzag_leb128(values, &mut offset)?;
+
+    // the header's total is not trusted beyond the number of values of the page
+    if total > num_values || num_miniblocks == 0 || block_size % num_miniblocks != 0 {
+        return Err(out_of_spec("DELTA_BINARY_PACKED"));
+    }
+    let values_per_miniblock = block_size / num_miniblocks;
+
+    let mut result = Vec::with_capacity(total);
+    if total == 0 {
+        return Ok((result, offset));
+    }
+    result.push(first);
+    let mut last = first;
+
+    while result.len() < total {
+        let min_delta = read_zigzag_leb128(values, &mut offset)?;
+        let bit_widths = offset
+            .checked_add(num_miniblocks)
+            .and_then(|end| values.get(offset..end))
+            .ok_or_else(|| out_of_spec("DELTA_BINARY_PACKED"))?;
+        offset += num_miniblocks;
+
+        for &bit_width in bit_widths {
+            // the bodies of miniblocks that are not needed are not written
+            if result.len() == total {
+                break;
+            }
+            let bit_width = bit_width as usize;
+            if bit_width > 64 {
+                return Err(out_of_spec("DELTA_BINARY_PACKED"));
+            }
+            let end = values_per_miniblock
+                .checked_mul(bit_width)
+                .and_then(|bits| bits.checked_add(7))
+                .and_then(|bits| offset.checked_add(bits / 8))
+                .ok_or_else(|| out_of_spec("DELTA_BINARY_PACKED"))?;
+            let end = std::cmp::min(end, values.len());
+            let packed = &values[offset..end];
+            offset = end;
+
+            let length = std::cmp::min(values_per_miniblock, total - result.len());
+            (0..length).for_each(|index| {
+                let delta = unpack(packed, bit_width, index) as i64;
+                last = last.wrapping_add(min_delta).wrapping_add(delta);
+                result.push(last);
+            });
+        }
+    }
+    Ok((result, offset))
+}
+
+/// Decodes a `BYTE_STREAM_SPLIT`-encoded buffer of values with `size` bytes each,
+/// where the k-th byte of every value is stored in the k-th stream.
+fn decode_byte_stream_split(values: &[u8], size: usize) -> Result<Vec<u8>> {
+    if size == 0 || values.len() % size != 0 {
+        return Err(out_of_spec("BYTE_STREAM_SPLIT"));
+    }
+    let length = values.len() / size;
+    let mut plain = vec![0; values.len()];
+    plain
+        .chunks_exact_mut(size)
+        .enumerate()
+        .for_each(|(index, value)| {
+            value
+                .iter_mut()
+                .enumerate()
+                .for_each(|(stream, byte)| *byte = values[stream * length + index])
+        });
+    Ok(plain)
+}
+
+fn push_plain_binary(plain: &mut Vec<u8>, value: &[u8]) {
+    plain.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    plain.extend_from_slice(value);
+}
+
+fn take_bytes<'a>(data: &mut &'a [u8], length: i64, encoding: &str) -> Result<&'a [u8]> {
+    let length = usize::try_from(length).map_err(|_| out_of_spec(encoding))?;
+    if length > data.len() {
+        return Err(out_of_spec(encoding));
+    }
+    let (value, remaining) = data.split_at(length);
+    *data = remaining;
+    Ok(value)
+}
+
+fn decode_delta_length_byte_array(values: &[u8], num_values: usize) -> Result<Vec<u8>> {
+    let (lengths, consumed) = decode_delta(values, num_values)?;
+    let mut data = &values[consumed..];
+
+    let mut plain = Vec::with_capacity(lengths.len() * 4 + data.len());
+    for length in lengths {
+        let value = take_bytes(&mut data, length, "DELTA_LENGTH_BYTE_ARRAY")?;
+        push_plain_binary(&mut plain, value);
+    }
+    Ok(plain)
+}
+
+fn decode_delta_byte_array(values: &[u8], num_values: usize) -> Result<Vec<u8>> {
+    let (prefixes, consumed) = decode_delta(values, num_values)?;
+    let (suffixes, suffixes_consumed) = decode_delta(&values[consumed..], num_values)?;
+    if prefixes.len() != suffixes.len() {
+        return Err(out_of_spec("DELTA_BYTE_ARRAY"));
+    }
+    let mut data = &values[consumed + suffixes_consumed..];
+
+    let mut plain = Vec::with_capacity(prefixes.len() * 4 + data.len());
+    let mut previous = vec![];
+    for (prefix, suffix) in prefixes.into_iter().zip(suffixes) {
+        let prefix = usize::try_from(prefix).map_err(|_| out_of_spec("DELTA_BYTE_ARRAY"))?;
+        if prefix > previous.len() {
+            return Err(out_of_spec("DELTA_BYTE_ARRAY"));
+        }
+        previous.truncate(prefix);
+        previous.extend_from_slice(take_bytes(&mut data, suffix, "DELTA_BYTE_ARRAY")?);
+        push_plain_binary(&mut plain, &previous);
+    }
+    Ok(plain)
+}
+
+fn decode_rle_boolean(values: &[u8]) -> Result<Vec<u8>> {
+    // SPEC: the RLE-encoded booleans are prefixed by their length in bytes (4 bytes, LE)
+    if values.len() < 4 {
+        return Err(out_of_spec("RLE"));
+    }
+    let length = get_length(values) as usize;
+    let runs = values
+        .get(4..4 + length)
+        .ok_or_else(|| out_of_spec("RLE"))?;
+
+    let mut bitmap = MutableBitmap::new();
+    for run in hybrid_rle::Decoder::new(runs, 1) {
+        match run {
+            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
+                bitmap.extend_from_slice(packed, 0, packed.len() * 8)
+            }
+            hybrid_rle::HybridEncoded::Rle(value, additional) => {
+                bitmap.extend_constant(additional, value[0] == 1)
+            }
+        }
+    }
+    Ok(bitmap.as_slice().to_vec())
+}
+
+/// Returns the PLAIN representation of the non-null values of a page of a fixed-width
+/// physical type, a page of `num_values` values (nulls included).
+/// Encodings that are not converted (e.g. dictionary indices) are returned as is.
+pub fn decode_primitive<'a>(
+    encoding: Encoding,
+    physical_type: &PhysicalType,
+    values: &'a [u8],
+    num_values: usize,
+) -> Result<Cow<'a, [u8]>> {
+    Ok(match encoding {
+        Encoding::DeltaBinaryPacked => {
+            let (deltas, _) = decode_delta(values, num_values)?;
+            let plain = match physical_type {
+                PhysicalType::Int32 => deltas
+                    .into_iter()
+                    .flat_map(|x| (x as i32).to_le_bytes())
+                    .collect(),
+                PhysicalType::Int64 => deltas.into_iter().flat_map(|x| x.to_le_bytes()).collect(),
+                other => {
+                    return Err(ArrowError::ExternalFormat(format!(
+                        "DELTA_BINARY_PACKED is not a valid encoding for {:?}",
+                        other
+                    )))
+                }
+            };
+            Cow::Owned(plain)
+        }
+        Encoding::ByteStreamSplit => {
+            let size = match physical_type {
+                PhysicalType::Int32 | PhysicalType::Float => 4,
+                PhysicalType::Int64 | PhysicalType::Double => 8,
+                PhysicalType::FixedLenByteArray(size) => *size as usize,
+                other => {
+                    return Err(ArrowError::ExternalFormat(format!(
+                        "BYTE_STREAM_SPLIT is not a valid encoding for {:?}",
+                        other
+                    )))
+                }
+            };
+            Cow::Owned(decode_byte_stream_split(values, size)?)
+        }
+        _ => Cow::Borrowed(values),
+    })
+}
+
+/// Returns the PLAIN representation of the non-null values of a page of `num_values`
+/// byte arrays (nulls included).
+/// Encodings that are not converted (e.g. dictionary indices) are returned as is.
+pub fn decode_binary(
+    encoding: Encoding,
+    values: &[u8],
+    num_values: usize,
+) -> Result<Cow<'_, [u8]>> {
+    Ok(match encoding {
+        Encoding::DeltaLengthByteArray => {
+            Cow::Owned(decode_delta_length_byte_array(values, num_values)?)
+        }
+        Encoding::DeltaByteArray => Cow::Owned(decode_delta_byte_array(values, num_values)?),
+        _ => Cow::Borrowed(values),
+    })
+}
+
+/// Returns the PLAIN representation of the non-null values of a page of booleans.
+/// Encodings that are not converted are returned as is.
+pub fn decode_boolean(encoding: Encoding, values: &[u8]) -> Result<Cow<'_, [u8]>> {
+    Ok(match encoding {
+        Encoding::Rle => Cow::Owned(decode_rle_boolean(values)?),
+        _ => Cow::Borrowed(values),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_single_miniblock() {
+        // header: block size 128, 1 miniblock, 5 values, first value 1
+        // block: min delta 1, bit width 0
+        let data = &[128u8, 1, 1, 5, 2, 2, 0];
+        let (values, consumed) = decode_delta(data, 5).unwrap();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        assert_eq!(consumed, data.len());
+    }
+
+    #[test]
+    fn delta_roundtrip_parquet2() {
+        let expected = (0..300).map(|x| (x * 7) % 13 - 6).collect::<Vec<i32>>();
+        let mut buffer = vec![];
+        parquet2::encoding::delta_bitpacked::encode(expected.clone().into_iter(), &mut buffer);
+
+        let (values, _) = decode_delta(&buffer, 300).unwrap();
+        let values = values.into_iter().map(|x| x as i32).collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn delta_multiple_miniblocks() {
+        // header: block size 8, 2 miniblocks (4 values each), 7 values, first value 10
+        let mut data = vec![8u8, 2, 7, 20];
+        // block: min delta -1 <=z> 1; bit widths [2, 1]
+        data.extend_from_slice(&[1, 2, 1]);
+        // miniblock 1 (2 bits): deltas - min_delta [0, 1, 2, 3] => 0b11100100
+        data.push(0b11100100);
+        // miniblock 2 (1 bit): [1, 0] (padded to 4 values) => 0b0001
+        data.push(0b0001);
+        // trailing data that must not be consumed
+        data.push(255);
+
+        let (values, consumed) = decode_delta(&data, 7).unwrap();
+        // deltas: [-1, 0, 1, 2, 0, -1]
+        assert_eq!(values, vec![10, 9, 9, 10, 12, 12, 11]);
+        assert_eq!(consumed, data.len() - 1);
+    }
+
+    #[test]
+    fn delta_i64() {
+        let data = &[
+            128u8, 1, 1, 2, 254, 255, 255, 255, 255, 255, 255, 255, 255, 1, 2, 0,
+        ];
+        let (values, _) = decode_delta(data, 2).unwrap();
+        assert_eq!(values, vec![i64::MAX, i64::MIN]);
+    }
+
+    #[test]
+    fn delta_total_larger_than_page() {
+        // header: block size 128, 1 miniblock, u64::MAX values, first value 1
+        let mut data = vec![128u8, 1];
+        data.extend_from_slice(&[255, 255, 255, 255, 255, 255, 255, 255, 255, 1]);
+        data.extend_from_slice(&[2, 2, 0]);
+        assert!(decode_delta(&data, 5).is_err());
+    }
+
+    #[test]
+    fn delta_miniblock_overflow() {
+        // header: block size u64::MAX - 63, 1 miniblock, 3 values, first value 1
+        let mut data = vec![0b11000000, 255, 255, 255, 255, 255, 255, 255, 255, 1];
+        data.extend_from_slice(&[1, 3, 2]);
+        // block: min delta 1, bit width 64
+        data.extend_from_slice(&[2, 64]);
+        assert!(decode_delta(&data, 3).is_err());
+    }
+
+    #[test]
+    fn delta_length_byte_array() {
+        let expected = ["Hello", "World", ""];
+        let mut buffer = vec![];
+        parquet2::encoding::delta_length_byte_array::encode(expected.iter(), &mut buffer);
+
+        let plain = decode_binary(Encoding::DeltaLengthByteArray, &buffer, 3).unwrap();
+        let values = super::super::utils::BinaryIter::new(&plain).collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            expected.iter().map(|x| x.as_bytes()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn delta_byte_array() {
+        // prefix lengths [0, 3, 4]
+        let mut data = vec![128u8, 1, 1, 3, 0, 2, 2, 0b10];
+        data.extend(std::iter::repeat(0).take(128 * 2 / 8 - 1));
+        // suffix lengths [5, 2, 0]
+        data.extend_from_slice(&[128u8, 1, 1, 3, 10, 5, 2, 0b0100]);
+        data.extend(std::iter::repeat(0).take(128 * 2 / 8 - 1));
+        data.extend_from_slice(b"helloly");
+
+        let plain = decode_binary(Encoding::DeltaByteArray, &data, 3).unwrap();
+        let values = super::super::utils::BinaryIter::new(&plain).collect::<Vec<_>>();
+        assert_eq!(values, vec![&b"hello"[..], b"helly", b"hell"]);
+    }
+
+    #[test]
+    fn byte_stream_split() {
+        let expected = [1.5f32, -2.0, 3.25];
+        let bytes = expected
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>();
+        let mut data = vec![];
+        (0..4).for_each(|stream| (0..3).for_each(|i| data.push(bytes[i * 4 + stream])));
+
+        let plain =
+            decode_primitive(Encoding::ByteStreamSplit, &PhysicalType::Float, &data, 3).unwrap();
+        assert_eq!(plain.as_ref(), bytes.as_slice());
+    }
+
+    #[test]
+    fn rle_boolean() {
+        // length 4, run of 3 `true`, bitpacked [false, true, false, ...]
+        let data = &[4u8, 0, 0, 0, 0b110, 1, 0b11, 0b010];
+        let plain = decode_boolean(Encoding::Rle, data).unwrap();
+        let values = crate::bitmap::utils::BitmapIter::new(&plain, 0, 6).collect::<Vec<_>>();
+        assert_eq!(values, vec![true, true, true, false, true, false]);
+    }
+}
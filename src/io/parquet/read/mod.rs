@@ -28,17 +28,35 @@ use crate::{
 };
 
 mod binary;
+mod bloom_filter;
 mod boolean;
+mod encoding;
 mod fixed_size_binary;
+#[cfg(feature = "compute")]
+mod multi_file;
 mod nested_utils;
+mod page_index;
 mod primitive;
 mod record_batch;
+mod row_group;
 pub mod schema;
 pub mod statistics;
+mod stream;
 mod utils;
 
+use nested_utils::{create_nested, init_nested, NestedLeaf};
+
+pub use bloom_filter::read_bloom_filter;
+#[cfg(feature = "compute")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute")))]
+pub use multi_file::MultiRecordReader;
+pub use page_index::{
+    read_page_index, select_pages_by_rows, select_pages_by_statistics, selected_rows, PageIndex,
+};
 pub use record_batch::RecordReader;
+pub use row_group::{read_compressed_row_group, CompressedRowGroup, Task};
 pub use schema::{get_schema, is_type_nullable, FileMetaData};
+pub use stream::AsyncRecordReader;
 
 /// Creates a new iterator of compressed pages.
 pub fn get_page_iterator<'b, RR: Read + Seek>(
@@ -78,6 +96,27 @@ pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     Ok(_read_metadata_async(reader).await?)
 }
 
+fn is_int96(metadata: &ColumnChunkMetaData) -> bool {
+    matches!(
+        metadata.descriptor().type_(),
+        ParquetType::PrimitiveType {
+            physical_type: PhysicalType::Int96,
+            ..
+        }
+    )
+}
+
+/// Converts an INT96 timestamp to a timestamp in `time_unit`.
+fn int96_to_i64(value: [u32; 3], time_unit: TimeUnit) -> i64 {
+    let nanos = int96_to_i64_ns(value);
+    match time_unit {
+        TimeUnit::Second => nanos / 1_000_000_000,
+        TimeUnit::Millisecond => nanos / 1_000_000,
+        TimeUnit::Microsecond => nanos / 1_000,
+        TimeUnit::Nanosecond => nanos,
+    }
+}
+
 fn dict_read<
     K: DictionaryKey,
     I: StreamingIterator<Item = std::result::Result<DataPage, ParquetError>>,
@@ -180,18 +219,12 @@ pub fn page_iter_to_array<
             primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as i32)
         }
 
-        Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
-            ParquetType::PrimitiveType { physical_type, .. } => match physical_type {
-                PhysicalType::Int96 => primitive::iter_to_array(
-                    iter,
-                    metadata,
-                    DataType::Timestamp(TimeUnit::Nanosecond, None),
-                    int96_to_i64_ns,
-                ),
-                _ => primitive::iter_to_array(iter, metadata, data_type, |x: i64| x),
-            },
-            _ => unreachable!(),
-        },
+        Timestamp(time_unit, _) if is_int96(metadata) => {
+            let time_unit = *time_unit;
+            primitive::iter_to_array(iter, metadata, data_type, move |x: [u32; 3]| {
+                int96_to_i64(x, time_unit)
+            })
+        }
 
         // INT64
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
@@ -210,47 +243,10 @@ pub fn page_iter_to_array<
             iter, data_type, metadata,
         )?)),
 
-        List(ref inner) => match inner.data_type() {
-            UInt8 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u8),
-            UInt16 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u16),
-            UInt32 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u32),
-            Int8 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as i8),
-            Int16 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as i16),
-            Int32 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as i32),
-
-            Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
-                ParquetType::PrimitiveType { physical_type, .. } => match physical_type {
-                    PhysicalType::Int96 => primitive::iter_to_array_nested(
-                        iter,
-                        metadata,
-                        DataType::Timestamp(TimeUnit::Nanosecond, None),
-                        int96_to_i64_ns,
-                    ),
-                    _ => primitive::iter_to_array(iter, metadata, data_type, |x: i64| x),
-                },
-                _ => unreachable!(),
-            },
-
-            // INT64
-            Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
-                primitive::iter_to_array_nested(iter, metadata, data_type, |x: i64| x)
-            }
-            UInt64 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i64| x as u64),
-
-            Float32 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: f32| x),
-            Float64 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: f64| x),
-
-            Boolean => boolean::iter_to_array_nested(iter, metadata, data_type),
-
-            Binary | Utf8 => binary::iter_to_array_nested::<i32, _, _>(iter, metadata, data_type),
-            LargeBinary | LargeUtf8 => {
-                binary::iter_to_array_nested::<i64, _, _>(iter, metadata, data_type)
-            }
-            other => Err(ArrowError::NotYetImplemented(format!(
-                "Reading {:?} from parquet still not implemented",
-                other
-            ))),
-        },
+        List(_) | LargeList(_) | Struct(_) => {
+            let leaf = page_iter_to_leaf(iter, metadata, &data_type)?;
+            create_nested(data_type, &mut [leaf])
+        }
 
         Dictionary(ref key, _) => match key.as_ref() {
             Int8 => dict_read::<i8, _>(iter, metadata, data_type),
@@ -271,6 +267,76 @@ pub fn page_iter_to_array<
     }
 }
 
+/// Reads the leaf column described by `metadata` of a nested field of type `data_type`,
+/// returning its nested levels and its values.
+fn page_iter_to_leaf<I: StreamingIterator<Item = std::result::Result<DataPage, ParquetError>>>(
+    iter: &mut I,
+    metadata: &ColumnChunkMetaData,
+    data_type: &DataType,
+) -> Result<NestedLeaf> {
+    use DataType::*;
+    let capacity = metadata.num_values() as usize;
+    let (mut nested, data_type) = init_nested(metadata.descriptor(), data_type, capacity)?;
+    let nested_ = &mut nested;
+
+    let values = match data_type.to_logical_type() {
+        UInt8 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x as u8)
+        }
+        UInt16 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x as u16)
+        }
+        UInt32 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x as u32)
+        }
+        Int8 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x as i8)
+        }
+        Int16 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x as i16)
+        }
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i32| x)
+        }
+
+        Timestamp(time_unit, _) if is_int96(metadata) => {
+            let time_unit = *time_unit;
+            primitive::iter_to_array_nested(
+                iter,
+                metadata,
+                data_type,
+                nested_,
+                move |x: [u32; 3]| int96_to_i64(x, time_unit),
+            )
+        }
+
+        // INT64
+        Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i64| x)
+        }
+        UInt64 => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: i64| x as u64)
+        }
+
+        Float32 => primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: f32| x),
+        Float64 => primitive::iter_to_array_nested(iter, metadata, data_type, nested_, |x: f64| x),
+
+        Boolean => boolean::iter_to_array_nested(iter, metadata, data_type, nested_),
+
+        Binary | Utf8 => {
+            binary::iter_to_array_nested::<i32, _, _>(iter, metadata, data_type, nested_)
+        }
+        LargeBinary | LargeUtf8 => {
+            binary::iter_to_array_nested::<i64, _, _>(iter, metadata, data_type, nested_)
+        }
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Reading {:?} from parquet still not implemented",
+            other
+        ))),
+    }?;
+    Ok((nested, values))
+}
+
 // Converts an async stream of compressed data pages into an [`Array`].
 pub async fn page_stream_to_array<I: Stream<Item = std::result::Result<DataPage, ParquetError>>>(
     pages: I,
@@ -289,21 +355,13 @@ pub async fn page_stream_to_array<I: Stream<Item = std::result::Result<DataPage,
             primitive::stream_to_array(pages, metadata, data_type, |x: i32| x as i32).await
         }
 
-        Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
-            ParquetType::PrimitiveType { physical_type, .. } => match physical_type {
-                PhysicalType::Int96 => {
-                    primitive::stream_to_array(
-                        pages,
-                        metadata,
-                        DataType::Timestamp(TimeUnit::Nanosecond, None),
-                        int96_to_i64_ns,
-                    )
-                    .await
-                }
-                _ => primitive::stream_to_array(pages, metadata, data_type, |x: i64| x).await,
-            },
-            _ => unreachable!(),
-        },
+        Timestamp(time_unit, _) if is_int96(metadata) => {
+            let time_unit = *time_unit;
+            primitive::stream_to_array(pages, metadata, data_type, move |x: [u32; 3]| {
+                int96_to_i64(x, time_unit)
+            })
+            .await
+        }
 
         // INT64
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
@@ -0,0 +1,304 @@
+//! Reading of [`RecordBatch`]es from multiple parquet files whose schemas evolved over time.
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{Read, Seek},
+    sync::Arc,
+};
+
+use crate::{
+    array::new_null_array,
+    compute::cast::cast,
+    datatypes::{DataType, Field, Schema},
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
+};
+
+use super::{get_schema, read_metadata, RecordReader};
+
+/// Returns the type that both `lhs` and `rhs` can be losslessly cast to, if any.
+fn widen(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    use DataType::*;
+    if lhs == rhs {
+        return Some(lhs.clone());
+    }
+    let rank = |data_type: &DataType| match data_type {
+        Int8 | UInt8 => 1,
+        Int16 | UInt16 => 2,
+        Int32 | UInt32 => 3,
+        Int64 | UInt64 => 4,
+        _ => 0,
+    };
+    let is_signed = |data_type: &DataType| matches!(data_type, Int8 | Int16 | Int32 | Int64);
+    let is_unsigned = |data_type: &DataType| matches!(data_type, UInt8 | UInt16 | UInt32 | UInt64);
+
+    match (lhs, rhs) {
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Float32, Float64) | (Float64, Float32) => Some(Float64),
+        (Utf8, LargeUtf8) | (LargeUtf8, Utf8) => Some(LargeUtf8),
+        (Binary, LargeBinary) | (LargeBinary, Binary) => Some(LargeBinary),
+        (List(lhs), List(rhs)) => widen_item(lhs, rhs).map(|item| List(Box::new(item))),
+        (LargeList(lhs), LargeList(rhs))
+        | (List(lhs), LargeList(rhs))
+        | (LargeList(lhs), List(rhs)) => widen_item(lhs, rhs).map(|item| LargeList(Box::new(item))),
+        (a, b) if is_signed(a) && is_signed(b) || is_unsigned(a) && is_unsigned(b) => {
+            Some(if rank(a) > rank(b) { a } else { b }.clone())
+        }
+        // an unsigned integer fits in any strictly wider signed integer
+        (unsigned, signed) | (signed, unsigned)
+            if is_unsigned(unsigned) && is_signed(signed) && rank(signed) > rank(unsigned) =>
+        {
+            Some(signed.clone())
+        }
+        _ => None,
+    }
+}
+
+fn widen_item(lhs: &Field, rhs: &Field) -> Option<Field> {
+    let data_type = widen(lhs.data_type(), rhs.data_type())?;
+    Some(Field::new(
+        lhs.name(),
+        data_type,
+        lhs.is_nullable() || rhs.is_nullable(),
+    ))
+}
+
+/// Returns the schema of all `schemas`: fields with the same name are merged via
+/// [`Schema::try_merge`] after their types are widened to a common type, and fields
+/// absent from some schema are nullable.
+fn evolve(schemas: &[Schema]) -> Result<Schema> {
+    let mut types: HashMap<&str, DataType> = HashMap::new();
+    for field in schemas.iter().flat_map(|schema| schema.fields()) {
+        match types.get_mut(field.name().as_str()) {
+            Some(data_type) => {
+                // types that can't be widened are left as is, so that merging reports them
+                if let Some(widened) = widen(data_type, field.data_type()) {
+                    *data_type = widened;
+                }
+            }
+            None => {
+                types.insert(field.name(), field.data_type().clone());
+            }
+        }
+    }
+
+    let widened = schemas.iter().map(|schema| {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let data_type = &types[field.name().as_str()];
+                match widen(field.data_type(), data_type) {
+                    Some(widened) if &widened == data_type => Field {
+                        data_type: widened,
+                        ..field.clone()
+                    },
+                    _ => field.clone(),
+                }
+            })
+            .collect();
+        Schema::new_from(fields, schema.metadata().clone())
+    });
+    let mut merged = Schema::try_merge(widened)?;
+
+    for field in merged.fields.iter_mut() {
+        let in_all = schemas
+            .iter()
+            .all(|schema| schema.field_with_name(field.name()).is_ok());
+        if !in_all {
+            field.nullable = true;
+        }
+    }
+    Ok(merged)
+}
+
+/// The reader of the file currently being read.
+struct FileReader<R: Read + Seek> {
+    reader: RecordReader<R>,
+    // the index of each field of the evolved schema in the batches of `reader`, if present
+    columns: Vec<Option<usize>>,
+    // the number of rows of each row group not yet read, when no field is present in the file
+    null_rows: VecDeque<usize>,
+}
+
+/// Single threaded iterator of [`RecordBatch`] from multiple parquet files, one after the other.
+///
+/// The schemas of the files are unified into a single schema: fields are matched by name,
+/// their types are widened to a common type (e.g. `Int32` and `Int64` to `Int64`, `Utf8` and
+/// `LargeUtf8` to `LargeUtf8`) and fields missing from a file are read as nulls. All batches
+/// have this schema.
+pub struct MultiRecordReader<R: Read + Seek> {
+    readers: std::vec::IntoIter<R>,
+    schema: Arc<Schema>,
+    current: Option<FileReader<R>>,
+    remaining_rows: usize,
+}
+
+impl<R: Read + Seek> MultiRecordReader<R> {
+    /// Reads the metadata of all `readers` and returns a new [`MultiRecordReader`] of the
+    /// fields in `projection` of the unified schema (all fields if `None`), returning at most
+    /// `limit` rows across all files.
+    /// # Errors
+    /// Errors if the same field has incompatible types in two files, or if the key-value
+    /// metadata of the files conflict.
+    pub fn try_new(
+        mut readers: Vec<R>,
+        projection: Option<Vec<usize>>,
+        limit: Option<usize>,
+    ) -> Result<Self> {
+        let schemas = readers
+            .iter_mut()
+            .map(|reader| {
+                let metadata = read_metadata(reader)?;
+                get_schema(&metadata)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = evolve(&schemas)?;
+        let schema = if let Some(projection) = projection {
+            let fields = projection
+                .iter()
+                .map(|&index| {
+                    schema.fields().get(index).cloned().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The projection contains the field {} but the schema of the files only has {} fields",
+                            index,
+                            schema.fields().len()
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Schema::new_from(fields, schema.metadata)
+        } else {
+            schema
+        };
+
+        Ok(Self {
+            readers: readers.into_iter(),
+            schema: Arc::new(schema),
+            current: None,
+            remaining_rows: limit.unwrap_or(usize::MAX),
+        })
+    }
+
+    /// The unified schema of all files.
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    fn open(&self, mut reader: R) -> Result<FileReader<R>> {
+        let metadata = read_metadata(&mut reader)?;
+        let file_schema = get_schema(&metadata)?;
+        let projection = file_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| self.schema.field_with_name(field.name()).is_ok())
+            .map(|(index, _)| index)
+            .collect();
+        reader.rewind()?;
+
+        let reader = RecordReader::try_new(
+            reader,
+            Some(projection),
+            Some(self.remaining_rows),
+            None,
+            None,
+        )?;
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| reader.schema().index_of(field.name()).ok())
+            .collect::<Vec<_>>();
+        let null_rows = if columns.iter().all(|column| column.is_none()) {
+            reader
+                .metadata()
+                .row_groups
+                .iter()
+                .map(|group| group.num_rows() as usize)
+                .collect()
+        } else {
+            VecDeque::new()
+        };
+        Ok(FileReader {
+            reader,
+            columns,
+            null_rows,
+        })
+    }
+
+    /// Reads the next batch of the current file, as read from the file.
+    fn next_file_batch(&mut self) -> Option<Result<(RecordBatch, usize)>> {
+        let current = self.current.as_mut()?;
+        if let Some(num_rows) = current.null_rows.pop_front() {
+            return Some(Ok((RecordBatch::new_empty(self.schema.clone()), num_rows)));
+        }
+        if current.columns.iter().all(|column| column.is_none()) {
+            return None;
+        }
+        current.reader.next().map(|batch| {
+            let batch = batch?;
+            let num_rows = batch.num_rows();
+            Ok((batch, num_rows))
+        })
+    }
+
+    /// Converts `batch`, read from the current file, to the unified schema.
+    fn evolve_batch(&self, batch: RecordBatch, num_rows: usize) -> Result<RecordBatch> {
+        let num_rows = num_rows.min(self.remaining_rows);
+        let columns = self.current.as_ref().unwrap().columns.iter();
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(columns)
+            .map(|(field, column)| {
+                let data_type = field.data_type();
+                let array = match column {
+                    Some(column) => {
+                        let array = batch.column(*column);
+                        if array.data_type() == data_type {
+                            array.slice(0, num_rows)
+                        } else {
+                            cast(array.as_ref(), data_type)?.slice(0, num_rows)
+                        }
+                    }
+                    None => new_null_array(data_type.clone(), num_rows),
+                };
+                Ok(array.into())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+}
+
+impl<R: Read + Seek> Iterator for MultiRecordReader<R> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.schema.fields().is_empty() {
+            return None;
+        }
+        loop {
+            if self.remaining_rows == 0 {
+                return None;
+            }
+            match self.next_file_batch() {
+                Some(Ok((batch, num_rows))) => {
+                    let batch = self.evolve_batch(batch, num_rows);
+                    if let Ok(batch) = &batch {
+                        self.remaining_rows -= batch.num_rows();
+                    }
+                    return Some(batch);
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => {
+                    let reader = self.readers.next()?;
+                    match self.open(reader) {
+                        Ok(reader) => self.current = Some(reader),
+                        Err(error) => return Some(Err(error)),
+                    }
+                }
+            }
+        }
+    }
+}
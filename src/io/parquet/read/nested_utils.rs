@@ -1,25 +1,36 @@
 use std::sync::Arc;
 
-use parquet2::schema::{types::ParquetType, Repetition};
+use parquet2::{
+    encoding::hybrid_rle::HybridRleDecoder,
+    metadata::ColumnDescriptor,
+    read::levels::get_bit_width,
+    schema::{types::ParquetType, Repetition},
+};
 
 use crate::{
-    array::{Array, ListArray},
+    array::{clone, Array, ListArray, StructArray},
     bitmap::{Bitmap, MutableBitmap},
     buffer::{Buffer, MutableBuffer},
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
+/// The state of a nested level (a list or a struct) while its leaf column is being read.
 pub trait Nested: std::fmt::Debug {
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>);
 
-    fn last_offset(&self) -> i64;
-
     fn push(&mut self, length: i64, is_valid: bool);
 
-    fn offsets(&mut self) -> &[i64];
-
     fn close(&mut self, length: i64);
+
+    /// Whether this level is nullable.
+    fn is_nullable(&self) -> bool;
+
+    /// Whether this level is repeated (a list) or not (a struct).
+    fn is_repeated(&self) -> bool;
+
+    /// The number of slots pushed so far.
+    fn len(&self) -> usize;
 }
 
 #[derive(Debug, Default)]
@@ -35,23 +46,26 @@ impl Nested for NestedOptional {
         (offsets.into(), validity.into())
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
-    }
-
     fn push(&mut self, value: i64, is_valid: bool) {
         self.offsets.push(value);
         self.validity.push(is_valid);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
         self.offsets.push(length)
     }
+
+    fn is_nullable(&self) -> bool {
+        true
+    }
+
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
 }
 
 impl NestedOptional {
@@ -73,22 +87,25 @@ impl Nested for NestedValid {
         (offsets.into(), None)
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
-    }
-
     fn push(&mut self, value: i64, _is_valid: bool) {
         self.offsets.push(value);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
         self.offsets.push(length)
     }
+
+    fn is_nullable(&self) -> bool {
+        false
+    }
+
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
 }
 
 impl NestedValid {
@@ -98,95 +115,325 @@ impl NestedValid {
     }
 }
 
+/// A struct level. It has no offsets; only a validity (when nullable) and a length.
+#[derive(Debug, Default)]
+pub struct NestedStruct {
+    pub is_nullable: bool,
+    pub validity: MutableBitmap,
+    pub length: usize,
+}
+
+impl Nested for NestedStruct {
+    fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
+        let validity = std::mem::take(&mut self.validity);
+        let validity = if self.is_nullable {
+            validity.into()
+        } else {
+            None
+        };
+        (Buffer::new(), validity)
+    }
+
+    fn push(&mut self, _value: i64, is_valid: bool) {
+        if self.is_nullable {
+            self.validity.push(is_valid);
+        }
+        self.length += 1;
+    }
+
+    fn close(&mut self, _length: i64) {}
+
+    fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl NestedStruct {
+    pub fn with_capacity(is_nullable: bool, capacity: usize) -> Self {
+        let validity = if is_nullable {
+            MutableBitmap::with_capacity(capacity)
+        } else {
+            MutableBitmap::new()
+        };
+        Self {
+            is_nullable,
+            validity,
+            length: 0,
+        }
+    }
+}
+
+/// The nested levels of a leaf column, alongside its values.
+pub type NestedLeaf = (Vec<Box<dyn Nested>>, Arc<dyn Array>);
+
+/// The definition levels of each level of `nested`, as
+/// `(level at which a slot exists, repetition level of its parent, level at which it is valid)`,
+/// followed by the definition level at which the leaf has a slot.
+fn nested_levels(nested: &[Box<dyn Nested>]) -> (Vec<(u32, u32, u32)>, u32) {
+    let mut slot_def = 0;
+    let mut rep = 0;
+    let mut def = 0;
+    let levels = nested
+        .iter()
+        .map(|nested| {
+            let valid_def = def + nested.is_nullable() as u32;
+            let levels = (slot_def, rep, valid_def);
+            def = valid_def;
+            if nested.is_repeated() {
+                // non-empty lists have an extra level
+                def += 1;
+                rep += 1;
+                slot_def = def;
+            }
+            levels
+        })
+        .collect();
+    (levels, slot_def)
+}
+
+/// Returns the minimum definition level for which the leaf of `nested` has a slot (null or not).
+pub fn leaf_def_level(nested: &[Box<dyn Nested>]) -> u32 {
+    nested_levels(nested).1
+}
+
+/// Iterator of the repetition or definition levels of a page.
+pub enum LevelsIter<'a> {
+    /// the maximum level is zero and thus levels are not encoded in the page
+    Zeros(usize),
+    Rle(HybridRleDecoder<'a>),
+}
+
+impl<'a> LevelsIter<'a> {
+    pub fn new(buffer: &'a [u8], max_level: i16, length: usize) -> Self {
+        if max_level == 0 {
+            Self::Zeros(length)
+        } else {
+            Self::Rle(HybridRleDecoder::new(
+                buffer,
+                get_bit_width(max_level),
+                length,
+            ))
+        }
+    }
+}
+
+impl Iterator for LevelsIter<'_> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Zeros(remaining) => {
+                if *remaining == 0 {
+                    None
+                } else {
+                    *remaining -= 1;
+                    Some(0)
+                }
+            }
+            Self::Rle(iter) => iter.next(),
+        }
+    }
+}
+
+/// Extends `nested` from the repetition and definition levels of a page whose leaf has
+/// `length` slots before this page.
 pub fn extend_offsets<R, D>(
     rep_levels: R,
     def_levels: D,
-    is_nullable: bool,
-    max_rep: u32,
-    max_def: u32,
-    nested: &mut Vec<Box<dyn Nested>>,
+    nested: &mut [Box<dyn Nested>],
+    mut length: usize,
 ) where
     R: Iterator<Item = u32>,
     D: Iterator<Item = u32>,
 {
-    assert_eq!(max_rep, 1);
-    let mut values_count = 0;
+    let (levels, leaf_def) = nested_levels(nested);
     rep_levels.zip(def_levels).for_each(|(rep, def)| {
-        if rep == 0 {
-            nested[0].push(values_count, def != 0);
+        for (i, (slot_def, parent_rep, valid_def)) in levels.iter().enumerate() {
+            if def >= *slot_def && rep <= *parent_rep {
+                let child_length = nested.get(i + 1).map(|x| x.len()).unwrap_or(length);
+                nested[i].push(child_length as i64, def >= *valid_def);
+            }
         }
-        if def == max_def || (is_nullable && def == max_def - 1) {
-            values_count += 1;
+        if def >= leaf_def {
+            length += 1;
         }
     });
-    nested[0].close(values_count);
 }
 
-pub fn is_nullable(type_: &ParquetType, container: &mut Vec<bool>) {
-    match type_ {
-        ParquetType::PrimitiveType { basic_info, .. } => {
-            container.push(super::schema::is_nullable(basic_info));
-        }
-        ParquetType::GroupType {
-            basic_info, fields, ..
-        } => {
-            if basic_info.repetition() != &Repetition::Repeated {
-                container.push(super::schema::is_nullable(basic_info));
+/// Closes the offsets of `nested` whose leaf has `length` slots.
+pub fn close(nested: &mut [Box<dyn Nested>], length: usize) {
+    (0..nested.len()).for_each(|i| {
+        let child_length = nested.get(i + 1).map(|x| x.len()).unwrap_or(length);
+        nested[i].close(child_length as i64);
+    })
+}
+
+/// Returns the nested levels from `data_type` to the leaf described by `descriptor`, alongside
+/// the leaf's [`DataType`].
+pub fn init_nested(
+    descriptor: &ColumnDescriptor,
+    data_type: &DataType,
+    capacity: usize,
+) -> Result<(Vec<Box<dyn Nested>>, DataType)> {
+    let path = descriptor.path_in_schema();
+    let mut nested = vec![];
+    let mut type_ = descriptor.base_type();
+    let mut data_type = data_type;
+    let mut depth = 0;
+    loop {
+        let fields = match type_ {
+            ParquetType::PrimitiveType { .. } => return Ok((nested, data_type.clone())),
+            ParquetType::GroupType { fields, .. } => fields,
+        };
+        // the items of 2-level lists are repeated, yet not nullable
+        let is_nullable = type_.get_basic_info().repetition() == &Repetition::Optional;
+        match data_type.to_logical_type() {
+            DataType::List(inner) | DataType::LargeList(inner) => {
+                nested.push(if is_nullable {
+                    Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>
+                } else {
+                    Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>
+                });
+                let repeated = fields.first().ok_or_else(|| {
+                    ArrowError::ExternalFormat("A parquet list must have a child".to_string())
+                })?;
+                depth += 1;
+                type_ = match repeated {
+                    // 2-level list: the repeated field is the element
+                    _ if super::schema::is_list_item(repeated, type_.name()) => repeated,
+                    // 3-level list: the repeated group wraps the element
+                    ParquetType::GroupType { fields, .. } => {
+                        depth += 1;
+                        &fields[0]
+                    }
+                    ParquetType::PrimitiveType { .. } => unreachable!(),
+                };
+                data_type = inner.data_type();
+            }
+            DataType::Struct(arrow_fields) => {
+                nested.push(Box::new(NestedStruct::with_capacity(is_nullable, capacity)));
+                depth += 1;
+                let name = path.get(depth).ok_or_else(|| {
+                    ArrowError::ExternalFormat(format!(
+                        "The column path {:?} does not reach a leaf",
+                        path
+                    ))
+                })?;
+                let index = fields
+                    .iter()
+                    .position(|field| field.name() == name)
+                    .ok_or_else(|| {
+                        ArrowError::ExternalFormat(format!(
+                            "The column path {:?} does not match the schema",
+                            path
+                        ))
+                    })?;
+                type_ = &fields[index];
+                data_type = arrow_fields
+                    .get(index)
+                    .ok_or_else(|| {
+                        ArrowError::ExternalFormat(format!(
+                            "The column path {:?} does not match the datatype {:?}",
+                            path, data_type
+                        ))
+                    })?
+                    .data_type();
             }
-            for field in fields {
-                is_nullable(field, container)
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Read nested datatype {:?}",
+                    other
+                )))
             }
         }
     }
 }
 
-pub fn init_nested(base_type: &ParquetType, capacity: usize) -> (Vec<Box<dyn Nested>>, bool) {
-    let mut nullable = Vec::new();
-    is_nullable(base_type, &mut nullable);
-    // the primitive's nullability is the last on the list
-    let is_nullable = nullable.pop().unwrap();
+/// Returns the number of leaf columns of `data_type`.
+pub fn num_leaves(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::List(inner) | DataType::LargeList(inner) | DataType::FixedSizeList(inner, _) => {
+            num_leaves(inner.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().map(|x| num_leaves(x.data_type())).sum(),
+        _ => 1,
+    }
+}
 
-    (
-        nullable
-            .iter()
-            .map(|is_nullable| {
-                if *is_nullable {
-                    Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>
-                } else {
-                    Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>
-                }
-            })
-            .collect(),
-        is_nullable,
-    )
+/// Creates an [`Array`] of `data_type` from the nested levels and values of each of its leaves.
+pub fn create_nested(data_type: DataType, leaves: &mut [NestedLeaf]) -> Result<Box<dyn Array>> {
+    create(data_type, leaves, 0)
 }
 
-pub fn create_list(
-    data_type: DataType,
-    nested: &mut [Box<dyn Nested>],
-    values: Arc<dyn Array>,
-) -> Result<Box<dyn Array>> {
-    Ok(match data_type {
-        DataType::List(_) => {
-            let (offsets, validity) = nested[0].inner();
+fn create(data_type: DataType, leaves: &mut [NestedLeaf], depth: usize) -> Result<Box<dyn Array>> {
+    if leaves.is_empty() {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The datatype {:?} requires at least one leaf column",
+            data_type
+        )));
+    }
+    Ok(match data_type.to_logical_type() {
+        DataType::List(inner) => {
+            let (offsets, validity) = leaves[0].0[depth].inner();
+            let values = create(inner.data_type().clone(), leaves, depth + 1)?.into();
 
             let offsets = Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32));
             Box::new(ListArray::<i32>::from_data(
                 data_type, offsets, values, validity,
             ))
         }
-        DataType::LargeList(_) => {
-            let (offsets, validity) = nested[0].inner();
+        DataType::LargeList(inner) => {
+            let (offsets, validity) = leaves[0].0[depth].inner();
+            let values = create(inner.data_type().clone(), leaves, depth + 1)?.into();
 
             Box::new(ListArray::<i64>::from_data(
                 data_type, offsets, values, validity,
             ))
         }
+        DataType::Struct(fields) => {
+            let (_, validity) = leaves[0].0[depth].inner();
+
+            let mut start = 0;
+            let values = fields
+                .iter()
+                .map(|field| {
+                    let end = start + num_leaves(field.data_type());
+                    if end > leaves.len() {
+                        return Err(ArrowError::ExternalFormat(format!(
+                            "The datatype {:?} requires {} leaf columns",
+                            data_type,
+                            num_leaves(&data_type)
+                        )));
+                    }
+                    let values = create(
+                        field.data_type().clone(),
+                        &mut leaves[start..end],
+                        depth + 1,
+                    );
+                    start = end;
+                    values.map(|x| x.into())
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Box::new(StructArray::from_data(data_type, values, validity))
+        }
         _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
+            if leaves[0].0.len() != depth {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Read nested datatype {:?}",
+                    data_type
+                )));
+            }
+            clone(leaves[0].1.as_ref())
         }
     })
 }
@@ -0,0 +1,211 @@
+//! Reading of the parquet [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md),
+//! used to select the pages of a column chunk that intersect a row selection or a predicate
+//! on the pages' statistics.
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet2::statistics::{deserialize_statistics as deserialize_parquet, ParquetStatistics};
+use parquet_format_async_temp::{
+    thrift::protocol::TCompactInputProtocol, ColumnIndex, OffsetIndex,
+};
+
+use super::{
+    statistics::{deserialize_statistics, Statistics},
+    ParquetError, PhysicalType, RowGroupMetaData,
+};
+use crate::error::{ArrowError, Result};
+
+/// The location, rows and statistics of a data page of a column chunk, as declared by the
+/// page index of a parquet file.
+#[derive(Debug)]
+pub struct PageIndex {
+    /// The offset of the page in the file
+    pub offset: u64,
+    /// The size in bytes of the page, header included
+    pub length: usize,
+    /// The index of the first row of the page within its row group
+    pub first_row: usize,
+    /// The number of rows of the page
+    pub num_rows: usize,
+    /// The statistics of the page, if the file has a column index for the column chunk
+    /// and its statistics can be deserialized
+    pub statistics: Option<Box<dyn Statistics>>,
+}
+
+/// Reads the `length` bytes at `offset`, erroring when they are not within the file.
+fn read_thrift<R: Read + Seek>(reader: &mut R, offset: i64, length: i32) -> Result<Vec<u8>> {
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    let is_within = offset >= 0
+        && length >= 0
+        && (offset as u64)
+            .checked_add(length as u64)
+            .map(|end| end <= file_length)
+            .unwrap_or(false);
+    if !is_within {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The page index at offset {} with length {} is not within the file of {} bytes",
+            offset, length, file_length
+        )));
+    }
+    reader.seek(SeekFrom::Start(offset as u64))?;
+    let mut buffer = vec![0; length as usize];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Reads the page index of the column `column` of `row_group`.
+/// Returns `None` when the column chunk has no offset index.
+pub fn read_page_index<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    column: usize,
+) -> Result<Option<Vec<PageIndex>>> {
+    let metadata = row_group.column(column);
+    let descriptor = metadata.descriptor().clone();
+    let chunk = metadata.clone().into_thrift();
+
+    let offset_index = match (chunk.offset_index_offset, chunk.offset_index_length) {
+        (Some(offset), Some(length)) => {
+            let buffer = read_thrift(reader, offset, length)?;
+            let mut protocol = TCompactInputProtocol::new(buffer.as_slice());
+            OffsetIndex::read_from_in_protocol(&mut protocol).map_err(ParquetError::from)?
+        }
+        _ => return Ok(None),
+    };
+
+    let column_index = match (chunk.column_index_offset, chunk.column_index_length) {
+        (Some(offset), Some(length)) => {
+            let buffer = read_thrift(reader, offset, length)?;
+            let mut protocol = TCompactInputProtocol::new(buffer.as_slice());
+            Some(ColumnIndex::read_from_in_protocol(&mut protocol).map_err(ParquetError::from)?)
+        }
+        _ => None,
+    };
+    // statistics of fixed-length byte arrays and int96 are not deserialized to arrow
+    let column_index = column_index.filter(|_| {
+        !matches!(
+            descriptor.physical_type(),
+            PhysicalType::FixedLenByteArray(_) | PhysicalType::Int96
+        )
+    });
+
+    let locations = offset_index.page_locations;
+    if let Some(column_index) = column_index.as_ref() {
+        let lengths = [
+            column_index.null_pages.len(),
+            column_index.min_values.len(),
+            column_index.max_values.len(),
+            column_index
+                .null_counts
+                .as_ref()
+                .map(|counts| counts.len())
+                .unwrap_or(locations.len()),
+        ];
+        if lengths.iter().any(|length| *length != locations.len()) {
+            return Err(ArrowError::ExternalFormat(format!(
+                "The column index of the column {} does not have one entry per page of its {} pages",
+                column,
+                locations.len()
+            )));
+        }
+    }
+    let num_rows = row_group.num_rows() as usize;
+    locations
+        .iter()
+        .enumerate()
+        .map(|(index, location)| {
+            let first_row = location.first_row_index;
+            let end = locations
+                .get(index + 1)
+                .map(|location| location.first_row_index)
+                .unwrap_or(num_rows as i64);
+            if first_row < 0
+                || end < first_row
+                || end > num_rows as i64
+                || location.offset < 0
+                || location.compressed_page_size < 0
+            {
+                return Err(ArrowError::ExternalFormat(format!(
+                    "The location of the page {} of the column {} is out of spec",
+                    index, column
+                )));
+            }
+            let (first_row, end) = (first_row as usize, end as usize);
+
+            let statistics = column_index
+                .as_ref()
+                .map(|column_index| {
+                    let is_null_page = column_index.null_pages[index];
+                    let statistics = ParquetStatistics {
+                        null_count: column_index
+                            .null_counts
+                            .as_ref()
+                            .map(|counts| counts[index]),
+                        distinct_count: None,
+                        max: None,
+                        min: None,
+                        max_value: (!is_null_page).then(|| column_index.max_values[index].clone()),
+                        min_value: (!is_null_page).then(|| column_index.min_values[index].clone()),
+                    };
+                    let statistics = deserialize_parquet(&statistics, descriptor.clone())?;
+                    deserialize_statistics(statistics.as_ref())
+                })
+                .transpose()?;
+
+            Ok(PageIndex {
+                offset: location.offset as u64,
+                length: location.compressed_page_size as usize,
+                first_row,
+                num_rows: end - first_row,
+                statistics,
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Returns the indices of the `pages` that contain at least one of the rows in `rows`,
+/// a list of `(start, length)` ranges of rows of the row group.
+pub fn select_pages_by_rows(pages: &[PageIndex], rows: &[(usize, usize)]) -> Vec<usize> {
+    pages
+        .iter()
+        .enumerate()
+        .filter(|(_, page)| {
+            let page_end = page.first_row + page.num_rows;
+            rows.iter()
+                .any(|(start, length)| *start < page_end && page.first_row < start + length)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns the indices of the `pages` whose statistics satisfy `predicate`.
+/// Pages without statistics are always selected, since they may contain matching rows.
+pub fn select_pages_by_statistics<F: Fn(&dyn Statistics) -> bool>(
+    pages: &[PageIndex],
+    predicate: F,
+) -> Vec<usize> {
+    pages
+        .iter()
+        .enumerate()
+        .filter(|(_, page)| {
+            page.statistics
+                .as_ref()
+                .map(|statistics| predicate(statistics.as_ref()))
+                .unwrap_or(true)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns the `(start, length)` ranges of rows of the `selected` pages (in increasing order),
+/// merging the ranges of consecutive pages.
+pub fn selected_rows(pages: &[PageIndex], selected: &[usize]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for page in selected.iter().map(|index| &pages[*index]) {
+        match ranges.last_mut() {
+            Some((start, length)) if *start + *length == page.first_row => *length += page.num_rows,
+            _ => ranges.push((page.first_row, page.num_rows)),
+        }
+    }
+    ranges
+}
@@ -4,6 +4,7 @@ use parquet2::{
     types::NativeType,
 };
 
+use super::super::encoding::decode_primitive;
 use super::super::utils as other_utils;
 use super::utils::ExactChunksIter;
 use super::ColumnDescriptor;
@@ -183,12 +184,18 @@ where
     let is_optional = descriptor.max_def_level() == 1;
 
     let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor);
+    let values_buffer = decode_primitive(
+        page.encoding(),
+        descriptor.physical_type(),
+        values_buffer,
+        page.num_values(),
+    )?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
             read_dict_buffer_optional(
                 validity_buffer,
-                values_buffer,
+                &values_buffer,
                 additional,
                 dict.as_any().downcast_ref().unwrap(),
                 values,
@@ -198,7 +205,7 @@ where
         }
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
             read_dict_buffer_required(
-                values_buffer,
+                &values_buffer,
                 additional,
                 dict.as_any().downcast_ref().unwrap(),
                 values,
@@ -208,15 +215,19 @@ where
         }
         // it can happen that there is a dictionary but the encoding is plain because
         // it falled back.
-        (Encoding::Plain, _, true) => read_nullable(
-            validity_buffer,
-            values_buffer,
-            additional,
-            values,
-            validity,
-            op,
-        ),
-        (Encoding::Plain, _, false) => read_required(page.buffer(), additional, values, op),
+        (Encoding::Plain | Encoding::DeltaBinaryPacked | Encoding::ByteStreamSplit, _, true) => {
+            read_nullable(
+                validity_buffer,
+                &values_buffer,
+                additional,
+                values,
+                validity,
+                op,
+            )
+        }
+        (Encoding::Plain | Encoding::DeltaBinaryPacked | Encoding::ByteStreamSplit, _, false) => {
+            read_required(&values_buffer, additional, values, op)
+        }
         _ => {
             return Err(other_utils::not_implemented(
                 &page.encoding(),
@@ -106,8 +106,9 @@ pub fn iter_to_array_nested<T, A, I, E, F>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
     data_type: DataType,
+    nested: &mut Vec<Box<dyn Nested>>,
     op: F,
-) -> Result<Box<dyn Array>>
+) -> Result<Arc<dyn Array>>
 where
     ArrowError: From<E>,
     T: NativeType,
@@ -120,38 +121,24 @@ where
     let mut values = MutableBuffer::<A>::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
+    let is_nullable = leaf_def_level(nested) < metadata.descriptor().max_def_level() as u32;
 
     while let Some(page) = iter.next() {
         nested::extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
             is_nullable,
-            &mut nested,
+            nested,
             &mut values,
             &mut validity,
             op,
         )?
     }
+    close(nested, values.len());
 
-    let values = match data_type {
-        DataType::List(ref inner) => Arc::new(PrimitiveArray::<A>::from_data(
-            inner.data_type().clone(),
-            values.into(),
-            validity.into(),
-        )),
-        DataType::LargeList(ref inner) => Arc::new(PrimitiveArray::<A>::from_data(
-            inner.data_type().clone(),
-            values.into(),
-            validity.into(),
-        )),
-        _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
-        }
-    };
-
-    create_list(data_type, &mut nested, values)
+    Ok(Arc::new(PrimitiveArray::<A>::from_data(
+        data_type,
+        values.into(),
+        validity.into(),
+    )))
 }
@@ -5,7 +5,8 @@ use parquet2::{
     types::NativeType,
 };
 
-use super::super::nested_utils::extend_offsets;
+use super::super::encoding::decode_primitive;
+use super::super::nested_utils::{extend_offsets, leaf_def_level, LevelsIter};
 use super::ColumnDescriptor;
 use super::{super::utils, utils::ExactChunksIter, Nested};
 use crate::{
@@ -16,6 +17,7 @@ use crate::{
 fn read_values<T, D, G, F, A>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     op: F,
     values: &mut MutableBuffer<A>,
@@ -31,7 +33,7 @@ fn read_values<T, D, G, F, A>(
         if def == max_def {
             values.push(op(new_values.next().unwrap()));
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             values.push(A::default());
             validity.push(false);
         }
@@ -57,7 +59,6 @@ fn read<T, A, F>(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
@@ -69,35 +70,35 @@ fn read<T, A, F>(
 {
     let new_values = ExactChunksIter::<T>::new(values_buffer);
 
-    let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
+    let min_def_level = leaf_def_level(nested);
+    let length = values.len();
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
+            let rep_levels = LevelsIter::new(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
                 let def_levels = HybridRleDecoder::new(
                     def_levels,
                     get_bit_width(def_level_encoding.1),
                     additional,
                 );
-                read_values(def_levels, max_def_level, new_values, op, values, validity)
+                read_values(
+                    def_levels,
+                    max_def_level,
+                    min_def_level,
+                    new_values,
+                    op,
+                    values,
+                    validity,
+                )
             } else {
                 read_values_required(new_values, op, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = LevelsIter::new(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested, length)
         }
         _ => todo!(),
     }
@@ -120,12 +121,18 @@ where
     let additional = page.num_values();
 
     let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let values_buffer = decode_primitive(
+        page.encoding(),
+        descriptor.physical_type(),
+        values_buffer,
+        page.num_values(),
+    )?;
 
     match (&page.encoding(), page.dictionary_page()) {
-        (Encoding::Plain, None) => read(
+        (Encoding::Plain | Encoding::DeltaBinaryPacked | Encoding::ByteStreamSplit, None) => read(
             rep_levels,
             def_levels,
-            values_buffer,
+            &values_buffer,
             additional,
             (
                 &page.repetition_level_encoding(),
@@ -135,7 +142,6 @@ where
                 &page.definition_level_encoding(),
                 descriptor.max_def_level(),
             ),
-            is_nullable,
             nested,
             values,
             validity,
@@ -8,29 +8,80 @@ use crate::{
     datatypes::{Field, Schema},
     error::{ArrowError, Result},
     record_batch::RecordBatch,
+    scalar::Scalar,
 };
 
 use super::{
-    get_page_iterator, get_schema, page_iter_to_array, read_metadata, Decompressor, FileMetaData,
+    create_nested, get_page_iterator, get_schema, page_iter_to_array, page_iter_to_leaf,
+    read_bloom_filter, read_metadata, row_group::field_columns, Decompressor, FileMetaData,
     PageFilter, RowGroupMetaData,
 };
 
-type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
+pub(super) type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
 
 /// Single threaded iterator of [`RecordBatch`] from a parquet file.
 pub struct RecordReader<R: Read + Seek> {
     reader: R,
     schema: Arc<Schema>,
-    indices: Rc<Vec<usize>>,
+    // the range of leaf columns of each field, according to the file's indexing
+    indices: Rc<Vec<(usize, usize)>>,
     buffer: Vec<u8>,
     decompress_buffer: Vec<u8>,
     groups_filter: Option<GroupFilter>,
     pages_filter: Option<PageFilter>,
+    // (field, value): row groups whose bloom filter of `field` does not contain `value` are skipped
+    value_filters: Vec<(usize, Arc<dyn Scalar>)>,
     metadata: Rc<FileMetaData>,
     current_group: usize,
     remaining_rows: usize,
 }
 
+/// Returns the schema of the fields of `metadata` in `projection` (all fields if `None`),
+/// alongside the range of leaf columns of each of them, according to the file's indexing.
+pub(super) fn project(
+    metadata: &FileMetaData,
+    projection: Option<&[usize]>,
+) -> Result<(Vec<(usize, usize)>, Schema)> {
+    let schema = get_schema(metadata)?;
+
+    let schema_metadata = schema.metadata;
+    let columns = field_columns(metadata);
+    let (indices, fields): (Vec<(usize, usize)>, Vec<Field>) = if let Some(projection) = projection
+    {
+        schema
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, f)| {
+                if projection.iter().any(|&i| i == index) {
+                    Some((columns[index], f))
+                } else {
+                    None
+                }
+            })
+            .unzip()
+    } else {
+        columns.into_iter().zip(schema.fields).unzip()
+    };
+
+    if let Some(projection) = projection {
+        if indices.len() != projection.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "While reading parquet, some columns in the projection do not exist in the file"
+                    .to_string(),
+            ));
+        }
+    }
+
+    Ok((
+        indices,
+        Schema {
+            fields,
+            metadata: schema_metadata,
+        },
+    ))
+}
+
 impl<R: Read + Seek> RecordReader<R> {
     pub fn try_new(
         mut reader: R,
@@ -41,36 +92,8 @@ impl<R: Read + Seek> RecordReader<R> {
     ) -> Result<Self> {
         let metadata = read_metadata(&mut reader)?;
 
-        let schema = get_schema(&metadata)?;
-
-        let schema_metadata = schema.metadata;
-        let (indices, fields): (Vec<usize>, Vec<Field>) = if let Some(projection) = &projection {
-            schema
-                .fields
-                .into_iter()
-                .enumerate()
-                .filter_map(|(index, f)| {
-                    if projection.iter().any(|&i| i == index) {
-                        Some((index, f))
-                    } else {
-                        None
-                    }
-                })
-                .unzip()
-        } else {
-            schema.fields.into_iter().enumerate().unzip()
-        };
-
-        if let Some(projection) = &projection {
-            if indices.len() != projection.len() {
-                return Err(ArrowError::InvalidArgumentError("While reading parquet, some columns in the projection do not exist in the file".to_string()));
-            }
-        }
-
-        let schema = Arc::new(Schema {
-            fields,
-            metadata: schema_metadata,
-        });
+        let (indices, schema) = project(&metadata, projection.as_deref())?;
+        let schema = Arc::new(schema);
 
         Ok(Self {
             reader,
@@ -78,6 +101,7 @@ impl<R: Read + Seek> RecordReader<R> {
             indices: Rc::new(indices),
             groups_filter,
             pages_filter,
+            value_filters: vec![],
             metadata: Rc::new(metadata),
             current_group: 0,
             buffer: vec![],
@@ -97,6 +121,12 @@ impl<R: Read + Seek> RecordReader<R> {
     pub fn set_groups_filter(&mut self, groups_filter: GroupFilter) {
         self.groups_filter = Some(groups_filter);
     }
+
+    /// Skips the row groups whose bloom filter of the field `field` (of the projected schema)
+    /// does not contain `value`. Row groups without a bloom filter for the field are read.
+    pub fn add_value_filter(&mut self, field: usize, value: Arc<dyn Scalar>) {
+        self.value_filters.push((field, value));
+    }
 }
 
 impl<R: Read + Seek> Iterator for RecordReader<R> {
@@ -123,6 +153,21 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
                 return self.next();
             }
         }
+        for (field, value) in self.value_filters.iter() {
+            let (start, end) = self.indices[*field];
+            if end - start != 1 {
+                // only fields with a single leaf have a bloom filter
+                continue;
+            }
+            match read_bloom_filter(&mut self.reader, group, start) {
+                Ok(Some(filter)) if !filter.contains_scalar(value.as_ref()) => {
+                    self.current_group += 1;
+                    return self.next();
+                }
+                Ok(_) => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
         let columns_meta = group.columns();
 
         // todo: avoid these clones.
@@ -134,20 +179,51 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
         let a = schema.fields().iter().enumerate().try_fold(
             (b1, b2, Vec::with_capacity(schema.fields().len())),
             |(b1, b2, mut columns), (column, field)| {
-                // column according to the file's indexing
-                let column = self.indices[column];
-                let column_metadata = &columns_meta[column];
-                let pages = get_page_iterator(
-                    column_metadata,
-                    &mut self.reader,
-                    self.pages_filter.clone(),
-                    b1,
-                )?;
-
-                let mut pages = Decompressor::new(pages, b2);
-
-                let array =
-                    page_iter_to_array(&mut pages, column_metadata, field.data_type().clone())?;
+                // columns according to the file's indexing
+                let (start, end) = self.indices[column];
+
+                let (array, b1, b2) = if end - start == 1 {
+                    let column_metadata = &columns_meta[start];
+                    let pages = get_page_iterator(
+                        column_metadata,
+                        &mut self.reader,
+                        self.pages_filter.clone(),
+                        b1,
+                    )?;
+
+                    let mut pages = Decompressor::new(pages, b2);
+
+                    let array =
+                        page_iter_to_array(&mut pages, column_metadata, field.data_type().clone())?;
+                    let (b1, b2) = pages.into_buffers();
+                    (array, b1, b2)
+                } else {
+                    // a nested field: read each of its leaves
+                    let (leaves, b1, b2) = columns_meta[start..end].iter().try_fold(
+                        (Vec::with_capacity(end - start), b1, b2),
+                        |(mut leaves, b1, b2), column_metadata| {
+                            let pages = get_page_iterator(
+                                column_metadata,
+                                &mut self.reader,
+                                self.pages_filter.clone(),
+                                b1,
+                            )?;
+
+                            let mut pages = Decompressor::new(pages, b2);
+
+                            leaves.push(page_iter_to_leaf(
+                                &mut pages,
+                                column_metadata,
+                                field.data_type(),
+                            )?);
+                            let (b1, b2) = pages.into_buffers();
+                            Result::Ok((leaves, b1, b2))
+                        },
+                    )?;
+                    let mut leaves = leaves;
+                    let array = create_nested(field.data_type().clone(), &mut leaves)?;
+                    (array, b1, b2)
+                };
 
                 let array = if array.len() > remaining_rows {
                     array.slice(0, remaining_rows)
@@ -156,7 +232,6 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
                 };
 
                 columns.push(array.into());
-                let (b1, b2) = pages.into_buffers();
                 Result::Ok((b1, b2, columns))
             },
         );
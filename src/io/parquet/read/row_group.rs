@@ -0,0 +1,157 @@
+//! Reading of row groups in two steps: the compressed column chunks are read sequentially
+//! from the file, and then decompressed and deserialized in parallel, one task per field.
+use std::{
+    io::{Read, Seek},
+    sync::{mpsc, Arc},
+};
+
+use crate::{
+    array::Array,
+    datatypes::{Field, Schema},
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
+};
+
+use super::{
+    create_nested, decompress, get_page_iterator, page_iter_to_array, page_iter_to_leaf,
+    record_batch::project, schema::num_columns, streaming_iterator, ColumnChunkMetaData,
+    CompressedDataPage, FileMetaData, PageFilter,
+};
+
+/// A task to be run by an executor, see [`CompressedRowGroup::decode`].
+pub type Task = Box<dyn FnOnce() + Send + 'static>;
+
+/// The range of leaf columns of each field of `metadata`'s schema, according to the
+/// file's indexing.
+pub(super) fn field_columns(metadata: &FileMetaData) -> Vec<(usize, usize)> {
+    let mut start = 0;
+    metadata
+        .schema()
+        .fields()
+        .iter()
+        .map(|type_| {
+            let end = start + num_columns(type_);
+            let range = (start, end);
+            start = end;
+            range
+        })
+        .collect()
+}
+
+/// The compressed pages of the column chunks (one per leaf) of a field of a row group.
+#[derive(Debug)]
+pub(super) struct CompressedField {
+    pub field: Field,
+    pub chunks: Vec<(ColumnChunkMetaData, Vec<CompressedDataPage>)>,
+}
+
+impl CompressedField {
+    /// Decompresses and deserializes the pages of this field into an array.
+    pub fn decode(self) -> Result<Box<dyn Array>> {
+        let data_type = self.field.data_type().clone();
+        let mut buffer = vec![];
+        let mut leaves = Vec::with_capacity(self.chunks.len());
+        let is_nested = self.chunks.len() > 1;
+        for (metadata, pages) in self.chunks {
+            let pages = pages.into_iter().map(|page| decompress(page, &mut buffer));
+            let mut pages = streaming_iterator::convert(pages);
+            if !is_nested {
+                return page_iter_to_array(&mut pages, &metadata, data_type);
+            }
+            leaves.push(page_iter_to_leaf(&mut pages, &metadata, &data_type)?);
+        }
+        create_nested(data_type, &mut leaves)
+    }
+}
+
+/// The compressed column chunks of a row group, read by [`read_compressed_row_group`].
+#[derive(Debug)]
+pub struct CompressedRowGroup {
+    schema: Arc<Schema>,
+    fields: Vec<CompressedField>,
+}
+
+/// Reads the compressed pages of the column chunks of the row group `row_group` of a file
+/// with metadata `metadata`, restricted to the fields in `projection` (in the file's order),
+/// if any. This performs all the IO needed to decode the row group with
+/// [`CompressedRowGroup::decode`].
+pub fn read_compressed_row_group<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    projection: Option<&[usize]>,
+    pages_filter: Option<PageFilter>,
+) -> Result<CompressedRowGroup> {
+    let group = metadata.row_groups.get(row_group).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The parquet file has {} row groups but row group {} was requested",
+            metadata.row_groups.len(),
+            row_group
+        ))
+    })?;
+
+    let (indices, schema) = project(metadata, projection)?;
+
+    let fields = schema
+        .fields
+        .iter()
+        .zip(indices)
+        .map(|(field, (start, end))| {
+            let chunks = group.columns()[start..end]
+                .iter()
+                .map(|column_metadata| {
+                    let pages =
+                        get_page_iterator(column_metadata, reader, pages_filter.clone(), vec![])?
+                            .collect::<std::result::Result<Vec<_>, _>>()?;
+                    Ok((column_metadata.clone(), pages))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(CompressedField {
+                field: field.clone(),
+                chunks,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(CompressedRowGroup {
+        schema: Arc::new(schema),
+        fields,
+    })
+}
+
+impl CompressedRowGroup {
+    /// The schema of the [`RecordBatch`] decoded from this row group.
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Decompresses and deserializes this row group into a [`RecordBatch`], passing one
+    /// [`Task`] per field to `spawn`, which is expected to run it, e.g. on a thread pool
+    /// (`|task| rayon::spawn(task)`), on a new thread (`|task| { std::thread::spawn(task); }`)
+    /// or inline (`|task| task()`). The columns of the batch are in the order of the schema
+    /// regardless of the order in which the tasks complete.
+    pub fn decode<F: FnMut(Task)>(self, mut spawn: F) -> Result<RecordBatch> {
+        let num_fields = self.fields.len();
+        let (sender, receiver) = mpsc::channel();
+        for (index, field) in self.fields.into_iter().enumerate() {
+            let sender = sender.clone();
+            spawn(Box::new(move || {
+                // the receiver is only dropped on error, in which case the result is not needed
+                let _ = sender.send((index, field.decode()));
+            }));
+        }
+        drop(sender);
+
+        let mut columns: Vec<Option<Arc<dyn Array>>> = vec![None; num_fields];
+        for _ in 0..num_fields {
+            let (index, array) = receiver.recv().map_err(|_| {
+                ArrowError::ExternalFormat(
+                    "A task decoding a parquet column did not complete".to_string(),
+                )
+            })?;
+            columns[index] = Some(array?.into());
+        }
+        let columns = columns.into_iter().map(|array| array.unwrap()).collect();
+        RecordBatch::try_new(self.schema, columns)
+    }
+}
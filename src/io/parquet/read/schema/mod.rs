@@ -21,3 +21,25 @@ pub fn get_schema(metadata: &FileMetaData) -> Result<Schema> {
 pub fn is_type_nullable(type_: &ParquetType) -> bool {
     is_nullable(type_.get_basic_info())
 }
+
+/// Returns whether `repeated`, the repeated field of the parquet list named `list`, is the
+/// list's item (2-level layout) instead of a group wrapping it (3-level layout), according to
+/// the [backward-compatibility rules](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules).
+pub(crate) fn is_list_item(repeated: &ParquetType, list: &str) -> bool {
+    match repeated {
+        ParquetType::PrimitiveType { .. } => true,
+        ParquetType::GroupType { fields, .. } => {
+            fields.len() != 1
+                || repeated.name() == "array"
+                || repeated.name() == format!("{}_tuple", list)
+        }
+    }
+}
+
+/// Returns the number of leaf (primitive) columns of `type_`.
+pub(crate) fn num_columns(type_: &ParquetType) -> usize {
+    match type_ {
+        ParquetType::PrimitiveType { .. } => 1,
+        ParquetType::GroupType { fields, .. } => fields.iter().map(num_columns).sum(),
+    }
+}
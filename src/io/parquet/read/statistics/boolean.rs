@@ -15,6 +15,10 @@ impl Statistics for BooleanStatistics {
     fn data_type(&self) -> &DataType {
         &DataType::Boolean
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 impl From<&ParquetBooleanStatistics> for BooleanStatistics {
@@ -16,6 +16,9 @@ pub use boolean::*;
 /// Trait denoting a deserialized parquet statistics (into arrow).
 pub trait Statistics: std::fmt::Debug {
     fn data_type(&self) -> &DataType;
+
+    /// Returns `self` as [`std::any::Any`], so that it can be downcasted to its concrete type.
+    fn as_any(&self) -> &dyn std::any::Any;
 }
 
 impl PartialEq for &dyn Statistics {
@@ -20,6 +20,10 @@ impl<T: NativeType> Statistics for PrimitiveStatistics<T> {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 impl<T, R> From<(&ParquetPrimitiveStatistics<R>, DataType)> for PrimitiveStatistics<T>
@@ -0,0 +1,202 @@
+//! Asynchronous reading of [`RecordBatch`]es from parquet files.
+use std::{
+    collections::VecDeque,
+    io::{Cursor, Read, Seek, SeekFrom},
+    sync::Arc,
+};
+
+use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, Stream};
+
+use crate::{
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
+};
+
+use super::{
+    get_page_iterator, read_metadata_async,
+    record_batch::{project, GroupFilter},
+    row_group::CompressedField,
+    FileMetaData, PageFilter, RowGroupMetaData,
+};
+
+/// The bytes of a column chunk, read as if they were at their position in the file.
+struct ChunkReader {
+    // the position of the chunk in the file
+    start: u64,
+    data: Cursor<Vec<u8>>,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.data.read(buf)
+    }
+}
+
+impl Seek for ChunkReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(offset) => {
+                SeekFrom::Start(offset.checked_sub(self.start).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Seek before the start of the column chunk",
+                    )
+                })?)
+            }
+            other => other,
+        };
+        Ok(self.data.seek(pos)? + self.start)
+    }
+}
+
+/// Asynchronous reader of [`RecordBatch`]es from a parquet file, see [`AsyncRecordReader::into_stream`].
+/// Each selected column chunk is fetched with a single read of its byte range, so that only the
+/// bytes of the projected columns of the selected row groups are read from `reader`.
+pub struct AsyncRecordReader<R: AsyncRead + AsyncSeek + Send + Unpin> {
+    reader: R,
+    schema: Arc<Schema>,
+    // the range of leaf columns of each field, according to the file's indexing
+    indices: Vec<(usize, usize)>,
+    groups_filter: Option<GroupFilter>,
+    pages_filter: Option<PageFilter>,
+    metadata: FileMetaData,
+    current_group: usize,
+    remaining_rows: usize,
+    batch_size: Option<usize>,
+    // batches of the current row group not yet returned
+    pending: VecDeque<RecordBatch>,
+}
+
+impl<R: AsyncRead + AsyncSeek + Send + Unpin> AsyncRecordReader<R> {
+    /// Reads the metadata of the parquet file from `reader` and returns a new
+    /// [`AsyncRecordReader`] of the fields in `projection` (all fields if `None`),
+    /// returning at most `limit` rows from the row groups selected by `groups_filter`.
+    pub async fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+        pages_filter: Option<PageFilter>,
+    ) -> Result<Self> {
+        let metadata = read_metadata_async(&mut reader).await?;
+        let (indices, schema) = project(&metadata, projection.as_deref())?;
+
+        Ok(Self {
+            reader,
+            schema: Arc::new(schema),
+            indices,
+            groups_filter,
+            pages_filter,
+            metadata,
+            current_group: 0,
+            remaining_rows: limit.unwrap_or(usize::MAX),
+            batch_size: None,
+            pending: VecDeque::new(),
+        })
+    }
+
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    pub fn metadata(&self) -> &FileMetaData {
+        &self.metadata
+    }
+
+    pub fn set_groups_filter(&mut self, groups_filter: GroupFilter) {
+        self.groups_filter = Some(groups_filter);
+    }
+
+    /// Sets the maximum number of rows of the returned batches. By default, one batch is
+    /// returned per row group.
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        assert!(batch_size > 0);
+        self.batch_size = Some(batch_size);
+    }
+
+    /// Reads the column chunks of the projected fields of the row group `group`.
+    async fn read_row_group(&mut self, group: usize) -> Result<RecordBatch> {
+        let row_group: &RowGroupMetaData = &self.metadata.row_groups[group];
+
+        let mut columns = Vec::with_capacity(self.indices.len());
+        for (field, (start, end)) in self.schema.fields().iter().zip(self.indices.iter()) {
+            let mut chunks = Vec::with_capacity(end - start);
+            for column_metadata in &row_group.columns()[*start..*end] {
+                let (start, length) = column_metadata.byte_range();
+                self.reader.seek(SeekFrom::Start(start)).await?;
+                let mut data = vec![0; length as usize];
+                self.reader.read_exact(&mut data).await?;
+
+                let mut chunk = ChunkReader {
+                    start,
+                    data: Cursor::new(data),
+                };
+                let pages = get_page_iterator(
+                    column_metadata,
+                    &mut chunk,
+                    self.pages_filter.clone(),
+                    vec![],
+                )?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+                chunks.push((column_metadata.clone(), pages));
+            }
+
+            let array = CompressedField {
+                field: field.clone(),
+                chunks,
+            }
+            .decode()?;
+            let array = if array.len() > self.remaining_rows {
+                array.slice(0, self.remaining_rows)
+            } else {
+                array
+            };
+            columns.push(array.into());
+        }
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+
+    /// Returns the next batch, or `None` when all selected row groups were read or the
+    /// limit was reached.
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        loop {
+            if let Some(batch) = self.pending.pop_front() {
+                return Ok(Some(batch));
+            }
+            if self.remaining_rows == 0 || self.current_group == self.metadata.row_groups.len() {
+                return Ok(None);
+            }
+            let group = self.current_group;
+            self.current_group += 1;
+            if let Some(groups_filter) = self.groups_filter.as_ref() {
+                if !(groups_filter)(group, &self.metadata.row_groups[group]) {
+                    continue;
+                }
+            }
+
+            let batch = self.read_row_group(group).await?;
+            self.remaining_rows -= batch.num_rows();
+            let num_rows = batch.num_rows();
+            let batch_size = self.batch_size.unwrap_or(num_rows).max(1);
+            for offset in (0..num_rows).step_by(batch_size) {
+                let length = batch_size.min(num_rows - offset);
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .map(|array| array.slice(offset, length).into())
+                    .collect();
+                self.pending
+                    .push_back(RecordBatch::try_new(self.schema.clone(), columns)?);
+            }
+        }
+    }
+
+    /// Converts this reader into a [`Stream`] of [`RecordBatch`]es. The stream ends after
+    /// the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch>> {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok::<_, ArrowError>(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+    }
+}
@@ -1,12 +1,12 @@
 use parquet2::{
-    encoding::{delta_bitpacked, Encoding},
+    encoding::Encoding,
     metadata::ColumnDescriptor,
     page::CompressedDataPage,
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
     write::WriteOptions,
 };
 
-use super::super::utils;
+use super::super::{encoding, utils};
 use crate::{
     array::{Array, BinaryArray, Offset},
     bitmap::Bitmap,
@@ -90,6 +90,7 @@ pub fn array_to_page<O: Offset>(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         uncompressed_page_size,
         0,
@@ -101,7 +102,7 @@ pub fn array_to_page<O: Offset>(
     )
 }
 
-pub(super) fn build_statistics<O: Offset>(
+pub(crate) fn build_statistics<O: Offset>(
     array: &BinaryArray<O>,
     descriptor: ColumnDescriptor,
 ) -> ParquetStatistics {
@@ -138,16 +139,15 @@ pub(crate) fn encode_delta<O: Offset>(
                 .zip(validity.iter())
                 .flat_map(|(x, is_valid)| if is_valid { Some(x) } else { None });
             let length = offsets.len() - 1 - validity.null_count();
-            let lengths = utils::ExactSizedIter::new(lengths, length);
 
-            delta_bitpacked::encode(lengths, buffer);
+            encoding::encode_delta_i32(lengths, length, buffer);
         } else {
             let lengths = offsets.windows(2).map(|w| (w[1] - w[0]).to_isize() as i32);
-            delta_bitpacked::encode(lengths, buffer);
+            encoding::encode_delta_i32(lengths, offsets.len() - 1, buffer);
         }
     } else {
         let lengths = offsets.windows(2).map(|w| (w[1] - w[0]).to_isize() as i32);
-        delta_bitpacked::encode(lengths, buffer);
+        encoding::encode_delta_i32(lengths, offsets.len() - 1, buffer);
     }
 
     buffer.extend_from_slice(
@@ -1,5 +1,8 @@
 use parquet2::{
-    encoding::{hybrid_rle::bitpacked_encode, Encoding},
+    encoding::{
+        hybrid_rle::{bitpacked_encode, encode_bool},
+        Encoding,
+    },
     metadata::ColumnDescriptor,
     page::CompressedDataPage,
     statistics::{serialize_statistics, BooleanStatistics, ParquetStatistics, Statistics},
@@ -7,7 +10,7 @@ use parquet2::{
 };
 
 use super::super::utils;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{array::*, io::parquet::read::is_type_nullable};
 
 fn encode(iterator: impl Iterator<Item = bool>, buffer: &mut Vec<u8>) -> Result<()> {
@@ -38,10 +41,29 @@ pub(super) fn encode_plain(
     }
 }
 
+fn encode_rle(array: &BooleanArray, is_optional: bool, buffer: &mut Vec<u8>) -> Result<()> {
+    // SPEC: RLE-encoded booleans are prefixed by the length in bytes of the runs (4 bytes, LE)
+    buffer.extend_from_slice(&[0; 4]);
+    let start = buffer.len();
+    if is_optional {
+        let iter = array
+            .iter()
+            .flatten()
+            .take(array.len() - array.null_count());
+        encode_bool(buffer, iter)?
+    } else {
+        encode_bool(buffer, array.values().iter())?
+    }
+    let length = (buffer.len() - start) as u32;
+    buffer[start - 4..start].copy_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
 pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<CompressedDataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
@@ -58,7 +80,17 @@ pub fn array_to_page(
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer)?,
+        Encoding::Rle => encode_rle(array, is_optional, &mut buffer)?,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let uncompressed_page_size = buffer.len();
 
@@ -73,6 +105,7 @@ pub fn array_to_page(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         uncompressed_page_size,
         0,
@@ -80,7 +113,7 @@ pub fn array_to_page(
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
@@ -5,32 +5,35 @@ use parquet2::{
 use super::super::{levels, utils};
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, BooleanArray, Offset},
+    array::{Array, BooleanArray},
     error::Result,
-    io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O>(
+pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
-) -> Result<CompressedDataPage>
-where
-    O: Offset,
-{
-    let is_optional = is_type_nullable(descriptor.type_());
-
-    let validity = array.validity();
-
+    nested: &levels::Levels,
+) -> Result<CompressedDataPage> {
     let mut buffer = vec![];
-    levels::write_rep_levels(&mut buffer, &nested, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &nested.rep,
+        descriptor.max_rep_level(),
+        options.version,
+    )?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &nested.def,
+        descriptor.max_def_level(),
+        options.version,
+    )?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    // the array has one slot per level, null whenever the level is not defined
+    encode_plain(array, true, &mut buffer)?;
 
     let uncompressed_page_size = buffer.len();
 
@@ -48,7 +51,8 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        array.len(),
+        nested.num_rows(),
         array.null_count(),
         uncompressed_page_size,
         repetition_levels_byte_length,
@@ -1,13 +1,20 @@
+use std::collections::HashMap;
+
 use parquet2::{
-    encoding::{hybrid_rle::encode_u32, Encoding},
+    encoding::{get_length, hybrid_rle::encode_u32, Encoding},
     metadata::ColumnDescriptor,
     page::{CompressedDictPage, CompressedPage},
+    statistics::ParquetStatistics,
     write::{DynIter, WriteOptions},
 };
 
-use super::binary::encode_plain as binary_encode_plain;
-use super::primitive::encode_plain as primitive_encode_plain;
-use super::utf8::encode_plain as utf8_encode_plain;
+use super::binary::{
+    build_statistics as binary_build_statistics, encode_plain as binary_encode_plain,
+};
+use super::primitive::{
+    build_statistics as primitive_build_statistics, encode_plain as primitive_encode_plain,
+};
+use super::utf8::{build_statistics as utf8_build_statistics, encode_plain as utf8_encode_plain};
 use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
@@ -21,6 +28,7 @@ fn encode_keys<K: DictionaryKey>(
     validity: &Option<Bitmap>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
+    statistics: Option<ParquetStatistics>,
 ) -> Result<CompressedPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
@@ -59,19 +67,15 @@ fn encode_keys<K: DictionaryKey>(
     // encode indices
     // compute the required number of bits
     if let Some(validity) = validity {
-        let keys = array
-            .iter()
-            .flatten()
-            .map(|x| {
-                let index = x.to_usize().unwrap();
-                // discard indices whose values are null, since they are part of the def levels.
-                if validity.get_bit(index) {
-                    Some(index as u32)
-                } else {
-                    None
-                }
-            })
-            .flatten();
+        let keys = array.iter().flatten().filter_map(|x| {
+            let index = x.to_usize().unwrap();
+            // discard indices whose values are null, since they are part of the def levels.
+            if validity.get_bit(index) {
+                Some(index as u32)
+            } else {
+                None
+            }
+        });
         let num_bits = utils::get_bit_width(keys.clone().max().unwrap_or(0) as u64) as u8;
 
         let keys = utils::ExactSizedIter::new(keys, array.len() - null_count);
@@ -101,11 +105,12 @@ fn encode_keys<K: DictionaryKey>(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         uncompressed_page_size,
         0,
         definition_levels_byte_length,
-        None,
+        statistics,
         descriptor,
         options,
         Encoding::RleDictionary,
@@ -129,6 +134,7 @@ pub fn array_to_pages<K: DictionaryKey>(
     descriptor: ColumnDescriptor,
     options: WriteOptions,
     encoding: Encoding,
+    max_page_size: usize,
 ) -> Result<DynIter<'static, Result<CompressedPage>>>
 where
     PrimitiveArray<K>: std::fmt::Display,
@@ -187,11 +193,23 @@ where
                 }
             };
 
-            // write DataPage pointing to DictPage
-            let data_page =
-                encode_keys(array.keys(), array.values().validity(), descriptor, options)?;
+            // write DataPages pointing to DictPage
+            let keys = array.keys();
+            let data_pages =
+                utils::page_ranges(keys.len(), utils::estimated_size(array), max_page_size)
+                    .into_iter()
+                    .map(|(offset, length)| {
+                        encode_keys(
+                            &keys.slice(offset, length),
+                            array.values().validity(),
+                            descriptor.clone(),
+                            options,
+                            None,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
 
-            let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
+            let iter = std::iter::once(Ok(dict_page)).chain(data_pages.into_iter().map(Ok));
             Ok(DynIter::new(Box::new(iter)))
         }
         _ => Err(ArrowError::NotYetImplemented(
@@ -199,3 +217,225 @@ where
         )),
     }
 }
+
+/// When non-dictionary arrays written with dictionary encoding are dictionary-encoded.
+/// Column chunks that exceed any of these limits are written with [`Encoding::Plain`] instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DictionaryOptions {
+    /// The maximum size in bytes of the (PLAIN-encoded) dictionary of a column chunk
+    pub max_size: usize,
+    /// The maximum ratio between the number of distinct and non-null values of a column
+    /// chunk, above which its dictionary is not worth its keys
+    pub max_distinct_ratio: f64,
+}
+
+impl Default for DictionaryOptions {
+    fn default() -> Self {
+        Self {
+            max_size: 1024 * 1024,
+            max_distinct_ratio: 0.5,
+        }
+    }
+}
+
+/// Returns the dictionary of `values`, each item being the PLAIN encoding of a non-null value,
+/// alongside its number of entries and the key of each item. Returns `None` when the
+/// dictionary is larger than `max_size`.
+fn build_dictionary<'a, I: Iterator<Item = &'a [u8]>>(
+    values: I,
+    max_size: usize,
+) -> Option<(Vec<u8>, usize, Vec<u32>)> {
+    let mut map = HashMap::<&[u8], u32>::new();
+    let mut dictionary = vec![];
+    let mut keys = Vec::with_capacity(values.size_hint().0);
+    for value in values {
+        let key = match map.get(value) {
+            Some(key) => *key,
+            None => {
+                dictionary.extend_from_slice(value);
+                if dictionary.len() > max_size {
+                    return None;
+                }
+                let key = map.len() as u32;
+                map.insert(value, key);
+                key
+            }
+        };
+        keys.push(key);
+    }
+    Some((dictionary, map.len(), keys))
+}
+
+/// Splits PLAIN-encoded byte arrays into their items (length prefix included).
+fn split_plain_binary(mut values: &[u8]) -> impl Iterator<Item = &[u8]> {
+    std::iter::from_fn(move || {
+        if values.is_empty() {
+            return None;
+        }
+        let length = 4 + get_length(values) as usize;
+        let (value, remaining) = values.split_at(length);
+        values = remaining;
+        Some(value)
+    })
+}
+
+macro_rules! dyn_plain_prim {
+    ($from:ty, $to:ty, $array:expr, $buffer:expr) => {{
+        let array = $array.as_any().downcast_ref().unwrap();
+        primitive_encode_plain::<$from, $to>(array, true, $buffer);
+        Some(std::mem::size_of::<$to>())
+    }};
+}
+
+macro_rules! dyn_prim_statistics {
+    ($from:ty, $to:ty, $array:expr, $descriptor:expr) => {{
+        let array = $array.as_any().downcast_ref().unwrap();
+        primitive_build_statistics::<$from, $to>(array, $descriptor)
+    }};
+}
+
+/// Returns the statistics of a non-dictionary `array` that can be dictionary-encoded.
+fn build_statistics(array: &dyn Array, descriptor: ColumnDescriptor) -> ParquetStatistics {
+    match array.data_type().to_logical_type() {
+        DataType::Int8 => dyn_prim_statistics!(i8, i32, array, descriptor),
+        DataType::Int16 => dyn_prim_statistics!(i16, i32, array, descriptor),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            dyn_prim_statistics!(i32, i32, array, descriptor)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_prim_statistics!(i64, i64, array, descriptor),
+        DataType::UInt8 => dyn_prim_statistics!(u8, i32, array, descriptor),
+        DataType::UInt16 => dyn_prim_statistics!(u16, i32, array, descriptor),
+        DataType::UInt32 => dyn_prim_statistics!(u32, i32, array, descriptor),
+        DataType::UInt64 => dyn_prim_statistics!(u64, i64, array, descriptor),
+        DataType::Float32 => dyn_prim_statistics!(f32, f32, array, descriptor),
+        DataType::Float64 => dyn_prim_statistics!(f64, f64, array, descriptor),
+        DataType::Utf8 => {
+            utf8_build_statistics::<i32>(array.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        DataType::LargeUtf8 => {
+            utf8_build_statistics::<i64>(array.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        DataType::Binary => {
+            binary_build_statistics::<i32>(array.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        DataType::LargeBinary => {
+            binary_build_statistics::<i64>(array.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        // `try_array_to_pages` errors on any other type before statistics are computed
+        _ => unreachable!(),
+    }
+}
+
+/// Dictionary-encodes a non-dictionary `array` into a dictionary page followed by data pages
+/// of (an estimated) at most `max_page_size` bytes each.
+/// Returns `None` when its dictionary exceeds the limits of `dictionary_options`, in which
+/// case the array should be written with [`Encoding::Plain`] instead.
+pub fn try_array_to_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    max_page_size: usize,
+    dictionary_options: DictionaryOptions,
+) -> Result<Option<DynIter<'static, Result<CompressedPage>>>> {
+    // the PLAIN encoding of the non-null values
+    let mut values = vec![];
+    let buffer = &mut values;
+    let size = match array.data_type().to_logical_type() {
+        DataType::Int8 => dyn_plain_prim!(i8, i32, array, buffer),
+        DataType::Int16 => dyn_plain_prim!(i16, i32, array, buffer),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            dyn_plain_prim!(i32, i32, array, buffer)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_plain_prim!(i64, i64, array, buffer),
+        DataType::UInt8 => dyn_plain_prim!(u8, i32, array, buffer),
+        DataType::UInt16 => dyn_plain_prim!(u16, i32, array, buffer),
+        DataType::UInt32 => dyn_plain_prim!(u32, i32, array, buffer),
+        DataType::UInt64 => dyn_plain_prim!(u64, i64, array, buffer),
+        DataType::Float32 => dyn_plain_prim!(f32, f32, array, buffer),
+        DataType::Float64 => dyn_plain_prim!(f64, f64, array, buffer),
+        DataType::Utf8 => {
+            utf8_encode_plain::<i32>(array.as_any().downcast_ref().unwrap(), true, buffer);
+            None
+        }
+        DataType::LargeUtf8 => {
+            utf8_encode_plain::<i64>(array.as_any().downcast_ref().unwrap(), true, buffer);
+            None
+        }
+        DataType::Binary => {
+            binary_encode_plain::<i32>(array.as_any().downcast_ref().unwrap(), true, buffer);
+            None
+        }
+        DataType::LargeBinary => {
+            binary_encode_plain::<i64>(array.as_any().downcast_ref().unwrap(), true, buffer);
+            None
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Dictionary-encoding parquet pages for data type {:?}",
+                other
+            )))
+        }
+    };
+
+    let max_size = dictionary_options.max_size;
+    let dictionary = match size {
+        Some(size) => build_dictionary(values.chunks_exact(size), max_size),
+        None => build_dictionary(split_plain_binary(&values), max_size),
+    };
+    let (dictionary, num_values, keys) = match dictionary {
+        Some(dictionary) => dictionary,
+        None => return Ok(None),
+    };
+    if num_values as f64 > dictionary_options.max_distinct_ratio * keys.len() as f64 {
+        return Ok(None);
+    }
+
+    // one key per slot; the keys of null slots are not written.
+    let keys = if let Some(validity) = array.validity() {
+        let mut keys = keys.into_iter();
+        validity
+            .iter()
+            .map(|is_valid| if is_valid { keys.next().unwrap() } else { 0 })
+            .collect()
+    } else {
+        keys.into()
+    };
+    let keys = PrimitiveArray::<u32>::from_data(DataType::UInt32, keys, array.validity().clone());
+
+    let dict_page = CompressedPage::Dict(CompressedDictPage::new(dictionary, num_values));
+    let data_pages = utils::page_ranges(
+        keys.len(),
+        keys.len() * std::mem::size_of::<u32>(),
+        max_page_size,
+    )
+    .into_iter()
+    .map(|(offset, length)| {
+        let statistics = if options.write_statistics {
+            Some(build_statistics(
+                array.slice(offset, length).as_ref(),
+                descriptor.clone(),
+            ))
+        } else {
+            None
+        };
+        encode_keys(
+            &keys.slice(offset, length),
+            &None,
+            descriptor.clone(),
+            options,
+            statistics,
+        )
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+    let iter = std::iter::once(Ok(dict_page)).chain(data_pages.into_iter().map(Ok));
+    Ok(Some(DynIter::new(Box::new(iter))))
+}
@@ -0,0 +1,226 @@
+//! Encoders of parquet encodings not offered by `parquet2` (or offered only for 32 bit integers).
+use parquet2::encoding::{uleb128, zigzag_leb128};
+
+const BLOCK_SIZE: usize = 128;
+const NUM_MINIBLOCKS: usize = 4;
+const MINIBLOCK_SIZE: usize = BLOCK_SIZE / NUM_MINIBLOCKS;
+
+fn write_uleb128(value: u64, buffer: &mut Vec<u8>) {
+    let mut container = [0u8; 10];
+    let used = uleb128::encode(value, &mut container);
+    buffer.extend_from_slice(&container[..used]);
+}
+
+fn write_zigzag_leb128(value: i64, buffer: &mut Vec<u8>) {
+    let (container, used) = zigzag_leb128::encode(value);
+    buffer.extend_from_slice(&container[..used]);
+}
+
+/// LSB bit-packs a miniblock of `values` with `bit_width` bits each. The miniblock is
+/// padded with zeros to [`MINIBLOCK_SIZE`] values.
+fn bitpack_miniblock(values: &[u64], bit_width: usize, buffer: &mut Vec<u8>) {
+    let start = buffer.len();
+    buffer.resize(start + MINIBLOCK_SIZE * bit_width / 8, 0);
+    let packed = &mut buffer[start..];
+    values.iter().enumerate().for_each(|(index, value)| {
+        let start = index * bit_width;
+        let bytes = ((*value as u128) << (start % 8)).to_le_bytes();
+        packed[start / 8..]
+            .iter_mut()
+            .zip(bytes.iter())
+            .for_each(|(packed, byte)| *packed |= byte);
+    });
+}
+
+/// An integer of a physical type encoded by `DELTA_BINARY_PACKED`, whose deltas are computed
+/// with wrapping arithmetic of its own width, so that they never need more bits than it.
+trait DeltaInteger: Copy + Ord + Default {
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// The bits of `self` as an unsigned integer of the same width
+    fn to_unsigned(self) -> u64;
+
+    fn to_i64(self) -> i64;
+}
+
+impl DeltaInteger for i32 {
+    fn wrapping_sub(self, other: Self) -> Self {
+        i32::wrapping_sub(self, other)
+    }
+
+    fn to_unsigned(self) -> u64 {
+        self as u32 as u64
+    }
+
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl DeltaInteger for i64 {
+    fn wrapping_sub(self, other: Self) -> Self {
+        i64::wrapping_sub(self, other)
+    }
+
+    fn to_unsigned(self) -> u64 {
+        self as u64
+    }
+
+    fn to_i64(self) -> i64 {
+        self
+    }
+}
+
+/// Encodes `length` values of an INT64 column from `iterator` according to
+/// [DELTA_BINARY_PACKED](https://github.com/apache/parquet-format/blob/master/Encodings.md#delta-encoding-delta_binary_packed--5),
+/// using blocks of 128 values split in 4 miniblocks.
+pub fn encode_delta<I: Iterator<Item = i64>>(iterator: I, length: usize, buffer: &mut Vec<u8>) {
+    encode_delta_integer(iterator, length, buffer)
+}
+
+/// Encodes `length` values of an INT32 column from `iterator` according to
+/// `DELTA_BINARY_PACKED`. Deltas are computed with 32 bit arithmetic, so that miniblocks
+/// are at most 32 bits wide, as required by readers of INT32 columns.
+pub fn encode_delta_i32<I: Iterator<Item = i32>>(iterator: I, length: usize, buffer: &mut Vec<u8>) {
+    encode_delta_integer(iterator, length, buffer)
+}
+
+fn encode_delta_integer<T: DeltaInteger, I: Iterator<Item = T>>(
+    mut iterator: I,
+    length: usize,
+    buffer: &mut Vec<u8>,
+) {
+    write_uleb128(BLOCK_SIZE as u64, buffer);
+    write_uleb128(NUM_MINIBLOCKS as u64, buffer);
+    write_uleb128(length as u64, buffer);
+
+    let first = iterator.next().unwrap_or_default();
+    write_zigzag_leb128(first.to_i64(), buffer);
+
+    let mut previous = first;
+    let mut deltas = [T::default(); BLOCK_SIZE];
+    let mut packed = [0u64; MINIBLOCK_SIZE];
+    loop {
+        let mut num_deltas = 0;
+        deltas
+            .iter_mut()
+            .zip(&mut iterator)
+            .for_each(|(delta, value)| {
+                *delta = value.wrapping_sub(previous);
+                previous = value;
+                num_deltas += 1;
+            });
+        if num_deltas == 0 {
+            break;
+        }
+        let deltas = &deltas[..num_deltas];
+
+        // <min delta> <list of bitwidths of miniblocks> <miniblocks>
+        let min_delta = *deltas.iter().min().unwrap();
+        write_zigzag_leb128(min_delta.to_i64(), buffer);
+
+        // the bit widths of unused miniblocks are zero and their bodies are not written
+        let bit_widths = buffer.len();
+        buffer.extend_from_slice(&[0; NUM_MINIBLOCKS]);
+
+        deltas
+            .chunks(MINIBLOCK_SIZE)
+            .enumerate()
+            .for_each(|(miniblock, deltas)| {
+                let packed = &mut packed[..deltas.len()];
+                packed
+                    .iter_mut()
+                    .zip(deltas.iter())
+                    .for_each(|(packed, delta)| {
+                        *packed = delta.wrapping_sub(min_delta).to_unsigned()
+                    });
+                let max = packed.iter().max().copied().unwrap_or_default();
+                let bit_width = 64 - max.leading_zeros() as usize;
+
+                buffer[bit_widths + miniblock] = bit_width as u8;
+                bitpack_miniblock(packed, bit_width, buffer);
+            });
+
+        if num_deltas < BLOCK_SIZE {
+            break;
+        }
+    }
+}
+
+/// Encodes PLAIN-encoded `values` of `size` bytes each as `BYTE_STREAM_SPLIT`,
+/// where the k-th byte of every value is written to the k-th stream.
+pub fn encode_byte_stream_split(values: &[u8], size: usize, buffer: &mut Vec<u8>) {
+    let length = values.len() / size;
+    let start = buffer.len();
+    buffer.resize(start + values.len(), 0);
+    let streams = &mut buffer[start..];
+    values
+        .chunks_exact(size)
+        .enumerate()
+        .for_each(|(index, value)| {
+            value
+                .iter()
+                .enumerate()
+                .for_each(|(stream, byte)| streams[stream * length + index] = *byte)
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_constant() {
+        // header: [128, 1, 4, 5, 2] (block size 128, 4 miniblocks, 5 values, first value 1)
+        // block: min delta 1 <=z> 2, bit widths [0, 0, 0, 0]
+        let mut buffer = vec![];
+        encode_delta(1..=5, 5, &mut buffer);
+        assert_eq!(buffer, vec![128, 1, 4, 5, 2, 2, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn delta_miniblocks() {
+        // 1 + 40 values: the deltas are split in two miniblocks
+        let values = (0..41).map(|x| x * x);
+        let mut buffer = vec![];
+        encode_delta(values, 41, &mut buffer);
+
+        // deltas are 2x + 1 for x in 0..40; min delta 1 <=z> 2
+        // miniblock 1: [0, 2, ..., 62] (6 bits); miniblock 2: [64, ..., 78] (7 bits)
+        assert_eq!(&buffer[..10], &[128, 1, 4, 41, 0, 2, 6, 7, 0, 0]);
+        assert_eq!(buffer.len(), 10 + 32 * 6 / 8 + 32 * 7 / 8);
+        // second value: 0b000010
+        assert_eq!(buffer[10], 0b10000000);
+    }
+
+    #[test]
+    fn delta_i32_extremes() {
+        // 32 bit deltas wrap: [-1, 1]; min delta -1 <=z> 1, values [0, 2] (2 bits)
+        let mut buffer = vec![];
+        encode_delta_i32(
+            vec![i32::MIN, i32::MAX, i32::MIN].into_iter(),
+            3,
+            &mut buffer,
+        );
+        // first value: i32::MIN <=z> 2^32 - 1
+        let mut expected = vec![128, 1, 4, 3, 255, 255, 255, 255, 15];
+        expected.extend_from_slice(&[1, 2, 0, 0, 0]);
+        // the miniblock: 0 (bits 0-1) and 2 (bits 2-3)
+        expected.extend_from_slice(&[0b1000, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn delta_empty() {
+        let mut buffer = vec![];
+        encode_delta(std::iter::empty(), 0, &mut buffer);
+        assert_eq!(buffer, vec![128, 1, 4, 0, 0]);
+    }
+
+    #[test]
+    fn byte_stream_split() {
+        let mut buffer = vec![];
+        encode_byte_stream_split(&[1, 2, 3, 4, 5, 6], 2, &mut buffer);
+        assert_eq!(buffer, vec![1, 3, 5, 2, 4, 6]);
+    }
+}
@@ -0,0 +1,407 @@
+//! Assembly of parquet files from column chunks written by `parquet2`.
+//!
+//! `parquet2` only writes whole files, declares neither where pages are written nor the page
+//! index, and takes the number of rows of a row group from the number of values of its column
+//! chunks, which are more than its rows for repeated columns. Each column chunk is instead written by `parquet2` to a file of its own, in
+//! memory, whose metadata is read back with `parquet2`'s reader. The chunk is then copied to
+//! the file, whose metadata is assembled from the metadata of its chunks and their number of
+//! rows, with the page index and bloom filters written before it.
+use std::{
+    cell::RefCell,
+    io::{Cursor, Result as IoResult, Write},
+    rc::Rc,
+};
+
+use parquet2::{
+    encoding::hybrid_rle::HybridRleDecoder,
+    error::ParquetError,
+    metadata::{ColumnChunkMetaData, ColumnDescriptor, FileMetaData, SchemaDescriptor},
+    page::{split_buffer, CompressedPage, DataPageHeader},
+    read::{decompress, get_page_iterator, levels::get_bit_width, read_metadata},
+    schema::types::ParquetType,
+    statistics::ParquetStatistics,
+    write::{write_file, DynIter, RowGroupIter, WriteOptions},
+};
+use parquet_format_async_temp::{
+    thrift::protocol::{TCompactOutputProtocol, TOutputProtocol},
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader, ColumnChunk,
+    KeyValue, RowGroup, SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use super::page_index::{ChunkSpec, PageSpec};
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::bloom_filter::BloomFilter;
+
+const PARQUET_MAGIC: [u8; 4] = *b"PAR1";
+
+const CREATED_BY: &str = "Arrow2 - Native Rust implementation of Arrow";
+
+/// A [`Write`] to memory whose length can be read while `parquet2` writes to it.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+/// A page pulled by `parquet2`: its offset and, for data pages, its number of values and
+/// statistics.
+type PulledPage = (u64, Option<(usize, Option<ParquetStatistics>)>);
+
+/// Returns `type_` with only the fields on `path`, the names of the fields below it.
+fn prune(type_: &ParquetType, path: &[String]) -> ParquetType {
+    match type_ {
+        ParquetType::GroupType {
+            basic_info,
+            logical_type,
+            converted_type,
+            fields,
+        } => ParquetType::GroupType {
+            basic_info: basic_info.clone(),
+            logical_type: logical_type.clone(),
+            converted_type: converted_type.clone(),
+            fields: fields
+                .iter()
+                .filter(|field| path.first().map(|name| name.as_str()) == Some(field.name()))
+                .map(|field| prune(field, &path[1..]))
+                .collect(),
+        },
+        primitive => primitive.clone(),
+    }
+}
+
+/// Appends the thrift struct serialized by `write` to `bytes` and returns its length.
+fn append_thrift<F>(bytes: &mut Vec<u8>, write: F) -> Result<usize>
+where
+    F: FnOnce(&mut dyn TOutputProtocol) -> parquet_format_async_temp::thrift::Result<usize>,
+{
+    let mut protocol = TCompactOutputProtocol::new(bytes);
+    let length = write(&mut protocol).map_err(ParquetError::from)?;
+    protocol.flush().map_err(ParquetError::from)?;
+    Ok(length)
+}
+
+/// Returns the number of rows of each data page of the chunk `column` of the file `bytes`,
+/// i.e. its number of repetition levels equal to zero.
+fn rows_per_page(bytes: &[u8], column: &ColumnChunkMetaData) -> Result<Vec<usize>> {
+    let descriptor = column.descriptor();
+    let bit_width = get_bit_width(descriptor.max_rep_level());
+    let mut reader = Cursor::new(bytes);
+    let mut buffer = vec![];
+    get_page_iterator(column, &mut reader, None, vec![])?
+        .map(|page| {
+            let num_values = page.as_ref().map(|page| page.num_values()).unwrap_or(0);
+            let page = decompress(page?, &mut buffer)?;
+            let (rep_levels, _, _) = split_buffer(&page, descriptor);
+            let num_rows = HybridRleDecoder::new(rep_levels, bit_width, num_values)
+                .filter(|level| *level == 0)
+                .count();
+            Ok(num_rows)
+        })
+        .collect()
+}
+
+/// Writes the column chunk of `pages` of the column `descriptor` as if it started at `offset`.
+/// Returns its bytes, its metadata and its data pages.
+fn write_column_chunk(
+    descriptor: &ColumnDescriptor,
+    pages: DynIter<Result<CompressedPage>>,
+    options: WriteOptions,
+    offset: u64,
+) -> Result<(Vec<u8>, ColumnChunk, ChunkSpec)> {
+    // a schema of the column alone, so that its levels are the ones of the column
+    let path = descriptor.path_in_schema();
+    let type_ = prune(descriptor.base_type(), path.get(1..).unwrap_or(&[]));
+    let schema = SchemaDescriptor::new("root".to_string(), vec![type_]);
+
+    let buffer = SharedBuffer::default();
+    let pulled = Rc::new(RefCell::new(Vec::<PulledPage>::new()));
+    let pages = {
+        let buffer = buffer.clone();
+        let pulled = pulled.clone();
+        pages.inspect(move |page| {
+            // pages are pulled one at a time, right before being written
+            let offset = buffer.0.borrow().len() as u64;
+            let data = match page {
+                Ok(CompressedPage::Data(page)) => {
+                    let statistics = match page.header() {
+                        DataPageHeader::V1(header) => header.statistics.clone(),
+                        DataPageHeader::V2(header) => header.statistics.clone(),
+                    };
+                    Some((page.num_values(), statistics))
+                }
+                Ok(CompressedPage::Dict(_)) => None,
+                Err(_) => return,
+            };
+            pulled.borrow_mut().push((offset, data));
+        })
+    };
+    let columns: RowGroupIter<ArrowError> = DynIter::new(std::iter::once(Ok(DynIter::new(pages))));
+    write_file(
+        &mut buffer.clone(),
+        std::iter::once(Ok(columns)),
+        schema,
+        options,
+        None,
+        None,
+    )?;
+
+    let mut bytes = std::mem::take(&mut *buffer.0.borrow_mut());
+    let metadata = read_metadata(&mut Cursor::new(&bytes))?;
+    let column = metadata
+        .row_groups
+        .first()
+        .and_then(|row_group| row_group.columns().first())
+        .ok_or_else(|| {
+            ArrowError::ExternalFormat("parquet2 did not write the column chunk".to_string())
+        })?;
+    let (start, length) = column.byte_range();
+    let end = start + length;
+
+    let pulled = std::mem::take(&mut *pulled.borrow_mut());
+    let ends = pulled
+        .iter()
+        .skip(1)
+        .map(|(offset, _)| *offset)
+        .chain(std::iter::once(end))
+        .collect::<Vec<_>>();
+    let is_spanned = pulled.first().map(|(offset, _)| *offset).unwrap_or(end) == start
+        && pulled
+            .iter()
+            .zip(ends.iter())
+            .all(|((offset, _), end)| offset < end)
+        && end as usize <= bytes.len();
+    if !is_spanned {
+        return Err(ArrowError::ExternalFormat(
+            "The column chunk written by parquet2 does not span its pages".to_string(),
+        ));
+    }
+
+    // the rows of repeated columns are the levels that are not repeated
+    let mut num_rows = if descriptor.max_rep_level() > 0 {
+        rows_per_page(&bytes, column)?.into_iter()
+    } else {
+        vec![].into_iter()
+    };
+
+    // the chunk is moved from `start` to `offset`
+    let shift = offset as i64 - start as i64;
+    let pages = pulled
+        .into_iter()
+        .zip(ends)
+        .filter_map(|((page_offset, data), end)| {
+            let (num_values, statistics) = data?;
+            Some(PageSpec {
+                offset: (page_offset as i64 + shift) as u64,
+                length: end - page_offset,
+                num_values,
+                num_rows: num_rows.next().unwrap_or(num_values),
+                statistics,
+            })
+        })
+        .collect();
+
+    let mut chunk = column.clone().into_thrift();
+    chunk.file_offset += shift;
+    if let Some(metadata) = chunk.meta_data.as_mut() {
+        metadata.data_page_offset += shift;
+        metadata.dictionary_page_offset = metadata.dictionary_page_offset.map(|x| x + shift);
+        metadata.index_page_offset = metadata.index_page_offset.map(|x| x + shift);
+    }
+
+    bytes.truncate(end as usize);
+    bytes.drain(..start as usize);
+    Ok((bytes, chunk, ChunkSpec { pages }))
+}
+
+/// Writes a parquet file part by part, returning the bytes of each part so that they can be
+/// written to synchronous and asynchronous writers alike.
+pub(super) struct FileWriter {
+    schema: SchemaDescriptor,
+    options: WriteOptions,
+    page_index: bool,
+    // the number of bytes of the file so far
+    offset: u64,
+    row_groups: Vec<RowGroup>,
+    // the data pages of the chunks of each row group
+    chunks: Vec<Vec<ChunkSpec>>,
+}
+
+impl FileWriter {
+    /// Returns a new [`FileWriter`] of a file of `schema` that writes the page index of its
+    /// multi-page column chunks of flat columns when `page_index` is true.
+    pub fn new(schema: SchemaDescriptor, options: WriteOptions, page_index: bool) -> Self {
+        Self {
+            schema,
+            options,
+            page_index,
+            offset: 0,
+            row_groups: vec![],
+            chunks: vec![],
+        }
+    }
+
+    /// The number of bytes of the file so far.
+    pub fn position(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the bytes that start the file.
+    pub fn start(&mut self) -> Vec<u8> {
+        self.offset += PARQUET_MAGIC.len() as u64;
+        PARQUET_MAGIC.to_vec()
+    }
+
+    /// Returns the bytes of the row group of `columns`, one per column of the schema.
+    pub fn write_row_group(&mut self, columns: RowGroupIter<ArrowError>) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        let mut chunks = vec![];
+        let mut specs = vec![];
+        let mut descriptors = self.schema.columns().iter();
+        for pages in columns {
+            let descriptor = descriptors.next().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The row group has more column chunks than the {} columns of the schema",
+                    self.schema.num_columns()
+                ))
+            })?;
+            let offset = self.offset + bytes.len() as u64;
+            let (chunk_bytes, chunk, spec) =
+                write_column_chunk(descriptor, pages?, self.options, offset)?;
+            bytes.extend_from_slice(&chunk_bytes);
+            chunks.push(chunk);
+            specs.push(spec);
+        }
+        if chunks.len() != self.schema.num_columns() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The row group has {} column chunks but the schema has {} columns",
+                chunks.len(),
+                self.schema.num_columns()
+            )));
+        }
+
+        let num_rows = specs.iter().map(|spec| spec.num_rows()).collect::<Vec<_>>();
+        if num_rows.windows(2).any(|rows| rows[0] != rows[1]) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Every column chunk of a row group must have the same number of rows, but they have {:?}",
+                num_rows
+            )));
+        }
+        let total_byte_size = chunks
+            .iter()
+            .filter_map(|chunk| chunk.meta_data.as_ref())
+            .map(|metadata| metadata.total_compressed_size)
+            .sum();
+        self.row_groups.push(RowGroup::new(
+            chunks,
+            total_byte_size,
+            num_rows.first().copied().unwrap_or(0) as i64,
+            None,
+            None,
+            None,
+            None,
+        ));
+        self.chunks.push(specs);
+        self.offset += bytes.len() as u64;
+        Ok(bytes)
+    }
+
+    /// Returns the bytes that end the file: the `bloom_filters` of each column chunk of each
+    /// row group, the page index (if enabled) and the file's metadata.
+    pub fn end(
+        &mut self,
+        key_value_metadata: Option<Vec<KeyValue>>,
+        bloom_filters: Vec<Vec<Option<BloomFilter>>>,
+    ) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        let mut row_groups = std::mem::take(&mut self.row_groups);
+
+        let filtered = row_groups
+            .iter_mut()
+            .zip(bloom_filters.iter())
+            .flat_map(|(row_group, filters)| row_group.columns.iter_mut().zip(filters.iter()))
+            .filter_map(|(column, filter)| filter.as_ref().map(|filter| (column, filter)));
+        for (column, filter) in filtered {
+            let offset = self.offset + bytes.len() as u64;
+            let bitset = filter.to_bytes();
+            let header = BloomFilterHeader::new(
+                bitset.len() as i32,
+                BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm::new()),
+                BloomFilterHash::XXHASH(XxHash::new()),
+                BloomFilterCompression::UNCOMPRESSED(Uncompressed::new()),
+            );
+            append_thrift(&mut bytes, |protocol| {
+                header.write_to_out_protocol(protocol)
+            })?;
+            bytes.extend_from_slice(&bitset);
+            if let Some(metadata) = column.meta_data.as_mut() {
+                metadata.bloom_filter_offset = Some(offset as i64);
+            }
+        }
+
+        if self.page_index {
+            let is_flat = self
+                .schema
+                .columns()
+                .iter()
+                .map(|column| column.max_rep_level() == 0)
+                .collect::<Vec<_>>();
+            let mut indexed = row_groups
+                .iter_mut()
+                .zip(self.chunks.iter())
+                .flat_map(|(row_group, chunks)| row_group.columns.iter_mut().zip(chunks.iter()))
+                .zip(is_flat.iter().cycle())
+                .filter(|((_, chunk), is_flat)| **is_flat && chunk.pages.len() > 1)
+                .map(|(column, _)| column)
+                .collect::<Vec<_>>();
+
+            // all column indexes are written before all offset indexes
+            for (column, chunk) in indexed.iter_mut() {
+                if let Some(index) = chunk.column_index() {
+                    let offset = self.offset + bytes.len() as u64;
+                    let length = append_thrift(&mut bytes, |protocol| {
+                        index.write_to_out_protocol(protocol)
+                    })?;
+                    column.column_index_offset = Some(offset as i64);
+                    column.column_index_length = Some(length as i32);
+                }
+            }
+            for (column, chunk) in indexed.iter_mut() {
+                let index = chunk.offset_index();
+                let offset = self.offset + bytes.len() as u64;
+                let length =
+                    append_thrift(&mut bytes, |protocol| index.write_to_out_protocol(protocol))?;
+                column.offset_index_offset = Some(offset as i64);
+                column.offset_index_length = Some(length as i32);
+            }
+        }
+
+        let num_rows = row_groups.iter().map(|row_group| row_group.num_rows).sum();
+        let mut metadata = FileMetaData::new(
+            self.options.version.into(),
+            num_rows,
+            Some(CREATED_BY.to_string()),
+            vec![],
+            key_value_metadata,
+            self.schema.clone(),
+            None,
+        )
+        .into_thrift()?;
+        metadata.row_groups = row_groups;
+        let length = append_thrift(&mut bytes, |protocol| {
+            metadata.write_to_out_protocol(protocol)
+        })?;
+        bytes.extend_from_slice(&(length as i32).to_le_bytes());
+        bytes.extend_from_slice(&PARQUET_MAGIC);
+
+        self.offset += bytes.len() as u64;
+        Ok(bytes)
+    }
+}
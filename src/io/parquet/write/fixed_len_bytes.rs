@@ -3,7 +3,7 @@ use parquet2::{
     page::CompressedDataPage, write::WriteOptions,
 };
 
-use super::utils;
+use super::{levels, utils};
 use crate::{
     array::{Array, FixedSizeBinaryArray},
     error::Result,
@@ -57,6 +57,7 @@ pub fn array_to_page(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         uncompressed_page_size,
         0,
@@ -67,3 +68,56 @@ pub fn array_to_page(
         Encoding::Plain,
     )
 }
+
+/// Returns a page of the leaf `array`, which has one slot per level of `nested`,
+/// null whenever the level is not defined.
+pub fn nested_array_to_page(
+    array: &FixedSizeBinaryArray,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+    nested: &levels::Levels,
+) -> Result<CompressedDataPage> {
+    let mut buffer = vec![];
+    levels::write_levels(
+        &mut buffer,
+        &nested.rep,
+        descriptor.max_rep_level(),
+        options.version,
+    )?;
+    let repetition_levels_byte_length = buffer.len();
+
+    levels::write_levels(
+        &mut buffer,
+        &nested.def,
+        descriptor.max_def_level(),
+        options.version,
+    )?;
+    let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
+
+    array
+        .iter()
+        .flatten()
+        .for_each(|x| buffer.extend_from_slice(x));
+
+    let uncompressed_page_size = buffer.len();
+
+    let buffer = utils::compress(
+        buffer,
+        options,
+        definition_levels_byte_length + repetition_levels_byte_length,
+    )?;
+
+    utils::build_plain_page(
+        buffer,
+        array.len(),
+        nested.num_rows(),
+        array.null_count(),
+        uncompressed_page_size,
+        repetition_levels_byte_length,
+        definition_levels_byte_length,
+        None,
+        descriptor,
+        options,
+        Encoding::Plain,
+    )
+}
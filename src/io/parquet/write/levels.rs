@@ -1,171 +1,319 @@
 use parquet2::encoding::hybrid_rle::encode_u32;
+use parquet2::schema::{types::ParquetType, Repetition};
 use parquet2::write::Version;
 
 use crate::{
-    array::Offset,
-    bitmap::{utils::BitmapIter, Bitmap},
-    error::Result,
+    array::{growable::make_growable, Array, FixedSizeListArray, ListArray, StructArray},
+    bitmap::Bitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    io::parquet::read::schema::is_list_item,
 };
 
-pub fn num_values<O: Offset>(offsets: &[O]) -> usize {
-    offsets
-        .windows(2)
-        .map(|w| {
-            let length = w[1].to_usize() - w[0].to_usize();
-            if length == 0 {
-                1
-            } else {
-                length
-            }
-        })
-        .sum()
-}
+use super::utils::get_bit_width;
 
-/// Iterator adapter of parquet / dremel repetition levels
-#[derive(Debug)]
-pub struct RepLevelsIter<'a, O: Offset> {
-    iter: std::slice::Windows<'a, O>,
-    remaining: usize,
-    length: usize,
-    total_size: usize,
+/// The offsets of a list, absolute to its values.
+#[derive(Debug, Clone, Copy)]
+pub enum ListOffsets<'a> {
+    Small(&'a [i32]),
+    Large(&'a [i64]),
+    Fixed(usize),
 }
 
-impl<'a, O: Offset> RepLevelsIter<'a, O> {
-    pub fn new(offsets: &'a [O]) -> Self {
-        let total_size = num_values(offsets);
-
-        Self {
-            iter: offsets.windows(2),
-            remaining: 0,
-            length: 0,
-            total_size,
+impl ListOffsets<'_> {
+    /// The range of values of the list at `index`.
+    #[inline]
+    fn range(&self, index: usize) -> (usize, usize) {
+        match self {
+            Self::Small(offsets) => (offsets[index] as usize, offsets[index + 1] as usize),
+            Self::Large(offsets) => (offsets[index] as usize, offsets[index + 1] as usize),
+            Self::Fixed(size) => (index * size, (index + 1) * size),
         }
     }
 }
 
-impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some(w) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(0);
-                }
-            } else {
-                return None;
+/// A level of nesting between an [`Array`] and one of its leaves.
+#[derive(Debug, Clone)]
+pub enum Nested<'a> {
+    List {
+        is_optional: bool,
+        offsets: ListOffsets<'a>,
+        validity: &'a Option<Bitmap>,
+    },
+    Struct {
+        is_optional: bool,
+        validity: &'a Option<Bitmap>,
+    },
+}
+
+/// Returns the leaves of `array`, whose parquet type is `type_`, in the order of parquet's
+/// columns (depth-first), each with the levels of nesting from `array` to it.
+pub fn to_leaves<'a>(
+    array: &'a dyn Array,
+    type_: &ParquetType,
+    nested: Vec<Nested<'a>>,
+    leaves: &mut Vec<(Vec<Nested<'a>>, &'a dyn Array)>,
+) -> Result<()> {
+    // the items of 2-level lists are repeated, yet not optional
+    let is_optional = *type_.get_basic_info().repetition() == Repetition::Optional;
+    match array.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = ListOffsets::Small(array.offsets());
+            list_to_leaves(
+                array.values().as_ref(),
+                type_,
+                offsets,
+                array.validity(),
+                nested,
+                leaves,
+            )
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = ListOffsets::Large(array.offsets());
+            list_to_leaves(
+                array.values().as_ref(),
+                type_,
+                offsets,
+                array.validity(),
+                nested,
+                leaves,
+            )
+        }
+        DataType::FixedSizeList(_, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let offsets = ListOffsets::Fixed(*size as usize);
+            list_to_leaves(
+                array.values().as_ref(),
+                type_,
+                offsets,
+                array.validity(),
+                nested,
+                leaves,
+            )
+        }
+        DataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = group_fields(type_)?;
+            if fields.len() != array.values().len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The parquet type {} does not match the datatype {:?}",
+                    type_.name(),
+                    array.data_type()
+                )));
             }
+            let mut nested = nested;
+            nested.push(Nested::Struct {
+                is_optional,
+                validity: array.validity(),
+            });
+            array
+                .values()
+                .iter()
+                .zip(fields.iter())
+                .try_for_each(|(array, type_)| {
+                    to_leaves(array.as_ref(), type_, nested.clone(), leaves)
+                })
+        }
+        _ => {
+            leaves.push((nested, array));
+            Ok(())
         }
-        let old = self.remaining;
-        self.remaining += 1;
-        self.total_size -= 1;
-        Some((old >= 1) as u32)
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
     }
 }
 
-/// Iterator adapter of parquet / dremel definition levels
-pub struct DefLevelsIter<'a, O: Offset> {
-    iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
-    primitive_validity: Option<BitmapIter<'a>>,
-    remaining: usize,
-    is_valid: bool,
-    length: usize,
-    total_size: usize,
+fn group_fields(type_: &ParquetType) -> Result<&[ParquetType]> {
+    match type_ {
+        ParquetType::GroupType { fields, .. } => Ok(fields),
+        ParquetType::PrimitiveType { .. } => Err(ArrowError::InvalidArgumentError(format!(
+            "The parquet type {} of a nested array must be a group",
+            type_.name()
+        ))),
+    }
 }
 
-impl<'a, O: Offset> DefLevelsIter<'a, O> {
-    pub fn new(
-        offsets: &'a [O],
-        validity: &'a Option<Bitmap>,
-        primitive_validity: &'a Option<Bitmap>,
-    ) -> Self {
-        let total_size = num_values(offsets);
-
-        let primitive_validity = primitive_validity.as_ref().map(|x| x.iter());
-
-        let validity = validity
-            .as_ref()
-            .map(|x| Box::new(x.iter()) as Box<dyn Iterator<Item = bool>>)
-            .unwrap_or_else(|| {
-                Box::new(std::iter::repeat(true).take(offsets.len() - 1))
-                    as Box<dyn Iterator<Item = bool>>
-            });
-
-        Self {
-            iter: offsets.windows(2).zip(validity),
-            primitive_validity,
-            remaining: 0,
-            length: 0,
-            is_valid: false,
-            total_size,
+fn list_to_leaves<'a>(
+    values: &'a dyn Array,
+    type_: &ParquetType,
+    offsets: ListOffsets<'a>,
+    validity: &'a Option<Bitmap>,
+    mut nested: Vec<Nested<'a>>,
+    leaves: &mut Vec<(Vec<Nested<'a>>, &'a dyn Array)>,
+) -> Result<()> {
+    nested.push(Nested::List {
+        is_optional: *type_.get_basic_info().repetition() == Repetition::Optional,
+        offsets,
+        validity,
+    });
+    let repeated = group_fields(type_)?.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The parquet list {} must have a child",
+            type_.name()
+        ))
+    })?;
+    let item = if is_list_item(repeated, type_.name()) {
+        // 2-level list: the repeated field is the item, which cannot be null
+        if values.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The parquet list {} has the legacy 2-level layout, which cannot have null items",
+                type_.name()
+            )));
         }
-    }
+        repeated
+    } else {
+        // 3-level list: the repeated group wraps the item
+        group_fields(repeated)?.first().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The parquet list {} must have an item",
+                type_.name()
+            ))
+        })?
+    };
+    to_leaves(values, item, nested, leaves)
 }
 
-impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some((w, is_valid)) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                self.is_valid = is_valid;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(is_valid as u32);
-                }
-            } else {
-                return None;
-            }
-        }
-        self.remaining += 1;
-        self.total_size -= 1;
-
-        let (base_def, p_is_valid) = self
-            .primitive_validity
-            .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
-        Some(def_)
-    }
+/// The repetition and definition levels of a leaf column.
+#[derive(Debug, Default, PartialEq)]
+pub struct Levels {
+    pub rep: Vec<u32>,
+    pub def: Vec<u32>,
+    /// The index of the leaf's value of each level, or `None` when it is not defined.
+    pub indices: Vec<Option<usize>>,
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
+impl Levels {
+    /// The number of top-level slots (rows) of the levels, whose repetition level is zero.
+    pub fn num_rows(&self) -> usize {
+        self.rep.iter().filter(|rep| **rep == 0).count()
     }
 }
 
-#[derive(Debug)]
-pub struct NestedInfo<'a, O: Offset> {
+/// Computes the [`Levels`] of a leaf of `length` top-level slots nested by `nested`,
+/// whose values have `validity` and are optional when `is_optional`.
+pub fn compute_levels(
+    nested: &[Nested],
+    validity: &Option<Bitmap>,
     is_optional: bool,
-    offsets: &'a [O],
-    validity: &'a Option<Bitmap>,
+    length: usize,
+) -> Levels {
+    let mut levels = Levels::default();
+    (0..length).for_each(|index| {
+        extend_levels(nested, validity, is_optional, index, 0, 0, 0, &mut levels)
+    });
+    levels
 }
 
-impl<'a, O: Offset> NestedInfo<'a, O> {
-    pub fn new(offsets: &'a [O], validity: &'a Option<Bitmap>, is_optional: bool) -> Self {
-        Self {
-            is_optional,
+#[allow(clippy::too_many_arguments)]
+fn extend_levels(
+    nested: &[Nested],
+    validity: &Option<Bitmap>,
+    is_optional: bool,
+    index: usize,
+    rep: u32,
+    def: u32,
+    rep_depth: u32,
+    levels: &mut Levels,
+) {
+    let is_valid = |validity: &Option<Bitmap>| {
+        validity
+            .as_ref()
+            .map(|validity| validity.get_bit(index))
+            .unwrap_or(true)
+    };
+    let mut push = |def: u32, index: Option<usize>| {
+        levels.rep.push(rep);
+        levels.def.push(def);
+        levels.indices.push(index);
+    };
+
+    match nested.first() {
+        None => {
+            if !is_optional {
+                push(def, Some(index))
+            } else if is_valid(validity) {
+                push(def + 1, Some(index))
+            } else {
+                push(def, None)
+            }
+        }
+        Some(Nested::Struct {
+            is_optional: struct_is_optional,
+            validity: struct_validity,
+        }) => {
+            if !is_valid(struct_validity) {
+                push(def, None);
+                return;
+            }
+            let def = def + *struct_is_optional as u32;
+            extend_levels(
+                &nested[1..],
+                validity,
+                is_optional,
+                index,
+                rep,
+                def,
+                rep_depth,
+                levels,
+            )
+        }
+        Some(Nested::List {
+            is_optional: list_is_optional,
             offsets,
-            validity,
+            validity: list_validity,
+        }) => {
+            if !is_valid(list_validity) {
+                push(def, None);
+                return;
+            }
+            let def = def + *list_is_optional as u32;
+            let (start, end) = offsets.range(index);
+            if start == end {
+                push(def, None);
+                return;
+            }
+            let rep_depth = rep_depth + 1;
+            (start..end).for_each(|child| {
+                let rep = if child == start { rep } else { rep_depth };
+                extend_levels(
+                    &nested[1..],
+                    validity,
+                    is_optional,
+                    child,
+                    rep,
+                    def + 1,
+                    rep_depth,
+                    levels,
+                )
+            })
         }
     }
+}
 
-    pub fn offsets(&self) -> &'a [O] {
-        self.offsets
+/// Gathers the values of `array` at `indices` into a new array whose slots are null
+/// wherever `indices` is `None`.
+pub fn gather(array: &dyn Array, indices: &[Option<usize>]) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], true, indices.len());
+
+    let mut iter = indices.iter().peekable();
+    while let Some(index) = iter.next() {
+        match index {
+            Some(start) => {
+                // extend contiguous runs at once
+                let mut len = 1;
+                while let Some(Some(next)) = iter.peek() {
+                    if *next != start + len {
+                        break;
+                    }
+                    len += 1;
+                    iter.next();
+                }
+                growable.extend(0, *start, len);
+            }
+            None => growable.extend_validity(1),
+        }
     }
+    growable.as_box()
 }
 
 fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
@@ -186,52 +334,28 @@ fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
     Ok(())
 }
 
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_rep_levels<O: Offset>(
+/// writes the levels to a `Vec<u8>`, encoded with a bit width of `max_level`.
+/// Nothing is written when `max_level` is zero.
+pub fn write_levels(
     buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
+    levels: &[u32],
+    max_level: i16,
     version: Version,
 ) -> Result<()> {
-    let num_bits = 1; // todo: compute this
-
-    match version {
-        Version::V1 => {
-            write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = RepLevelsIter::new(nested.offsets);
-                encode_u32(buffer, levels, num_bits)?;
-                Ok(())
-            })?;
-        }
-        Version::V2 => {
-            let levels = RepLevelsIter::new(nested.offsets);
-
-            encode_u32(buffer, levels, num_bits)?;
-        }
+    if max_level == 0 {
+        return Ok(());
     }
-
-    Ok(())
-}
-
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_def_levels<O: Offset>(
-    buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
-    validity: &Option<Bitmap>,
-    version: Version,
-) -> Result<()> {
-    let num_bits = 2;
+    let num_bits = get_bit_width(max_level as u64) as u8;
 
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-                encode_u32(buffer, levels, num_bits)?;
+                encode_u32(buffer, levels.iter().copied(), num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-            encode_u32(buffer, levels, num_bits)?;
+            encode_u32(buffer, levels.iter().copied(), num_bits)?;
         }
     }
 
@@ -240,34 +364,113 @@ pub fn write_def_levels<O: Offset>(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::*;
+    use crate::array::{Int32Array, Int64Array};
+    use crate::buffer::Buffer;
+    use crate::datatypes::Field;
+
+    fn list() -> ListArray<i32> {
+        let offsets = Buffer::from([0, 2, 2, 5, 8, 8, 11, 11, 12]);
+        let validity = Some(Bitmap::from([
+            true, false, true, true, true, true, false, true,
+        ]));
+        let values = Arc::new(Int64Array::from(&[
+            Some(0),
+            Some(1), //[0, 1]
+            Some(2),
+            None,
+            Some(3), //[2, None, 3]
+            Some(4),
+            Some(5),
+            Some(6), //[4, 5, 6]
+            Some(7),
+            Some(8),
+            Some(9),  //[7, 8, 9]
+            Some(10), //[10]
+        ]));
+        let data_type = DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+        ListArray::<i32>::from_data(data_type, offsets, values, validity)
+    }
+
+    fn list_levels(array: &ListArray<i32>) -> Levels {
+        let nested = vec![Nested::List {
+            is_optional: true,
+            offsets: ListOffsets::Small(array.offsets()),
+            validity: array.validity(),
+        }];
+        compute_levels(&nested, array.values().validity(), true, array.len())
+    }
 
     #[test]
     fn test_rep_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
         let expected = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
 
-        let result = RepLevelsIter::new(offsets).collect::<Vec<_>>();
-        assert_eq!(result, expected)
+        let result = list_levels(&list());
+        assert_eq!(result.rep, expected)
+    }
+
+    #[test]
+    fn test_num_rows() {
+        let array = list();
+        assert_eq!(list_levels(&array).num_rows(), array.len())
     }
 
     #[test]
     fn test_def_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
-        let validity = Some(Bitmap::from([
-            true, false, true, true, true, true, false, true,
-        ]));
-        let primitive_validity = Some(Bitmap::from([
-            true, true, //[0, 1]
-            true, false, true, //[2, None, 3]
-            true, true, true, //[4, 5, 6]
-            true, true, true, //[7, 8, 9]
-            true, //[10]
-        ]));
         let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
 
-        let result =
-            DefLevelsIter::new(offsets, &validity, &primitive_validity).collect::<Vec<_>>();
-        assert_eq!(result, expected)
+        let result = list_levels(&list());
+        assert_eq!(result.def, expected)
+    }
+
+    #[test]
+    fn test_indices() {
+        let expected = vec![
+            Some(0),
+            Some(1),
+            None,
+            Some(2),
+            None,
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            None,
+            Some(8),
+            Some(9),
+            Some(10),
+            None,
+            Some(11),
+        ];
+
+        let result = list_levels(&list());
+        assert_eq!(result.indices, expected)
+    }
+
+    #[test]
+    fn test_struct_levels() {
+        // {a: 1}, None, {a: None}
+        let values = Int32Array::from(&[Some(1), Some(2), None]);
+        let validity = Some(Bitmap::from([true, false, true]));
+        let nested = vec![Nested::Struct {
+            is_optional: true,
+            validity: &validity,
+        }];
+
+        let result = compute_levels(&nested, values.validity(), true, 3);
+        assert_eq!(result.rep, vec![0, 0, 0]);
+        assert_eq!(result.def, vec![2, 0, 1]);
+        assert_eq!(result.indices, vec![Some(0), None, None]);
+    }
+
+    #[test]
+    fn test_gather() {
+        let array = Int32Array::from_slice(&[1, 2, 3, 4]);
+
+        let result = gather(&array, &[Some(0), Some(1), None, Some(3)]);
+        let expected = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+        assert_eq!(expected, result.as_ref());
     }
 }
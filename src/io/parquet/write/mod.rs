@@ -2,8 +2,11 @@
 mod binary;
 mod boolean;
 mod dictionary;
+mod encoding;
+mod file;
 mod fixed_len_bytes;
 mod levels;
+mod page_index;
 mod primitive;
 mod record_batch;
 mod schema;
@@ -12,18 +15,19 @@ mod utils;
 
 pub mod stream;
 
+use std::convert::TryFrom;
 use std::sync::Arc;
 
-use crate::array::*;
-use crate::bitmap::Bitmap;
+use parquet2::schema::{types::PhysicalType, Repetition};
+
+use crate::array::{growable::make_growable, *};
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::io::parquet::read::is_type_nullable;
-use crate::io::parquet::write::levels::NestedInfo;
 use crate::types::days_ms;
 use crate::types::NativeType;
 
+pub use dictionary::DictionaryOptions;
 pub use parquet2::{
     compression::Compression,
     encoding::Encoding,
@@ -32,9 +36,9 @@ pub use parquet2::{
     schema::types::ParquetType,
     write::{write_file as parquet_write_file, DynIter, RowGroupIter, Version, WriteOptions},
 };
-pub use record_batch::RowGroupIterator;
+pub use record_batch::{BloomFilters, RowGroupIterator, SizeOptions};
 use schema::schema_to_metadata_key;
-pub use schema::to_parquet_type;
+pub use schema::{to_parquet_type, to_parquet_type_with, CompatibilityOptions};
 
 pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
@@ -49,15 +53,93 @@ pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
 
 /// Creates a parquet [`SchemaDescriptor`] from a [`Schema`].
 pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
+    to_parquet_schema_with(schema, CompatibilityOptions::default())
+}
+
+/// Creates a parquet [`SchemaDescriptor`] from a [`Schema`] according to `compatibility`.
+/// Arrays are written according to the parquet types of their [`ColumnDescriptor`]s.
+pub fn to_parquet_schema_with(
+    schema: &Schema,
+    compatibility: CompatibilityOptions,
+) -> Result<SchemaDescriptor> {
     let parquet_types = schema
         .fields()
         .iter()
-        .map(to_parquet_type)
+        .map(|field| to_parquet_type_with(field, compatibility))
         .collect::<Result<Vec<_>>>()?;
     Ok(SchemaDescriptor::new("root".to_string(), parquet_types))
 }
 
-/// Writes
+fn is_int96(descriptor: &ColumnDescriptor) -> bool {
+    matches!(descriptor.physical_type(), PhysicalType::Int96)
+}
+
+/// Converts timestamps in `time_unit` to the 12 bytes of INT96 timestamps: the nanoseconds
+/// of the day followed by the julian day.
+/// # Errors
+/// This function errors iff a timestamp's julian day is out of the range of `u32`.
+fn timestamp_to_int96(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+) -> Result<FixedSizeBinaryArray> {
+    const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+    const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+    let factor = match time_unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    let units_per_day = NANOS_PER_DAY / factor;
+
+    let mut values = MutableBuffer::<u8>::with_capacity(12 * array.len());
+    for (index, x) in array.values().iter().enumerate() {
+        // the values of null slots are undefined
+        if array.is_null(index) {
+            values.extend_from_slice(&[0; 12]);
+            continue;
+        }
+        // the day is split before scaling, so that the nanoseconds of the day do not overflow
+        let day =
+            u32::try_from(x.div_euclid(units_per_day) + JULIAN_DAY_OF_EPOCH).map_err(|_| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The timestamp {} ({:?}) is out of the range of parquet's INT96 timestamps",
+                    x, time_unit
+                ))
+            })?;
+        let nanos = x.rem_euclid(units_per_day) * factor;
+        values.extend_from_slice(&nanos.to_le_bytes());
+        values.extend_from_slice(&day.to_le_bytes());
+    }
+    Ok(FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(12),
+        values.into(),
+        array.validity().clone(),
+    ))
+}
+
+/// Returns the values of the dictionary `array` at its keys, i.e. the array it encodes.
+fn unpack_dictionary<K: DictionaryKey>(array: &DictionaryArray<K>) -> Arc<dyn Array> {
+    let mut growable = make_growable(&[array.values().as_ref()], true, array.len());
+    array.keys().iter().for_each(|key| match key {
+        Some(key) => growable.extend(0, key.to_usize().unwrap(), 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_arc()
+}
+
+/// Options of the structures written to a parquet file besides its row groups.
+#[derive(Debug, Clone, Default)]
+pub struct FileOptions {
+    /// Whether to write the [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md)
+    /// of the column chunks of non-repeated columns with more than one data page.
+    pub page_index: bool,
+    /// The bloom filters built while the row groups are consumed
+    /// (see [`RowGroupIterator::bloom_filters`]).
+    pub bloom_filters: BloomFilters,
+}
+
+/// Writes a parquet file of `row_groups` to `writer` and returns the number of bytes written.
 pub fn write_file<'a, W, I>(
     writer: &mut W,
     row_groups: I,
@@ -66,6 +148,61 @@ pub fn write_file<'a, W, I>(
     options: WriteOptions,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Result<u64>
+where
+    W: std::io::Write + std::io::Seek,
+    I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
+{
+    write_file_with(
+        writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        key_value_metadata,
+        &FileOptions::default(),
+    )
+}
+
+/// Writes a parquet file like [`write_file`], alongside the `bloom_filters` built while
+/// `row_groups` is consumed (see [`RowGroupIterator::bloom_filters`]).
+pub fn write_file_with_bloom_filters<'a, W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+    bloom_filters: &BloomFilters,
+) -> Result<u64>
+where
+    W: std::io::Write + std::io::Seek,
+    I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
+{
+    let file_options = FileOptions {
+        page_index: false,
+        bloom_filters: bloom_filters.clone(),
+    };
+    write_file_with(
+        writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        key_value_metadata,
+        &file_options,
+    )
+}
+
+/// Writes a parquet file like [`write_file`], alongside the structures of `file_options`.
+pub fn write_file_with<'a, W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+    file_options: &FileOptions,
+) -> Result<u64>
 where
     W: std::io::Write + std::io::Seek,
     I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
@@ -77,30 +214,75 @@ where
         })
         .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
 
-    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
-    Ok(parquet_write_file(
-        writer,
-        row_groups,
-        parquet_schema,
-        options,
-        created_by,
-        key_value_metadata,
-    )?)
+    let mut file = file::FileWriter::new(parquet_schema, options, file_options.page_index);
+    writer.write_all(&file.start())?;
+    for row_group in row_groups {
+        writer.write_all(&file.write_row_group(row_group?)?)?;
+    }
+    let bloom_filters = file_options.bloom_filters.take();
+    writer.write_all(&file.end(key_value_metadata, bloom_filters)?)?;
+    Ok(file.position())
 }
 
 /// Checks whether the `data_type` can be encoded as `encoding`.
 /// Note that this is whether this implementation supports it, which is a subset of
 /// what the parquet spec allows.
+/// Non-dictionary arrays that can be dictionary-encoded are written with
+/// [`Encoding::Plain`] when their dictionary exceeds the limits of [`DictionaryOptions`].
 pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
     matches!(
-        (encoding, data_type),
+        (encoding, data_type.to_logical_type()),
         (Encoding::Plain, _)
             | (
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
-            | (Encoding::RleDictionary, DataType::Dictionary(_, _))
-            | (Encoding::PlainDictionary, DataType::Dictionary(_, _))
+            | (
+                Encoding::DeltaBinaryPacked,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Date32
+                    | DataType::Date64
+                    | DataType::Time32(_)
+                    | DataType::Time64(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Duration(_),
+            )
+            | (
+                Encoding::ByteStreamSplit,
+                DataType::Float32 | DataType::Float64
+            )
+            | (Encoding::Rle, DataType::Boolean)
+            | (
+                Encoding::RleDictionary | Encoding::PlainDictionary,
+                DataType::Dictionary(_, _)
+                    | DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Date32
+                    | DataType::Date64
+                    | DataType::Time32(_)
+                    | DataType::Time64(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Duration(_)
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::Utf8
+                    | DataType::LargeUtf8,
+            )
     )
 }
 
@@ -111,6 +293,39 @@ pub fn array_to_pages(
     options: WriteOptions,
     encoding: Encoding,
 ) -> Result<DynIter<'static, Result<CompressedPage>>> {
+    array_to_sized_pages(
+        array,
+        descriptor,
+        options,
+        encoding,
+        usize::MAX,
+        DictionaryOptions::default(),
+    )
+}
+
+/// Returns an iterator of compressed pages, whose data pages hold (an estimate of)
+/// at most `max_page_size` bytes of values each. Pages have at least one slot.
+/// Non-dictionary arrays written with dictionary encoding are dictionary-encoded
+/// according to `dictionary_options`.
+pub fn array_to_sized_pages(
+    array: Arc<dyn Array>,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    max_page_size: usize,
+    dictionary_options: DictionaryOptions,
+) -> Result<DynIter<'static, Result<CompressedPage>>> {
+    // INT96 values are only written PLAIN
+    let (array, encoding) = match array.data_type() {
+        DataType::Dictionary(key_type, _) if is_int96(&descriptor) => (
+            with_match_dictionary_key_type!(key_type.as_ref(), |$T| {
+                unpack_dictionary::<$T>(array.as_any().downcast_ref().unwrap())
+            }),
+            Encoding::Plain,
+        ),
+        _ if is_int96(&descriptor) => (array, Encoding::Plain),
+        _ => (array, encoding),
+    };
     match array.data_type() {
         DataType::Dictionary(key_type, _) => {
             with_match_dictionary_key_type!(key_type.as_ref(), |$T| {
@@ -119,11 +334,46 @@ pub fn array_to_pages(
                     descriptor,
                     options,
                     encoding,
+                    max_page_size,
                 )
             })
         }
-        _ => array_to_page(array.as_ref(), descriptor, options, encoding)
-            .map(|page| DynIter::new(std::iter::once(Ok(page)))),
+        _ => {
+            let encoding = match encoding {
+                Encoding::PlainDictionary | Encoding::RleDictionary
+                    if can_encode(array.data_type(), encoding) =>
+                {
+                    if let Some(pages) = dictionary::try_array_to_pages(
+                        array.as_ref(),
+                        descriptor.clone(),
+                        options,
+                        max_page_size,
+                        dictionary_options,
+                    )? {
+                        return Ok(pages);
+                    }
+                    // the dictionary is not worth it: fall back to plain
+                    Encoding::Plain
+                }
+                _ => encoding,
+            };
+            let pages = utils::page_ranges(
+                array.len(),
+                utils::estimated_size(array.as_ref()),
+                max_page_size,
+            )
+            .into_iter()
+            .map(|(offset, length)| {
+                array_to_page(
+                    array.slice(offset, length).as_ref(),
+                    descriptor.clone(),
+                    options,
+                    encoding,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+            Ok(DynIter::new(pages.into_iter().map(Ok)))
+        }
     }
 }
 
@@ -142,47 +392,61 @@ pub fn array_to_page(
     }
 
     match data_type.to_logical_type() {
-        DataType::Boolean => {
-            boolean::array_to_page(array.as_any().downcast_ref().unwrap(), options, descriptor)
-        }
+        DataType::Boolean => boolean::array_to_page(
+            array.as_any().downcast_ref().unwrap(),
+            options,
+            descriptor,
+            encoding,
+        ),
         // casts below MUST match the casts done at the metadata (field -> parquet type).
         DataType::UInt8 => primitive::array_to_page::<u8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 descriptor,
+                encoding,
             )
         }
+        DataType::Timestamp(time_unit, _) if is_int96(&descriptor) => {
+            let array = timestamp_to_int96(array.as_any().downcast_ref().unwrap(), *time_unit)?;
+            fixed_len_bytes::array_to_page(&array, options, descriptor)
+        }
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
@@ -191,16 +455,19 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -228,7 +495,7 @@ pub fn array_to_page(
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+            primitive::array_to_page::<i32, i32>(&array, options, descriptor, Encoding::Plain)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let array = array
@@ -285,7 +552,7 @@ pub fn array_to_page(
                     values,
                     array.validity().clone(),
                 );
-                primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+                primitive::array_to_page::<i32, i32>(&array, options, descriptor, Encoding::Plain)
             } else if precision <= 18 {
                 let values = array.values().iter().map(|x| *x as i64);
                 let values = Buffer::from_trusted_len_iter(values);
@@ -294,7 +561,7 @@ pub fn array_to_page(
                     values,
                     array.validity().clone(),
                 );
-                primitive::array_to_page::<i64, i64>(&array, options, descriptor)
+                primitive::array_to_page::<i64, i64>(&array, options, descriptor, Encoding::Plain)
             } else {
                 let size = decimal_length_from_precision(precision);
 
@@ -312,9 +579,10 @@ pub fn array_to_page(
                 fixed_len_bytes::array_to_page(&array, options, descriptor)
             }
         }
-        DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
-            nested_array_to_page(array, descriptor, options)
-        }
+        DataType::FixedSizeList(_, _)
+        | DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::Struct(_) => nested_array_to_page(array, descriptor, options),
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing parquet V1 pages for data type {:?}",
             other
@@ -324,138 +592,190 @@ pub fn array_to_page(
 }
 
 macro_rules! dyn_nested_prim {
-    ($from:ty, $to:ty, $offset:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
         let values = $values.as_any().downcast_ref().unwrap();
 
-        primitive::nested_array_to_page::<$from, $to, $offset>(
-            values,
-            $options,
-            $descriptor,
-            $nested,
-        )
+        primitive::nested_array_to_page::<$from, $to>(values, $options, $descriptor, $nested)
     }};
 }
 
-fn list_array_to_page<O: Offset>(
-    offsets: &[O],
-    validity: &Option<Bitmap>,
+/// Returns a page of the leaf `values`, which has one slot per level of `nested`.
+fn leaf_to_page(
     values: &dyn Array,
+    nested: &levels::Levels,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<CompressedDataPage> {
     use DataType::*;
-    let is_optional = is_type_nullable(descriptor.type_());
-    let nested = NestedInfo::new(offsets, validity, is_optional);
-
-    match values.data_type() {
+    match values.data_type().to_logical_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
-            boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
+            boolean::nested_array_to_page(values, options, descriptor, nested)
         }
-        UInt8 => dyn_nested_prim!(u8, i32, O, values, nested, descriptor, options),
-        UInt16 => dyn_nested_prim!(u16, i32, O, values, nested, descriptor, options),
-        UInt32 => dyn_nested_prim!(u32, i32, O, values, nested, descriptor, options),
-        UInt64 => dyn_nested_prim!(u64, i64, O, values, nested, descriptor, options),
+        UInt8 => dyn_nested_prim!(u8, i32, values, nested, descriptor, options),
+        UInt16 => dyn_nested_prim!(u16, i32, values, nested, descriptor, options),
+        UInt32 => dyn_nested_prim!(u32, i32, values, nested, descriptor, options),
+        UInt64 => dyn_nested_prim!(u64, i64, values, nested, descriptor, options),
 
-        Int8 => dyn_nested_prim!(i8, i32, O, values, nested, descriptor, options),
-        Int16 => dyn_nested_prim!(i16, i32, O, values, nested, descriptor, options),
+        Int8 => dyn_nested_prim!(i8, i32, values, nested, descriptor, options),
+        Int16 => dyn_nested_prim!(i16, i32, values, nested, descriptor, options),
         Int32 | Date32 | Time32(_) => {
-            dyn_nested_prim!(i32, i32, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i32, i32, values, nested, descriptor, options)
+        }
+        Timestamp(time_unit, _) if is_int96(&descriptor) => {
+            let values = timestamp_to_int96(values.as_any().downcast_ref().unwrap(), *time_unit)?;
+            fixed_len_bytes::nested_array_to_page(&values, options, descriptor, nested)
         }
         Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
-            dyn_nested_prim!(i64, i64, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i64, i64, values, nested, descriptor, options)
         }
 
-        Float32 => dyn_nested_prim!(f32, f32, O, values, nested, descriptor, options),
-        Float64 => dyn_nested_prim!(f64, f64, O, values, nested, descriptor, options),
+        Float32 => dyn_nested_prim!(f32, f32, values, nested, descriptor, options),
+        Float64 => dyn_nested_prim!(f64, f64, values, nested, descriptor, options),
 
         Utf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeUtf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
         Binary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeBinary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
-        _ => todo!(),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Writing nested parquet pages for data type {:?}",
+            other
+        ))),
     }
 }
 
+/// Returns one page per leaf of the nested `array`, whose parquet type is `type_`.
+/// `descriptors` are the descriptors of each of its leaves.
+fn nested_array_to_pages(
+    array: &dyn Array,
+    type_: &ParquetType,
+    descriptors: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+) -> Result<Vec<CompressedDataPage>> {
+    let mut leaves = vec![];
+    levels::to_leaves(array, type_, vec![], &mut leaves)?;
+    if leaves.len() != descriptors.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The datatype {:?} has {} leaves but {} column descriptors were provided",
+            array.data_type(),
+            leaves.len(),
+            descriptors.len()
+        )));
+    }
+
+    leaves
+        .into_iter()
+        .zip(descriptors)
+        .map(|((nested, values), descriptor)| {
+            // the items of 2-level lists are repeated, yet not optional
+            let is_optional =
+                *descriptor.type_().get_basic_info().repetition() == Repetition::Optional;
+            let nested =
+                levels::compute_levels(&nested, values.validity(), is_optional, array.len());
+            let values = levels::gather(values, &nested.indices);
+            leaf_to_page(values.as_ref(), &nested, descriptor, options)
+        })
+        .collect()
+}
+
 fn nested_array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<CompressedDataPage> {
-    match array.data_type() {
-        DataType::List(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::LargeList(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
+    let type_ = descriptor.base_type().clone();
+    let mut leaves = vec![];
+    levels::to_leaves(array, &type_, vec![], &mut leaves)?;
+    if leaves.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A page holds a single parquet column, but the datatype {:?} has {} leaves; use `array_to_columns` to write one column per leaf",
+            array.data_type(),
+            leaves.len()
+        )));
+    }
+    let mut pages = nested_array_to_pages(array, &type_, vec![descriptor], options)?;
+    Ok(pages.pop().unwrap())
+}
+
+/// Returns an iterator of compressed pages per parquet column (leaf) of `array`.
+/// `descriptors` are the descriptors of the leaves of `array`, in the order of the parquet schema.
+/// Leaves of nested arrays (lists and structs) are written with [`Encoding::Plain`].
+/// Data pages hold (an estimate of) at most `max_page_size` bytes of values each; nested arrays
+/// are split by top-level slots, so that the pages of all their leaves cover the same rows.
+pub fn array_to_columns(
+    array: Arc<dyn Array>,
+    descriptors: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    encoding: Encoding,
+    max_page_size: usize,
+    dictionary_options: DictionaryOptions,
+) -> Result<Vec<DynIter<'static, Result<CompressedPage>>>> {
+    match array.data_type().to_logical_type() {
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => {
+            let type_ = descriptors
+                .first()
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(
+                        "Writing an array requires at least one column descriptor".to_string(),
+                    )
+                })?
+                .base_type()
+                .clone();
+
+            let mut columns = descriptors.iter().map(|_| vec![]).collect::<Vec<_>>();
+            for (offset, length) in utils::page_ranges(
+                array.len(),
+                utils::estimated_size(array.as_ref()),
+                max_page_size,
+            ) {
+                let pages = nested_array_to_pages(
+                    array.slice(offset, length).as_ref(),
+                    &type_,
+                    descriptors.clone(),
+                    options,
+                )?;
+                columns
+                    .iter_mut()
+                    .zip(pages)
+                    .for_each(|(column, page)| column.push(Ok(CompressedPage::Data(page))));
+            }
+            Ok(columns
+                .into_iter()
+                .map(|pages| DynIter::new(pages.into_iter()))
+                .collect())
         }
-        DataType::FixedSizeList(_, size) => {
-            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
-                .map(|x| size * x as i32)
-                .collect::<Vec<_>>();
-            list_array_to_page(
-                &offsets,
-                array.validity(),
-                array.values().as_ref(),
+        _ => {
+            if descriptors.len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The datatype {:?} has one leaf but {} column descriptors were provided",
+                    array.data_type(),
+                    descriptors.len()
+                )));
+            }
+            let descriptor = descriptors.into_iter().next().unwrap();
+            Ok(vec![array_to_sized_pages(
+                array,
                 descriptor,
                 options,
-            )
+                encoding,
+                max_page_size,
+                dictionary_options,
+            )?])
         }
-        _ => todo!(),
     }
 }
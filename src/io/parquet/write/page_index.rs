@@ -0,0 +1,79 @@
+//! Building of the parquet [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md)
+//! of a column chunk from the data pages written to it.
+use parquet2::statistics::ParquetStatistics;
+use parquet_format_async_temp::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+
+/// A data page written to a column chunk.
+pub(super) struct PageSpec {
+    /// The offset of the page in the file
+    pub offset: u64,
+    /// The size of the page, header included
+    pub length: u64,
+    pub num_values: usize,
+    pub num_rows: usize,
+    pub statistics: Option<ParquetStatistics>,
+}
+
+/// The data pages written to a column chunk.
+#[derive(Default)]
+pub(super) struct ChunkSpec {
+    pub pages: Vec<PageSpec>,
+}
+
+impl ChunkSpec {
+    /// The number of rows of the chunk.
+    pub fn num_rows(&self) -> usize {
+        self.pages.iter().map(|page| page.num_rows).sum()
+    }
+
+    pub fn offset_index(&self) -> OffsetIndex {
+        let mut first_row_index = 0;
+        let locations = self
+            .pages
+            .iter()
+            .map(|page| {
+                let location = PageLocation::new(
+                    page.offset as i64,
+                    page.length as i32,
+                    first_row_index as i64,
+                );
+                first_row_index += page.num_rows;
+                location
+            })
+            .collect();
+        OffsetIndex::new(locations)
+    }
+
+    /// Returns the [`ColumnIndex`] of this chunk, or `None` if a page without nulls
+    /// has no min/max statistics. Pages' boundaries are declared as unordered.
+    pub fn column_index(&self) -> Option<ColumnIndex> {
+        let mut null_pages = Vec::with_capacity(self.pages.len());
+        let mut min_values = Vec::with_capacity(self.pages.len());
+        let mut max_values = Vec::with_capacity(self.pages.len());
+        let mut null_counts = Some(Vec::with_capacity(self.pages.len()));
+        for page in &self.pages {
+            let statistics = page.statistics.as_ref()?;
+            let null_count = statistics.null_count;
+            let is_null_page = null_count == Some(page.num_values as i64);
+            null_pages.push(is_null_page);
+            if is_null_page {
+                min_values.push(vec![]);
+                max_values.push(vec![]);
+            } else {
+                min_values.push(statistics.min_value.clone()?);
+                max_values.push(statistics.max_value.clone()?);
+            }
+            null_counts = null_counts.and_then(|mut counts: Vec<i64>| {
+                counts.push(null_count?);
+                Some(counts)
+            });
+        }
+        Some(ColumnIndex::new(
+            null_pages,
+            min_values,
+            max_values,
+            BoundaryOrder::UNORDERED,
+            null_counts,
+        ))
+    }
+}
@@ -7,10 +7,10 @@ use parquet2::{
     write::WriteOptions,
 };
 
-use super::super::utils;
+use super::super::{encoding, utils};
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -38,14 +38,61 @@ where
     }
 }
 
+fn encode_delta<T, R>(array: &PrimitiveArray<T>, is_optional: bool, buffer: &mut Vec<u8>)
+where
+    T: ArrowNativeType,
+    R: NativeType + num_traits::AsPrimitive<i64>,
+    T: num_traits::AsPrimitive<R>,
+{
+    let to_i64 = |x: &T| {
+        let parquet_native: R = x.as_();
+        parquet_native.as_()
+    };
+    // INT32 deltas are computed in 32 bits
+    let is_i32 = std::mem::size_of::<R>() == 4;
+    if is_optional {
+        // encode the non-null values
+        let length = array.len() - array.null_count();
+        let values = array.iter().flatten().map(to_i64);
+        if is_i32 {
+            encoding::encode_delta_i32(values.map(|x| x as i32), length, buffer)
+        } else {
+            encoding::encode_delta(values, length, buffer)
+        }
+    } else {
+        // encode all values
+        let values = array.values().iter().map(to_i64);
+        if is_i32 {
+            encoding::encode_delta_i32(values.map(|x| x as i32), array.len(), buffer)
+        } else {
+            encoding::encode_delta(values, array.len(), buffer)
+        }
+    }
+}
+
+fn encode_byte_stream_split<T, R>(
+    array: &PrimitiveArray<T>,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) where
+    T: ArrowNativeType,
+    R: NativeType,
+    T: num_traits::AsPrimitive<R>,
+{
+    let mut plain = vec![];
+    encode_plain::<T, R>(array, is_optional, &mut plain);
+    encoding::encode_byte_stream_split(&plain, std::mem::size_of::<R>(), buffer)
+}
+
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<CompressedDataPage>
 where
     T: ArrowNativeType,
-    R: NativeType,
+    R: NativeType + num_traits::AsPrimitive<i64>,
     T: num_traits::AsPrimitive<R>,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -63,7 +110,18 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::DeltaBinaryPacked => encode_delta(array, is_optional, &mut buffer),
+        Encoding::ByteStreamSplit => encode_byte_stream_split(array, is_optional, &mut buffer),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let uncompressed_page_size = buffer.len();
 
@@ -78,6 +136,7 @@ where
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         uncompressed_page_size,
         0,
@@ -85,7 +144,7 @@ where
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
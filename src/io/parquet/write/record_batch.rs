@@ -1,46 +1,325 @@
+use std::{
+    collections::VecDeque,
+    iter::Fuse,
+    sync::{Arc, Mutex},
+};
+
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, Encoding, RowGroupIter, SchemaDescriptor,
-    WriteOptions,
+    array_to_columns, is_int96, to_parquet_schema_with, utils::estimated_size, ColumnDescriptor,
+    CompatibilityOptions, CompressedPage, DictionaryOptions, DynIter, Encoding, RowGroupIter,
+    SchemaDescriptor, WriteOptions,
 };
 use crate::{
-    datatypes::Schema,
+    array::{growable::make_growable, Array},
+    datatypes::{DataType, Schema},
     error::{ArrowError, Result},
+    io::parquet::bloom_filter::{can_filter, BloomFilter, BloomFilterOptions},
+    io::parquet::read::schema::num_columns,
     record_batch::RecordBatch,
 };
 
+/// The target sizes of the row groups and pages written by [`RowGroupIterator`].
+/// Sizes in bytes are estimates of the uncompressed, PLAIN-encoded values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeOptions {
+    /// The maximum number of rows of a row group
+    pub max_row_group_rows: usize,
+    /// The maximum size in bytes of a row group
+    pub max_row_group_size: usize,
+    /// The maximum size in bytes of the values of a data page
+    pub max_page_size: usize,
+}
+
+impl Default for SizeOptions {
+    fn default() -> Self {
+        Self {
+            max_row_group_rows: 1024 * 1024,
+            max_row_group_size: 128 * 1024 * 1024,
+            max_page_size: 1024 * 1024,
+        }
+    }
+}
+
+/// The bloom filters of the column chunks written by a [`RowGroupIterator`], per row group
+/// and parquet column. A handle shared with the iterator, see
+/// [`write_file_with_bloom_filters`](super::write_file_with_bloom_filters).
+#[derive(Debug, Clone, Default)]
+pub struct BloomFilters(Arc<Mutex<Vec<Vec<Option<BloomFilter>>>>>);
+
+impl BloomFilters {
+    /// Takes the bloom filters built so far.
+    pub(super) fn take(&self) -> Vec<Vec<Option<BloomFilter>>> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    fn push(&self, filters: Vec<Option<BloomFilter>>) {
+        self.0.lock().unwrap().push(filters)
+    }
+}
+
 /// An iterator adapter that converts an iterator over [`RecordBatch`] into an iterator
 /// of row groups.
+/// Each batch is written as its own row group, unless [`SizeOptions`] are set with
+/// [`RowGroupIterator::set_sizes`], in which case batches are merged or split into row
+/// groups according to them, and the column chunks of each row group are split into pages
+/// of at most [`SizeOptions::max_page_size`] bytes.
 /// Use it to create an iterator consumable by the parquet's API.
 pub struct RowGroupIterator<I: Iterator<Item = Result<RecordBatch>>> {
-    iter: I,
+    iter: Fuse<I>,
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    sizes: Option<SizeOptions>,
+    dictionary_options: DictionaryOptions,
+    // (slices of) batches not yet written, alongside their estimated size
+    buffer: VecDeque<(RecordBatch, usize)>,
+    bloom_filter_options: Vec<Option<BloomFilterOptions>>,
+    bloom_filters: BloomFilters,
 }
 
-impl<'a, I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
+impl<I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
     /// Creates a new [`RowGroupIterator`] from an iterator over [`RecordBatch`].
+    /// Each batch is written as a row group of a single page per column; use
+    /// [`RowGroupIterator::set_sizes`] to size row groups and pages instead.
     pub fn try_new(
         iter: I,
         schema: &Schema,
         options: WriteOptions,
         encodings: Vec<Encoding>,
+    ) -> Result<Self> {
+        Self::try_new_with_compatibility(iter, schema, options, encodings, Default::default())
+    }
+
+    /// Creates a new [`RowGroupIterator`] from an iterator over [`RecordBatch`], whose
+    /// parquet schema is created according to `compatibility`.
+    pub fn try_new_with_compatibility(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+        compatibility: CompatibilityOptions,
     ) -> Result<Self> {
         assert_eq!(schema.fields().len(), encodings.len());
 
-        let parquet_schema = to_parquet_schema(schema)?;
+        let parquet_schema = to_parquet_schema_with(schema, compatibility)?;
 
         Ok(Self {
-            iter,
+            iter: iter.fuse(),
             options,
             parquet_schema,
             encodings,
+            sizes: None,
+            dictionary_options: DictionaryOptions::default(),
+            buffer: VecDeque::new(),
+            bloom_filter_options: vec![None; schema.fields().len()],
+            bloom_filters: BloomFilters::default(),
         })
     }
 
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
     }
+
+    /// Sets the target sizes of the row groups and pages, e.g. [`SizeOptions::default`]
+    /// (1Mi rows and 128 MiB per row group, 1 MiB per page).
+    ///
+    /// Note that once sizes are set, batches are merged and split into row groups of up to
+    /// these sizes: the row groups no longer correspond to the incoming batches.
+    /// # Errors
+    /// This function errors iff the maximum number of rows or size of a row group is zero.
+    pub fn set_sizes(&mut self, sizes: SizeOptions) -> Result<()> {
+        if sizes.max_row_group_rows == 0 || sizes.max_row_group_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "The maximum number of rows and size of a row group must be larger than zero"
+                    .to_string(),
+            ));
+        }
+        self.sizes = Some(sizes);
+        Ok(())
+    }
+
+    /// Sets when the fields written with dictionary encoding are dictionary-encoded,
+    /// replacing [`DictionaryOptions::default`].
+    pub fn set_dictionary_options(&mut self, options: DictionaryOptions) {
+        self.dictionary_options = options;
+    }
+
+    /// Sets the options of the bloom filter of each field, `None` for no bloom filter.
+    /// Fields whose data type is not supported by [`can_filter`] have no bloom filter.
+    pub fn set_bloom_filters(&mut self, options: Vec<Option<BloomFilterOptions>>) {
+        assert_eq!(options.len(), self.encodings.len());
+        self.bloom_filter_options = options;
+    }
+
+    /// Returns the handle of the bloom filters built while iterating.
+    pub fn bloom_filters(&self) -> BloomFilters {
+        self.bloom_filters.clone()
+    }
+
+    /// Returns the batches of the next row group: the next batch when no sizes are set,
+    /// and the buffered (slices of) batches that fill a row group otherwise.
+    fn next_row_group(&mut self) -> Option<Result<Vec<RecordBatch>>> {
+        let sizes = match self.sizes {
+            Some(sizes) => sizes,
+            None => return self.iter.next().map(|batch| batch.map(|batch| vec![batch])),
+        };
+        if let Err(error) = self.fill(sizes) {
+            return Some(Err(error));
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+        Some(self.take_row_group(sizes))
+    }
+
+    /// Buffers batches from the inner iterator until a row group is full or the iterator
+    /// is exhausted. Empty batches are dropped.
+    fn fill(&mut self, sizes: SizeOptions) -> Result<()> {
+        let (mut rows, mut size) = self
+            .buffer
+            .iter()
+            .fold((0, 0), |(rows, size), (batch, batch_size)| {
+                (rows + batch.num_rows(), size + batch_size)
+            });
+        while rows < sizes.max_row_group_rows && size < sizes.max_row_group_size {
+            let batch = match self.iter.next() {
+                Some(batch) => batch?,
+                None => break,
+            };
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let batch_size = batch_estimated_size(&batch);
+            rows += batch.num_rows();
+            size += batch_size;
+            self.buffer.push_back((batch, batch_size));
+        }
+        Ok(())
+    }
+
+    /// Pops the buffered (slices of) batches that form the next row group.
+    fn take_row_group(&mut self, sizes: SizeOptions) -> Result<Vec<RecordBatch>> {
+        let max_rows = sizes.max_row_group_rows;
+        let max_size = sizes.max_row_group_size;
+
+        let mut batches = vec![];
+        let (mut rows, mut size) = (0, 0);
+        while let Some((batch, batch_size)) = self.buffer.pop_front() {
+            let num_rows = batch.num_rows();
+            // the number of rows of this batch that fit in the row group
+            let length = if size + batch_size <= max_size {
+                num_rows
+            } else {
+                (num_rows as u128 * (max_size - size) as u128 / batch_size as u128) as usize
+            };
+            let mut length = length.min(max_rows - rows);
+            if batches.is_empty() {
+                // row groups have at least one row
+                length = length.max(1);
+            }
+
+            if length < num_rows {
+                if length > 0 {
+                    let head = slice_batch(&batch, 0, length)?;
+                    let head_size = batch_estimated_size(&head);
+                    batches.push(head);
+                    let tail = slice_batch(&batch, length, num_rows - length)?;
+                    self.buffer
+                        .push_front((tail, batch_size.saturating_sub(head_size)));
+                } else {
+                    self.buffer.push_front((batch, batch_size));
+                }
+                break;
+            }
+
+            rows += num_rows;
+            size += batch_size;
+            batches.push(batch);
+            if rows >= max_rows || size >= max_size {
+                break;
+            }
+        }
+        Ok(batches)
+    }
+}
+
+fn batch_estimated_size(batch: &RecordBatch) -> usize {
+    batch
+        .columns()
+        .iter()
+        .map(|array| estimated_size(array.as_ref()))
+        .sum()
+}
+
+fn slice_batch(batch: &RecordBatch, offset: usize, length: usize) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|array| array.slice(offset, length).into())
+        .collect();
+    RecordBatch::try_new(batch.schema().clone(), columns)
+}
+
+fn concatenate(arrays: &[Arc<dyn Array>]) -> Arc<dyn Array> {
+    let arrays = arrays
+        .iter()
+        .map(|array| array.as_ref())
+        .collect::<Vec<_>>();
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+
+    let mut growable = make_growable(&arrays, false, capacity);
+    arrays
+        .iter()
+        .enumerate()
+        .for_each(|(index, array)| growable.extend(index, 0, array.len()));
+    growable.as_arc()
+}
+
+/// Returns an iterator of compressed pages per parquet column (leaf) of a field
+/// whose values are the concatenation of `arrays`.
+fn arrays_to_columns(
+    arrays: Vec<Arc<dyn Array>>,
+    descriptors: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    encoding: Encoding,
+    max_page_size: usize,
+    dictionary_options: DictionaryOptions,
+) -> Result<Vec<DynIter<'static, Result<CompressedPage>>>> {
+    // non-nested arrays are concatenated so that the column chunk has a single dictionary
+    // and pages are not bounded by the batches' lengths. Nested arrays are written one
+    // after the other.
+    let is_nested = matches!(
+        arrays[0].data_type().to_logical_type(),
+        DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Struct(_)
+    );
+    let arrays = if arrays.len() > 1 && !is_nested {
+        vec![concatenate(&arrays)]
+    } else {
+        arrays
+    };
+
+    let mut columns = descriptors.iter().map(|_| vec![]).collect::<Vec<_>>();
+    for array in arrays {
+        let pages = array_to_columns(
+            array,
+            descriptors.clone(),
+            options,
+            encoding,
+            max_page_size,
+            dictionary_options,
+        )?;
+        columns
+            .iter_mut()
+            .zip(pages)
+            .for_each(|(column, pages)| column.push(pages));
+    }
+    Ok(columns
+        .into_iter()
+        .map(|pages| DynIter::new(pages.into_iter().flatten()))
+        .collect())
 }
 
 impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
@@ -48,18 +327,90 @@ impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let options = self.options;
+        let max_page_size = self
+            .sizes
+            .map(|sizes| sizes.max_page_size)
+            .unwrap_or(usize::MAX);
+        let dictionary_options = self.dictionary_options;
 
-        self.iter.next().map(|batch| {
-            let batch = batch?;
-            let columns = batch.columns().to_vec();
+        self.next_row_group().map(|batches| {
+            let batches = batches?;
             let encodings = self.encodings.clone();
+
+            // the arrays of each field
+            let columns = (0..encodings.len())
+                .map(|index| {
+                    batches
+                        .iter()
+                        .map(|batch| batch.column(index).clone())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            // the descriptors of the leaves of each field
+            let mut descriptors = self.parquet_schema.columns().iter();
+            let descriptors = self
+                .parquet_schema
+                .fields()
+                .iter()
+                .map(|type_| {
+                    descriptors
+                        .by_ref()
+                        .take(num_columns(type_))
+                        .cloned()
+                        .collect::<Vec<ColumnDescriptor>>()
+                })
+                .collect::<Vec<_>>();
+
+            // the bloom filter of each parquet column; only fields with a single leaf have one.
+            // INT96 values are not hashed like the timestamps they are written from.
+            let mut filters = vec![];
+            for ((arrays, descriptors), options) in columns
+                .iter()
+                .zip(descriptors.iter())
+                .zip(self.bloom_filter_options.iter())
+            {
+                let options = options.filter(|_| {
+                    descriptors.len() == 1
+                        && !is_int96(&descriptors[0])
+                        && can_filter(arrays[0].data_type())
+                });
+                let filter = match options {
+                    Some(options) => {
+                        let mut filter = BloomFilter::from_ndv_fpp(options.ndv, options.fpp);
+                        for array in arrays {
+                            filter.insert_array(array.as_ref())?;
+                        }
+                        Some(filter)
+                    }
+                    None => None,
+                };
+                // one filter per parquet column (leaf) of the field
+                filters.extend(
+                    std::iter::once(filter)
+                        .chain(std::iter::repeat(None))
+                        .take(descriptors.len()),
+                );
+            }
+            self.bloom_filters.push(filters);
+
             Ok(DynIter::new(
                 columns
                     .into_iter()
-                    .zip(self.parquet_schema.columns().to_vec().into_iter())
-                    .zip(encodings.into_iter())
-                    .map(move |((array, type_), encoding)| {
-                        array_to_pages(array, type_, options, encoding)
+                    .zip(descriptors)
+                    .zip(encodings)
+                    .flat_map(move |((arrays, descriptors), encoding)| {
+                        match arrays_to_columns(
+                            arrays,
+                            descriptors,
+                            options,
+                            encoding,
+                            max_page_size,
+                            dictionary_options,
+                        ) {
+                            Ok(columns) => columns.into_iter().map(Ok).collect::<Vec<_>>(),
+                            Err(error) => vec![Err(error)],
+                        }
                     }),
             ))
         })
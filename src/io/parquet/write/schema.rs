@@ -2,8 +2,8 @@ use parquet2::{
     metadata::KeyValue,
     schema::{
         types::{
-            DecimalType, IntType, LogicalType, ParquetType, PhysicalType, PrimitiveConvertedType,
-            TimeType, TimeUnit as ParquetTimeUnit, TimestampType,
+            DecimalType, GroupConvertedType, IntType, LogicalType, ParquetType, PhysicalType,
+            PrimitiveConvertedType, TimeType, TimeUnit as ParquetTimeUnit, TimestampType,
         },
         Repetition,
     },
@@ -37,7 +37,130 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     }
 }
 
+/// Options to write parquet types readable by legacy consumers (e.g. Hive or Impala).
+/// By default, types are written as recommended by the
+/// [parquet format](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompatibilityOptions {
+    /// Writes timestamps, of any unit, as (deprecated) INT96 nanoseconds
+    pub int96_timestamps: bool,
+    /// Annotates types with converted types only. Types whose logical type has no
+    /// equivalent converted type (e.g. timestamps in nanoseconds) are not annotated.
+    pub legacy_converted_types: bool,
+    /// Writes lists in the legacy 2-level layout, whose repeated field, named `array`, is the
+    /// item. Such lists cannot have null items.
+    pub legacy_lists: bool,
+}
+
+impl CompatibilityOptions {
+    /// The options with all legacy representations enabled.
+    pub fn legacy() -> Self {
+        Self {
+            int96_timestamps: true,
+            legacy_converted_types: true,
+            legacy_lists: true,
+        }
+    }
+}
+
+/// Returns `type_` with its logical type replaced by its equivalent converted type, if any.
+fn to_legacy_type(type_: ParquetType) -> Result<ParquetType> {
+    Ok(match type_ {
+        ParquetType::PrimitiveType {
+            basic_info,
+            logical_type,
+            converted_type,
+            physical_type,
+        } => {
+            let converted_type = converted_type.or(match logical_type {
+                Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })) => match unit {
+                    ParquetTimeUnit::MILLIS(_) => Some(PrimitiveConvertedType::TimestampMillis),
+                    ParquetTimeUnit::MICROS(_) => Some(PrimitiveConvertedType::TimestampMicros),
+                    ParquetTimeUnit::NANOS(_) => None,
+                },
+                _ => None,
+            });
+            ParquetType::try_from_primitive(
+                basic_info.name().to_string(),
+                physical_type,
+                *basic_info.repetition(),
+                converted_type,
+                None,
+                *basic_info.id(),
+            )?
+        }
+        ParquetType::GroupType {
+            basic_info,
+            logical_type,
+            converted_type,
+            fields,
+        } => {
+            let converted_type = converted_type.or(match logical_type {
+                Some(LogicalType::LIST(_)) => Some(GroupConvertedType::List),
+                _ => None,
+            });
+            ParquetType::try_from_group(
+                basic_info.name().to_string(),
+                *basic_info.repetition(),
+                converted_type,
+                None,
+                fields,
+                *basic_info.id(),
+            )?
+        }
+    })
+}
+
+/// Returns `type_` with repetition [`Repetition::Repeated`].
+fn to_repeated_type(type_: ParquetType) -> Result<ParquetType> {
+    Ok(match type_ {
+        ParquetType::PrimitiveType {
+            basic_info,
+            logical_type,
+            converted_type,
+            physical_type,
+        } => ParquetType::try_from_primitive(
+            basic_info.name().to_string(),
+            physical_type,
+            Repetition::Repeated,
+            converted_type,
+            logical_type,
+            *basic_info.id(),
+        )?,
+        ParquetType::GroupType {
+            basic_info,
+            logical_type,
+            converted_type,
+            fields,
+        } => ParquetType::try_from_group(
+            basic_info.name().to_string(),
+            Repetition::Repeated,
+            converted_type,
+            logical_type,
+            fields,
+            *basic_info.id(),
+        )?,
+    })
+}
+
 pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
+    to_parquet_type_with(field, CompatibilityOptions::default())
+}
+
+/// Converts a [`Field`] to a [`ParquetType`] according to `compatibility`.
+pub fn to_parquet_type_with(
+    field: &Field,
+    compatibility: CompatibilityOptions,
+) -> Result<ParquetType> {
+    let type_ = to_type(field, compatibility)?;
+    if compatibility.legacy_converted_types {
+        to_legacy_type(type_)
+    } else {
+        Ok(type_)
+    }
+}
+
+fn to_type(field: &Field, compatibility: CompatibilityOptions) -> Result<ParquetType> {
     let name = field.name().clone();
     let repetition = if field.is_nullable() {
         Repetition::Optional
@@ -195,6 +318,16 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
             })),
             None,
         )?),
+        DataType::Timestamp(_, _) if compatibility.int96_timestamps => {
+            Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::Int96,
+                repetition,
+                None,
+                None,
+                None,
+            )?)
+        }
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
         DataType::Timestamp(TimeUnit::Second, _) => Ok(ParquetType::try_from_primitive(
@@ -270,7 +403,7 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
             // recursively convert children to types/nodes
             let fields = fields
                 .iter()
-                .map(|f| to_parquet_type(f))
+                .map(|f| to_parquet_type_with(f, compatibility))
                 .collect::<Result<Vec<_>>>()?;
             Ok(ParquetType::try_from_group(
                 name, repetition, None, None, fields, None,
@@ -278,7 +411,7 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
         }
         DataType::Dictionary(_, value) => {
             let dict_field = Field::new(name.as_str(), value.as_ref().clone(), field.is_nullable());
-            to_parquet_type(&dict_field)
+            to_parquet_type_with(&dict_field, compatibility)
         }
         DataType::FixedSizeBinary(size) => Ok(ParquetType::try_from_primitive(
             name,
@@ -324,6 +457,22 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
             None,
             None,
         )?),
+        DataType::List(f) | DataType::FixedSizeList(f, _) | DataType::LargeList(f)
+            if compatibility.legacy_lists =>
+        {
+            let item = Field::new("array", f.data_type().clone(), false);
+            Ok(ParquetType::try_from_group(
+                name,
+                repetition,
+                None,
+                Some(LogicalType::LIST(Default::default())),
+                vec![to_repeated_type(to_parquet_type_with(
+                    &item,
+                    compatibility,
+                )?)?],
+                None,
+            )?)
+        }
         DataType::List(f) | DataType::FixedSizeList(f, _) | DataType::LargeList(f) => {
             Ok(ParquetType::try_from_group(
                 name,
@@ -335,7 +484,7 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                     Repetition::Repeated,
                     None,
                     None,
-                    vec![to_parquet_type(f)?],
+                    vec![to_parquet_type_with(f, compatibility)?],
                     None,
                 )?],
                 None,
@@ -1,20 +1,18 @@
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
+use futures::AsyncWriteExt;
 
+use parquet2::metadata::{KeyValue, SchemaDescriptor};
 use parquet2::write::RowGroupIter;
-use parquet2::{
-    metadata::{KeyValue, SchemaDescriptor},
-    write::stream::write_stream as parquet_write_stream,
-    write::stream::write_stream_stream as parquet_write_stream_stream,
-};
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
+use super::file::FileWriter;
 use super::schema::schema_to_metadata_key;
-use super::WriteOptions;
+use super::{FileOptions, WriteOptions};
 
 /// Writes
-pub async fn write_stream<'a, W, I>(
+pub async fn write_stream<W, I>(
     writer: &mut W,
     row_groups: I,
     schema: &Schema,
@@ -22,6 +20,32 @@ pub async fn write_stream<'a, W, I>(
     options: WriteOptions,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Result<u64>
+where
+    W: std::io::Write + std::io::Seek,
+    I: Stream<Item = Result<RowGroupIter<'static, ArrowError>>>,
+{
+    write_stream_with(
+        writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        key_value_metadata,
+        &FileOptions::default(),
+    )
+    .await
+}
+
+/// Writes like [`write_stream`], alongside the structures of `file_options`.
+pub async fn write_stream_with<W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+    file_options: &FileOptions,
+) -> Result<u64>
 where
     W: std::io::Write + std::io::Seek,
     I: Stream<Item = Result<RowGroupIter<'static, ArrowError>>>,
@@ -33,26 +57,51 @@ where
         })
         .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
 
-    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
-    Ok(parquet_write_stream(
+    let mut file = FileWriter::new(parquet_schema, options, file_options.page_index);
+    writer.write_all(&file.start())?;
+    futures::pin_mut!(row_groups);
+    while let Some(row_group) = row_groups.next().await {
+        writer.write_all(&file.write_row_group(row_group?)?)?;
+    }
+    let bloom_filters = file_options.bloom_filters.take();
+    writer.write_all(&file.end(key_value_metadata, bloom_filters)?)?;
+    Ok(file.position())
+}
+
+/// Async writes
+pub async fn write_stream_stream<W, I>(
+    writer: &mut W,
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+    options: WriteOptions,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Result<u64>
+where
+    W: futures::io::AsyncWrite + Unpin + Send,
+    I: Stream<Item = Result<RowGroupIter<'static, ArrowError>>>,
+{
+    write_stream_stream_with(
         writer,
         row_groups,
+        schema,
         parquet_schema,
         options,
-        created_by,
         key_value_metadata,
+        &FileOptions::default(),
     )
-    .await?)
+    .await
 }
 
-/// Async writes
-pub async fn write_stream_stream<'a, W, I>(
+/// Async writes like [`write_stream_stream`], alongside the structures of `file_options`.
+pub async fn write_stream_stream_with<W, I>(
     writer: &mut W,
     row_groups: I,
     schema: &Schema,
     parquet_schema: SchemaDescriptor,
     options: WriteOptions,
     key_value_metadata: Option<Vec<KeyValue>>,
+    file_options: &FileOptions,
 ) -> Result<u64>
 where
     W: futures::io::AsyncWrite + Unpin + Send,
@@ -65,14 +114,15 @@ where
         })
         .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
 
-    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
-    Ok(parquet_write_stream_stream(
-        writer,
-        row_groups,
-        parquet_schema,
-        options,
-        created_by,
-        key_value_metadata,
-    )
-    .await?)
+    let mut file = FileWriter::new(parquet_schema, options, file_options.page_index);
+    writer.write_all(&file.start()).await?;
+    futures::pin_mut!(row_groups);
+    while let Some(row_group) = row_groups.next().await {
+        let bytes = file.write_row_group(row_group?)?;
+        writer.write_all(&bytes).await?;
+    }
+    let bloom_filters = file_options.bloom_filters.take();
+    let bytes = file.end(key_value_metadata, bloom_filters)?;
+    writer.write_all(&bytes).await?;
+    Ok(file.position())
 }
@@ -7,31 +7,36 @@ use super::basic::{build_statistics, encode_plain};
 use crate::{
     array::{Array, Offset, Utf8Array},
     error::Result,
-    io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O, OO>(
+pub fn array_to_page<O>(
     array: &Utf8Array<O>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<OO>,
+    nested: &levels::Levels,
 ) -> Result<CompressedDataPage>
 where
-    OO: Offset,
     O: Offset,
 {
-    let is_optional = is_type_nullable(descriptor.type_());
-
-    let validity = array.validity();
-
     let mut buffer = vec![];
-    levels::write_rep_levels(&mut buffer, &nested, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &nested.rep,
+        descriptor.max_rep_level(),
+        options.version,
+    )?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &nested.def,
+        descriptor.max_def_level(),
+        options.version,
+    )?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
-    encode_plain(array, is_optional, &mut buffer);
+    // the array has one slot per level, null whenever the level is not defined
+    encode_plain(array, true, &mut buffer);
 
     let uncompressed_page_size = buffer.len();
 
@@ -49,7 +54,8 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        array.len(),
+        nested.num_rows(),
         array.null_count(),
         uncompressed_page_size,
         repetition_levels_byte_length,
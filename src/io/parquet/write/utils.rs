@@ -1,4 +1,6 @@
+use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::datatypes::PhysicalType;
 
 use parquet2::{
     compression::{create_codec, Compression},
@@ -61,6 +63,7 @@ pub fn write_def_levels(
 pub fn build_plain_page(
     buffer: Vec<u8>,
     len: usize,
+    num_rows: usize,
     null_count: usize,
     uncompressed_page_size: usize,
     repetition_levels_byte_length: usize,
@@ -94,7 +97,7 @@ pub fn build_plain_page(
                 num_values: len as i32,
                 encoding: encoding.into(),
                 num_nulls: null_count as i32,
-                num_rows: len as i32,
+                num_rows: num_rows as i32,
                 definition_levels_byte_length: definition_levels_byte_length as i32,
                 repetition_levels_byte_length: repetition_levels_byte_length as i32,
                 is_compressed: Some(options.compression != Compression::Uncompressed),
@@ -172,10 +175,11 @@ impl<T, I: Iterator<Item = T>> Iterator for ExactSizedIter<T, I> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|x| {
+        let item = self.iter.next();
+        if item.is_some() {
             self.remaining -= 1;
-            x
-        })
+        }
+        item
     }
 
     #[inline]
@@ -189,3 +193,120 @@ impl<T, I: Iterator<Item = T>> Iterator for ExactSizedIter<T, I> {
 pub fn get_bit_width(max: u64) -> u32 {
     64 - max.leading_zeros()
 }
+
+fn offsets_size<O: Offset>(offsets: &[O]) -> usize {
+    let values = offsets[offsets.len() - 1].to_usize() - offsets[0].to_usize();
+    // a 4-byte length per value when PLAIN-encoded
+    4 * (offsets.len() - 1) + values
+}
+
+fn list_size<O: Offset>(array: &dyn Array) -> usize {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let offsets = array.offsets();
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    estimated_size(array.values().slice(start, end - start).as_ref())
+}
+
+/// Returns an estimate of the size in bytes of the PLAIN-encoded values of `array`.
+/// Only the values in the (sliced) range of `array` are accounted for.
+pub fn estimated_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => (array.len() + 7) / 8,
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            array.len() * std::mem::size_of::<$T>()
+        }),
+        Binary => offsets_size(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .offsets(),
+        ),
+        LargeBinary => offsets_size(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .offsets(),
+        ),
+        Utf8 => offsets_size(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .offsets(),
+        ),
+        LargeUtf8 => offsets_size(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .offsets(),
+        ),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.len() * array.size()
+        }
+        List => list_size::<i32>(array),
+        LargeList => list_size::<i64>(array),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+            let length = array.len() * *size as usize;
+            estimated_size(array.values().slice(0, length).as_ref())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|child| estimated_size(child.as_ref()))
+                .sum()
+        }
+        // dictionary-encoded: the keys are written as (at most) 32 bit indices
+        Dictionary(_) => array.len() * std::mem::size_of::<u32>(),
+        Union => array.len(),
+    }
+}
+
+/// Splits `length` slots whose values have an (estimated) size of `size` bytes into
+/// ranges `(offset, length)` of at most `max_size` bytes each. Every range has at least
+/// one slot, and a single empty range is returned when `length` is zero.
+pub fn page_ranges(length: usize, size: usize, max_size: usize) -> Vec<(usize, usize)> {
+    if length == 0 {
+        return vec![(0, 0)];
+    }
+    let slots = if size <= max_size {
+        length
+    } else {
+        ((length as u128 * max_size as u128 / size as u128) as usize).max(1)
+    };
+    (0..length)
+        .step_by(slots)
+        .map(|offset| (offset, slots.min(length - offset)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::datatypes::{DataType, Field};
+
+    #[test]
+    fn estimated_size_fixed_size_list() {
+        let values = Arc::new(Int32Array::from_values(0..12)) as Arc<dyn Array>;
+        let data_type =
+            DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, false)), 3);
+        let array = FixedSizeListArray::from_data(data_type, values, None);
+        assert_eq!(estimated_size(&array), 48);
+        assert_eq!(estimated_size(&array.slice(1, 2)), 24);
+    }
+}
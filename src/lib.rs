@@ -19,6 +19,7 @@ pub mod compute;
 pub mod io;
 pub mod record_batch;
 pub mod temporal_conversions;
+pub mod tensor;
 pub use alloc::total_allocated_bytes;
 
 pub mod datatypes;
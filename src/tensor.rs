@@ -0,0 +1,400 @@
+//! Contains [`Tensor`] and [`SparseTensor`], multi-dimensional arrays of values of a
+//! [`NativeType`], as defined by the
+//! [Arrow specification](https://arrow.apache.org/docs/format/Other.html).
+
+use crate::{
+    array::{Array, FixedSizeListArray, PrimitiveArray},
+    buffer::{Buffer, MutableBuffer},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// Returns the strides (in number of values) of a row-major tensor with shape `shape`.
+fn row_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+fn check_names(names: &Option<Vec<String>>, shape: &[usize]) -> Result<()> {
+    match names {
+        Some(names) if names.len() != shape.len() => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "A tensor with {} dimensions must have {} dimension names, but it has {}",
+                shape.len(),
+                shape.len(),
+                names.len()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_data_type<T: NativeType>(data_type: &DataType) -> Result<()> {
+    if T::is_valid(data_type) {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Type {} does not support logical type {:?}",
+            std::any::type_name::<T>(),
+            data_type
+        )))
+    }
+}
+
+/// A dense multi-dimensional array of values of type `T`, whose value at index `[i_0, i_1, ...]`
+/// is at position `i_0 * strides[0] + i_1 * strides[1] + ...` of its values.
+/// # Example
+/// ```
+/// use arrow2::buffer::Buffer;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::tensor::Tensor;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let values = Buffer::from(&[1i32, 2, 3, 4, 5, 6]);
+/// let tensor = Tensor::try_new(DataType::Int32, values, vec![2, 3], None, None)?;
+/// assert_eq!(tensor.value(&[1, 0]), 4);
+/// assert_eq!(tensor.strides(), vec![3, 1]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tensor<T: NativeType> {
+    data_type: DataType,
+    values: Buffer<T>,
+    shape: Vec<usize>,
+    // in number of values; `None` for row-major
+    strides: Option<Vec<usize>>,
+    names: Option<Vec<String>>,
+}
+
+impl<T: NativeType> Tensor<T> {
+    /// Returns a new [`Tensor`] of shape `shape` whose values are `values`, laid out according
+    /// to `strides` (in number of values), or in row-major order if `None`.
+    /// # Errors
+    /// Errors if `data_type` is not valid for `T`, if the number of strides or names differs
+    /// from the number of dimensions, or if `values` is too short for `shape` and `strides`.
+    pub fn try_new(
+        data_type: DataType,
+        values: Buffer<T>,
+        shape: Vec<usize>,
+        strides: Option<Vec<usize>>,
+        names: Option<Vec<String>>,
+    ) -> Result<Self> {
+        check_data_type::<T>(&data_type)?;
+        check_names(&names, &shape)?;
+        let required = match &strides {
+            Some(strides) => {
+                if strides.len() != shape.len() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "A tensor with {} dimensions must have {} strides, but it has {}",
+                        shape.len(),
+                        shape.len(),
+                        strides.len()
+                    )));
+                }
+                if shape.contains(&0) {
+                    0
+                } else {
+                    1 + shape
+                        .iter()
+                        .zip(strides.iter())
+                        .map(|(size, stride)| (size - 1) * stride)
+                        .sum::<usize>()
+                }
+            }
+            None => shape.iter().product(),
+        };
+        if values.len() < required {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A tensor with shape {:?} requires {} values, but it has {}",
+                shape,
+                required,
+                values.len()
+            )));
+        }
+        Ok(Self {
+            data_type,
+            values,
+            shape,
+            strides,
+            names,
+        })
+    }
+
+    /// Returns a two-dimensional row-major [`Tensor`] with one row per list of `array`.
+    /// # Errors
+    /// Errors if `array` or its values contain nulls, or if its values are not a
+    /// [`PrimitiveArray<T>`].
+    pub fn from_fixed_size_list(array: &FixedSizeListArray) -> Result<Self> {
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "A tensor of {} can't be created from a list of {:?}",
+                    std::any::type_name::<T>(),
+                    array.values().data_type()
+                ))
+            })?;
+        if array.null_count() > 0 || values.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "A tensor can't be created from a list array with nulls".to_string(),
+            ));
+        }
+        let (_, size) = FixedSizeListArray::get_child_and_size(array.data_type());
+        Self::try_new(
+            values.data_type().clone(),
+            values.values().clone(),
+            vec![array.len(), *size as usize],
+            None,
+            None,
+        )
+    }
+
+    /// The logical type of the values of this tensor
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The values of this tensor, laid out according to [`Tensor::strides`].
+    pub fn values(&self) -> &Buffer<T> {
+        &self.values
+    }
+
+    /// The size of each dimension of this tensor
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The number of dimensions of this tensor
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// The number of values of this tensor, the product of its shape
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Whether this tensor has no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of values between consecutive indices of each dimension
+    pub fn strides(&self) -> Vec<usize> {
+        self.strides
+            .clone()
+            .unwrap_or_else(|| row_major_strides(&self.shape))
+    }
+
+    /// Whether the values of this tensor are laid out in row-major order
+    pub fn is_row_major(&self) -> bool {
+        self.strides
+            .as_ref()
+            .map(|strides| strides == &row_major_strides(&self.shape))
+            .unwrap_or(true)
+    }
+
+    /// The name of each dimension of this tensor, if any
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+
+    /// Returns the value at `index`.
+    /// # Panics
+    /// Panics iff `index` has a different number of dimensions than this tensor or is out of
+    /// bounds.
+    pub fn value(&self, index: &[usize]) -> T {
+        assert_eq!(index.len(), self.shape.len());
+        assert!(index.iter().zip(self.shape.iter()).all(|(i, s)| i < s));
+        let position = index
+            .iter()
+            .zip(self.strides().iter())
+            .map(|(i, stride)| i * stride)
+            .sum::<usize>();
+        self.values.as_slice()[position]
+    }
+}
+
+/// A sparse multi-dimensional array of values of type `T` in coordinate (COO) format: the
+/// indices of its `n` non-default values are a row-major `n x ndim` matrix.
+/// # Example
+/// ```
+/// use arrow2::buffer::Buffer;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::tensor::{SparseTensor, Tensor};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let values = Buffer::from(&[0i32, 2, 0, 0, 0, 6]);
+/// let tensor = Tensor::try_new(DataType::Int32, values, vec![2, 3], None, None)?;
+/// let sparse = SparseTensor::from_dense(&tensor);
+/// assert_eq!(sparse.values().as_slice(), &[2, 6]);
+/// assert_eq!(sparse.indices().as_slice(), &[0, 1, 1, 2]);
+/// assert_eq!(sparse.to_dense(), tensor);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseTensor<T: NativeType> {
+    data_type: DataType,
+    values: Buffer<T>,
+    indices: Buffer<i64>,
+    shape: Vec<usize>,
+    names: Option<Vec<String>>,
+    is_canonical: bool,
+}
+
+impl<T: NativeType> SparseTensor<T> {
+    /// Returns a new [`SparseTensor`] of shape `shape` whose non-default values are `values`,
+    /// at the coordinates `indices`, a row-major `values.len() x shape.len()` matrix.
+    /// `is_canonical` declares that the indices are sorted in lexicographical order and
+    /// without duplicates.
+    /// # Errors
+    /// Errors if `data_type` is not valid for `T`, if the number of names differs from the
+    /// number of dimensions, or if `indices` has the wrong length or is out of bounds.
+    pub fn try_new(
+        data_type: DataType,
+        values: Buffer<T>,
+        indices: Buffer<i64>,
+        shape: Vec<usize>,
+        names: Option<Vec<String>>,
+        is_canonical: bool,
+    ) -> Result<Self> {
+        check_data_type::<T>(&data_type)?;
+        check_names(&names, &shape)?;
+        if indices.len() != values.len() * shape.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A sparse tensor with {} values and {} dimensions requires {} indices, but it has {}",
+                values.len(),
+                shape.len(),
+                values.len() * shape.len(),
+                indices.len()
+            )));
+        }
+        if !shape.is_empty() {
+            let in_bounds = indices.as_slice().chunks_exact(shape.len()).all(|index| {
+                index
+                    .iter()
+                    .zip(shape.iter())
+                    .all(|(i, size)| *i >= 0 && (*i as usize) < *size)
+            });
+            if !in_bounds {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The indices of a sparse tensor must be within its shape {:?}",
+                    shape
+                )));
+            }
+        }
+        Ok(Self {
+            data_type,
+            values,
+            indices,
+            shape,
+            names,
+            is_canonical,
+        })
+    }
+
+    /// Returns a canonical [`SparseTensor`] with the values of `tensor` that differ from
+    /// `T::default()`.
+    pub fn from_dense(tensor: &Tensor<T>) -> Self {
+        let shape = tensor.shape();
+        let mut values = MutableBuffer::<T>::new();
+        let mut indices = MutableBuffer::<i64>::new();
+
+        let mut index = vec![0; shape.len()];
+        for _ in 0..tensor.len() {
+            let value = tensor.value(&index);
+            if value != T::default() {
+                values.push(value);
+                indices.extend(index.iter().map(|i| *i as i64));
+            }
+            // the next index, in row-major order
+            for dim in (0..shape.len()).rev() {
+                index[dim] += 1;
+                if index[dim] < shape[dim] {
+                    break;
+                }
+                index[dim] = 0;
+            }
+        }
+
+        Self {
+            data_type: tensor.data_type().clone(),
+            values: values.into(),
+            indices: indices.into(),
+            shape: shape.to_vec(),
+            names: tensor.names().map(|names| names.to_vec()),
+            is_canonical: true,
+        }
+    }
+
+    /// Returns the row-major [`Tensor`] of this sparse tensor, whose missing values are
+    /// `T::default()`.
+    pub fn to_dense(&self) -> Tensor<T> {
+        let strides = row_major_strides(&self.shape);
+        let length = self.shape.iter().product();
+        let mut values = MutableBuffer::<T>::from_len_zeroed(length);
+        if !self.shape.is_empty() {
+            let slice = values.as_mut_slice();
+            self.indices
+                .as_slice()
+                .chunks_exact(self.shape.len())
+                .zip(self.values.as_slice())
+                .for_each(|(index, value)| {
+                    let position = index
+                        .iter()
+                        .zip(strides.iter())
+                        .map(|(i, stride)| *i as usize * stride)
+                        .sum::<usize>();
+                    slice[position] = *value;
+                });
+        }
+        Tensor {
+            data_type: self.data_type.clone(),
+            values: values.into(),
+            shape: self.shape.clone(),
+            strides: None,
+            names: self.names.clone(),
+        }
+    }
+
+    /// The logical type of the values of this tensor
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The non-default values of this tensor
+    pub fn values(&self) -> &Buffer<T> {
+        &self.values
+    }
+
+    /// The coordinates of each value, a row-major `values.len() x ndim` matrix
+    pub fn indices(&self) -> &Buffer<i64> {
+        &self.indices
+    }
+
+    /// The size of each dimension of this tensor
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The number of dimensions of this tensor
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// The name of each dimension of this tensor, if any
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+
+    /// Whether the indices are sorted in lexicographical order and without duplicates
+    pub fn is_canonical(&self) -> bool {
+        self.is_canonical
+    }
+}
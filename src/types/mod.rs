@@ -76,6 +76,9 @@ pub unsafe trait NativeType:
     /// To bytes in big endian
     fn to_be_bytes(&self) -> Self::Bytes;
 
+    /// From bytes in little endian
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
     /// From bytes in big endian
     fn from_be_bytes(bytes: Self::Bytes) -> Self;
 }
@@ -94,6 +97,11 @@ macro_rules! native {
                 Self::to_be_bytes(*self)
             }
 
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                Self::from_le_bytes(bytes)
+            }
+
             #[inline]
             fn from_be_bytes(bytes: Self::Bytes) -> Self {
                 Self::from_be_bytes(bytes)
@@ -188,6 +196,21 @@ unsafe impl NativeType for days_ms {
         result
     }
 
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        let mut days = [0; 4];
+        days[0] = bytes[0];
+        days[1] = bytes[1];
+        days[2] = bytes[2];
+        days[3] = bytes[3];
+        let mut ms = [0; 4];
+        ms[0] = bytes[4];
+        ms[1] = bytes[5];
+        ms[2] = bytes[6];
+        ms[3] = bytes[7];
+        Self([i32::from_le_bytes(days), i32::from_le_bytes(ms)])
+    }
+
     #[inline]
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         let mut days = [0; 4];
@@ -280,6 +303,29 @@ unsafe impl NativeType for months_days_ns {
         result
     }
 
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        let mut months = [0; 4];
+        months[0] = bytes[0];
+        months[1] = bytes[1];
+        months[2] = bytes[2];
+        months[3] = bytes[3];
+        let mut days = [0; 4];
+        days[0] = bytes[4];
+        days[1] = bytes[5];
+        days[2] = bytes[6];
+        days[3] = bytes[7];
+        let mut ns = [0; 8];
+        (0..8).for_each(|i| {
+            ns[i] = bytes[8 + i];
+        });
+        Self(
+            i32::from_le_bytes(months),
+            i32::from_le_bytes(days),
+            i64::from_le_bytes(ns),
+        )
+    }
+
     #[inline]
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         let mut months = [0; 4];
@@ -101,3 +101,33 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn timestamp_units() -> Result<()> {
+    use arrow2::datatypes::{DataType, TimeUnit};
+    let array = Int64Array::from_slice(&[1, 2]);
+    let seconds = array
+        .clone()
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let nanoseconds = array.to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+
+    let cmp = build_compare(&seconds, &seconds)?;
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+
+    assert!(build_compare(&seconds, &nanoseconds).is_err());
+    Ok(())
+}
+
+#[test]
+fn decimal_scales() -> Result<()> {
+    use arrow2::datatypes::DataType;
+    let array = Int128Array::from_slice(&[1, 2]);
+    let scale_2 = array.clone().to(DataType::Decimal(10, 2));
+    let scale_4 = array.to(DataType::Decimal(10, 4));
+
+    let cmp = build_compare(&scale_2, &scale_2)?;
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+
+    assert!(build_compare(&scale_2, &scale_4).is_err());
+    Ok(())
+}
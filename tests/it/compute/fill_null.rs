@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::fill_null::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::*;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), None]);
+    let value = PrimitiveScalar::new(DataType::Int32, Some(0));
+
+    let result = fill_null(&array, &value).unwrap();
+    let expected = Int32Array::from_slice(&[1, 0, 3, 0]);
+    assert_eq!(expected, result.as_ref());
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn primitive_logical_type() {
+    let array = Int32Array::from(&[Some(1), None]).to(DataType::Date32);
+    let value = PrimitiveScalar::new(DataType::Date32, Some(10));
+
+    let result = fill_null(&array, &value).unwrap();
+    let expected = Int32Array::from_slice(&[1, 10]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let value = PrimitiveScalar::new(DataType::Int32, Some(10));
+    assert!(fill_null(&array, &value).is_err());
+}
+
+#[test]
+fn null_value() {
+    let array = Int32Array::from(&[Some(1), None]);
+    let value = PrimitiveScalar::<i32>::new(DataType::Int32, None);
+
+    let result = fill_null(&array, &value).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, None, Some("b")]);
+    let value = Utf8Scalar::<i32>::new(Some("z"));
+
+    let result = fill_null(&array, &value).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(&["a", "z", "z", "b"]);
+    assert_eq!(expected, result.as_ref());
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn utf8_extension() {
+    let data_type = DataType::Extension("ext".to_string(), Box::new(DataType::Utf8), None);
+    let array = Utf8Array::<i32>::from(&[Some("a"), None]);
+    let (offsets, values) = (array.offsets().clone(), array.values().clone());
+    let array =
+        Utf8Array::<i32>::from_data(data_type.clone(), offsets, values, array.validity().clone());
+    let value = Utf8Scalar::<i32>::new(Some("z"));
+
+    let result = fill_null(&array, &value).unwrap();
+    assert_eq!(result.data_type(), &data_type);
+    let expected = Utf8Array::<i32>::from_slice(&["a", "z"]);
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(
+        result.values_iter().collect::<Vec<_>>(),
+        expected.values_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn fixed_size_binary_unsupported() {
+    assert!(!can_fill_null(&DataType::FixedSizeBinary(2)));
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None], 2);
+    assert!(coalesce(&[&array, &array]).is_err());
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from(&[Some(false), None]);
+    let value = BooleanScalar::new(Some(true));
+
+    let result = fill_null(&array, &value).unwrap();
+    let expected = BooleanArray::from_slice(&[false, true]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn list() {
+    let data = vec![Some(vec![Some(1i32), Some(2)]), None];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let values: Arc<dyn Array> = Arc::new(Int32Array::from_slice(&[3]));
+    let value = ListScalar::<i32>::new(array.data_type().clone(), Some(values));
+
+    let result = fill_null(&array, &value).unwrap();
+
+    let data = vec![Some(vec![Some(1i32), Some(2)]), Some(vec![Some(3)])];
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn struct_() {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let array = StructArray::from_data(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None])),
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), None])),
+        ],
+        Some([true, false].into()),
+    );
+    let value = StructScalar::new(
+        data_type.clone(),
+        Some(vec![
+            Arc::new(PrimitiveScalar::new(DataType::Int32, Some(2))),
+            Arc::new(Utf8Scalar::<i32>::new(Some("b"))),
+        ]),
+    );
+
+    let result = fill_null(&array, &value).unwrap();
+    let expected = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2])),
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("a"), None, Some("b"), None])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let value = Utf8Scalar::<i32>::new(Some("c"));
+    let result = fill_null(&array, &value).unwrap();
+    assert!(result.validity().is_none());
+
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    let result = result
+        .keys()
+        .values()
+        .iter()
+        .map(|key| values.value(*key as usize))
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec!["a", "c", "b", "c"]);
+}
+
+#[test]
+fn coalesce_primitive() {
+    let a = Int32Array::from(&[Some(1), None, None, None]);
+    let b = Int32Array::from(&[Some(4), Some(5), None, None]);
+    let c = Int32Array::from(&[Some(7), Some(8), Some(9), None]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(5), Some(9), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn coalesce_without_nulls() {
+    let a = Utf8Array::<i32>::from(&[Some("a"), None]);
+    let b = Utf8Array::<i32>::from(&[None, Some("b")]);
+
+    let result = coalesce(&[&a, &b]).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    assert_eq!(expected, result.as_ref());
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn coalesce_errors() {
+    assert!(coalesce(&[]).is_err());
+
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int64Array::from_slice(&[1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int32Array::from_slice(&[1]);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
+
+#[test]
+fn forward() {
+    let array = Int32Array::from(&[None, Some(1), None, None, Some(2), None]);
+
+    let result = fill_forward(&array, None).unwrap();
+    let expected = Int32Array::from(&[None, Some(1), Some(1), Some(1), Some(2), Some(2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_forward(&array, Some(1)).unwrap();
+    let expected = Int32Array::from(&[None, Some(1), Some(1), None, Some(2), Some(2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_forward(&array, Some(0)).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn forward_without_nulls() {
+    let array = Utf8Array::<i64>::from(&[Some("a"), None, None]);
+
+    let result = fill_forward(&array, None).unwrap();
+    let expected = Utf8Array::<i64>::from_slice(&["a", "a", "a"]);
+    assert_eq!(expected, result.as_ref());
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn backward() {
+    let array = Int32Array::from(&[None, Some(1), None, None, Some(2), None]);
+
+    let result = fill_backward(&array, None).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(1), Some(2), Some(2), Some(2), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_backward(&array, Some(1)).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(1), None, Some(2), Some(2), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn backward_dictionary() {
+    let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![None, Some("a"), None, Some("b")])
+        .unwrap();
+    let array: DictionaryArray<u8> = array.into();
+
+    let result = fill_backward(&array, None).unwrap();
+    assert!(result.validity().is_none());
+
+    let mut expected = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend(vec![Some("a"), Some("a"), Some("b"), Some("b")])
+        .unwrap();
+    let expected: DictionaryArray<u8> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
@@ -0,0 +1,131 @@
+use arrow2::array::*;
+use arrow2::compute::is_in::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let values = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+
+    let set = Int32Array::from_slice(&[4, 1, 10]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = is_in(&values, &set, NullBehavior::Skip).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, false, true]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn primitive_set_with_nulls() {
+    let values = Int64Array::from(&[Some(1), Some(2), None]);
+    let set = Int64Array::from(&[Some(1), None]);
+
+    // `2 IN (1, NULL)` is unknown
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(result, expected);
+
+    let result = is_in(&values, &set, NullBehavior::Skip).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, false]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn floats() {
+    let values = Float64Array::from_slice(&[0.0, -0.0, f64::NAN, 1.5, 2.0]);
+    let set = Float64Array::from_slice(&[-0.0, f64::NAN, 1.5]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from_slice(&[true, true, true, true, false]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn boolean() {
+    let values = BooleanArray::from(&[Some(true), Some(false), None]);
+    let set = BooleanArray::from_slice(&[false]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[Some(false), Some(true), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8() {
+    let values = Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None, Some("")]);
+    let set = Utf8Array::<i32>::from_slice(&["bb", "", "c"]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[Some(false), Some(true), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn binary() {
+    let values = BinaryArray::<i64>::from(&[Some(b"a".as_ref()), None, Some(b"c".as_ref())]);
+    let set = BinaryArray::<i64>::from(&[Some(b"c".as_ref()), None]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[None, None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = is_in(&values, &set, NullBehavior::Skip).unwrap();
+    let expected = BooleanArray::from_slice(&[false, false, true]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn dictionary() {
+    let mut values = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    values
+        .try_extend(vec![Some("a"), None, Some("b"), Some("a"), Some("c")])
+        .unwrap();
+    let values: DictionaryArray<i32> = values.into();
+
+    let set = Utf8Array::<i32>::from_slice(&["a", "c"]);
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(true), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = is_in(&values, &set, NullBehavior::Skip).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, false, true, true]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn dictionary_set() {
+    let values = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+
+    let mut set = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    set.try_extend(vec![Some("c"), Some("a")]).unwrap();
+    let set: DictionaryArray<u8> = set.into();
+
+    let result = is_in(&values, &set, NullBehavior::ThreeValued).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, true]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn different_types() {
+    let values = Int32Array::from_slice(&[1]);
+    let set = Int64Array::from_slice(&[1]);
+    assert!(is_in(&values, &set, NullBehavior::ThreeValued).is_err());
+
+    let values = Int32Array::from_slice(&[1]).to(DataType::Date32);
+    let set = Int32Array::from_slice(&[1]);
+    assert!(is_in(&values, &set, NullBehavior::ThreeValued).is_err());
+}
+
+#[test]
+fn consistency() {
+    use arrow2::datatypes::DataType::*;
+
+    let datatypes = vec![Null, Boolean, Int8, UInt64, Float32, Utf8, LargeBinary];
+    datatypes.into_iter().for_each(|data_type| {
+        let array = new_null_array(data_type.clone(), 10);
+        let set = new_empty_array(data_type.clone());
+        if can_is_in(&data_type) {
+            let result = is_in(array.as_ref(), set.as_ref(), NullBehavior::Skip).unwrap();
+            assert_eq!(result, BooleanArray::from_slice(&[false; 10]));
+        } else {
+            assert!(is_in(array.as_ref(), set.as_ref(), NullBehavior::Skip).is_err());
+        }
+    });
+}
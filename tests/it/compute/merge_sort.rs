@@ -228,3 +228,51 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn merge_sort_many_blocks() -> Result<()> {
+    // more rows than encoded at once by the comparator of multiple columns
+    let a00 = Int32Array::from_values((0..3000).map(|x| x / 2));
+    let a01 = Int32Array::from_values((0..2000).map(|x| x / 2));
+    let a10 = Int32Array::from_values((0..3000).map(|x| x % 2));
+    let a11 = Int32Array::from_values((0..2000).map(|x| x % 2));
+
+    let options = SortOptions::default();
+    let arrays0: Vec<&dyn Array> = vec![&a00, &a01];
+    let arrays1: Vec<&dyn Array> = vec![&a10, &a11];
+    let pairs = vec![(arrays0.as_ref(), &options), (arrays1.as_ref(), &options)];
+    let comparator = build_comparator(&pairs)?;
+    let slices =
+        merge_sort_slices(once(&(0, 0, 3000)), once(&(1, 0, 2000)), &comparator).to_vec(Some(4500));
+
+    // rows `(x / 2, x % 2)` of both inputs are interleaved
+    let column0 = take_arrays(&arrays0, slices.iter().copied(), None);
+    let column1 = take_arrays(&arrays1, slices.iter().copied(), None);
+    let expected0 = (0..1000)
+        .flat_map(|x| vec![x; 4])
+        .chain((2000..2500).map(|x| x / 2))
+        .collect::<Vec<_>>();
+    let expected1 = (0..1000)
+        .flat_map(|_| vec![0, 0, 1, 1])
+        .chain((2000..2500).map(|x| x % 2))
+        .collect::<Vec<_>>();
+    assert_eq!(Int32Array::from_slice(&expected0), column0.as_ref());
+    assert_eq!(Int32Array::from_slice(&expected1), column1.as_ref());
+    Ok(())
+}
+
+#[test]
+fn merge_dictionary_key_out_of_bounds() {
+    let keys = Int32Array::from_slice(&[0, 2]);
+    let values = Int32Array::from_slice(&[1, 2]);
+    let a0 = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+    let a1 = a0.clone();
+    let b0 = Int32Array::from_slice(&[0, 1]);
+    let b1 = b0.clone();
+
+    let options = SortOptions::default();
+    let arrays0: Vec<&dyn Array> = vec![&a0, &a1];
+    let arrays1: Vec<&dyn Array> = vec![&b0, &b1];
+    let pairs = vec![(arrays0.as_ref(), &options), (arrays1.as_ref(), &options)];
+    assert!(slices(&pairs).is_err());
+}
@@ -2,9 +2,11 @@ mod boolean;
 mod boolean_kleene;
 mod cast;
 mod concat;
+mod fill_null;
 mod filter;
 mod hash;
 mod if_then_else;
+mod is_in;
 mod length;
 #[cfg(feature = "regex")]
 mod like;
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::compute::sort::*;
 use arrow2::datatypes::*;
@@ -441,6 +443,175 @@ fn string_dicts() {
     );
 }
 
+#[test]
+fn decimal() {
+    primitive_arrays::<i128>(
+        &[None, Some(300), Some(-100), Some(200), None],
+        DataType::Decimal(5, 2),
+        SortOptions {
+            descending: false,
+            nulls_first: true,
+        },
+        &[None, None, Some(-100), Some(200), Some(300)],
+    );
+}
+
+#[test]
+fn timestamp_with_timezone() {
+    primitive_arrays::<i64>(
+        &[Some(3), None, Some(1), Some(2)],
+        DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+        SortOptions {
+            descending: true,
+            nulls_first: false,
+        },
+        &[Some(3), Some(2), Some(1), None],
+    );
+}
+
+#[test]
+fn fixed_size_binary() {
+    let input =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"bb"), None, Some(b"ab"), Some(b"ba")], 2);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[2, 3, 0, 1]));
+
+    let output = sort(&input, &options, None).unwrap();
+    let expected =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), Some(b"ba"), Some(b"bb"), None], 2);
+    assert_eq!(expected, output.as_ref());
+}
+
+#[test]
+fn structs() {
+    let a = Int32Array::from(&[Some(1), Some(0), Some(1), None, Some(0)]);
+    let b = Utf8Array::<i32>::from(&[Some("b"), Some("z"), Some("a"), Some("a"), None]);
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let input = StructArray::from_data(
+        data_type,
+        vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)],
+        Some([true, true, true, true, false].into()),
+    );
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    // the struct's null is last, and so are null fields
+    assert_eq!(output, Int32Array::from_slice(&[1, 2, 0, 3, 4]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, Some(3)).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[4, 3, 0]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[0, 2, 1, 3, 4]));
+
+    // the same order is used when sorting by multiple columns
+    let columns = vec![SortColumn {
+        values: &input,
+        options: Some(options),
+    }];
+    let output = lexsort_to_indices::<i32>(&columns, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[0, 2, 1, 3, 4]));
+}
+
+#[test]
+fn dict_null_values() {
+    // the value of key 1 is null
+    let keys = Int32Array::from_slice(&[0, 1, 2, 1]);
+    let values = Int32Array::from(&[Some(1), None, Some(3)]);
+    let input = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    let output = output.values().as_slice();
+    assert!(output == [1, 3, 0, 2] || output == [3, 1, 0, 2]);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    let output = output.values().as_slice();
+    assert!(output == [1, 3, 2, 0] || output == [3, 1, 2, 0]);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    let output = output.values().as_slice();
+    assert!(output == [2, 0, 1, 3] || output == [2, 0, 3, 1]);
+}
+
+#[test]
+fn primitive_dict() {
+    let mut input = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    input
+        .try_extend(vec![Some(10), None, Some(-1), Some(10), Some(3)])
+        .unwrap();
+    let input: DictionaryArray<u8> = input.into();
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    let output = output.values().as_slice();
+    assert!(output == [0, 3, 4, 2, 1] || output == [3, 0, 4, 2, 1]);
+
+    let output = sort_to_indices::<i32>(&input, &options, Some(3)).unwrap();
+    assert_eq!(output.values()[2], 4);
+}
+
+#[test]
+fn boolean_limit() {
+    let input = BooleanArray::from(&[None, Some(false), Some(true), Some(true), Some(false), None]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, Some(3)).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[2, 3, 1]));
+}
+
+#[test]
+fn limit_is_top_k() {
+    let input = Int64Array::from_slice(&[5, 3, 9, 1, 7, 2, 8]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, Some(3)).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[2, 6, 4]));
+
+    let columns = vec![SortColumn {
+        values: &input,
+        options: Some(options),
+    }];
+    let output = lexsort_to_indices::<i32>(&columns, Some(3)).unwrap();
+    assert_eq!(output, Int32Array::from_slice(&[2, 6, 4]));
+}
+
 /*
 #[test]
 fn list() {
@@ -572,6 +743,7 @@ fn consistency() {
         Timestamp(TimeUnit::Millisecond, None),
         Timestamp(TimeUnit::Microsecond, None),
         Timestamp(TimeUnit::Nanosecond, None),
+        Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
         Time64(TimeUnit::Microsecond),
         Time64(TimeUnit::Nanosecond),
         Date32,
@@ -582,6 +754,8 @@ fn consistency() {
         LargeUtf8,
         Binary,
         LargeBinary,
+        FixedSizeBinary(2),
+        Decimal(10, 2),
         Duration(TimeUnit::Second),
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
@@ -601,3 +775,161 @@ fn consistency() {
         }
     });
 }
+
+mod row {
+    use std::cmp::Ordering;
+
+    use arrow2::array::ord::build_compare;
+    use arrow2::compute::sort::row::encode;
+
+    use super::*;
+
+    // the reference: lexicographical comparison via `build_compare`
+    fn reference(columns: &[SortColumn], i: usize, j: usize) -> Ordering {
+        for column in columns {
+            let options = column.options.unwrap_or_default();
+            let array = column.values;
+            let ordering = match (array.is_valid(i), array.is_valid(j)) {
+                (true, true) => {
+                    let ordering = build_compare(array, array).unwrap()(i, j);
+                    if options.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+                (false, true) if options.nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if options.nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+                (false, false) => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn check(arrays: &[&dyn Array]) {
+        let all_options = [(false, false), (false, true), (true, false), (true, true)];
+        for (descending, nulls_first) in all_options.iter() {
+            let columns = arrays
+                .iter()
+                .map(|array| SortColumn {
+                    values: *array,
+                    options: Some(SortOptions {
+                        descending: *descending,
+                        nulls_first: *nulls_first,
+                    }),
+                })
+                .collect::<Vec<_>>();
+            let rows = encode(&columns).unwrap();
+            assert_eq!(rows.len(), arrays[0].len());
+            for i in 0..rows.len() {
+                for j in 0..rows.len() {
+                    assert_eq!(
+                        rows.compare(i, j),
+                        reference(&columns, i, j),
+                        "rows {} and {} with descending={} nulls_first={}",
+                        i,
+                        j,
+                        descending,
+                        nulls_first
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn primitives() {
+        let a = Int32Array::from(&[Some(1), None, Some(-1), Some(i32::MIN), Some(1), Some(0)]);
+        let b = Float64Array::from(&[
+            Some(0.0),
+            Some(-0.0),
+            None,
+            Some(f64::NAN),
+            Some(f64::NEG_INFINITY),
+            Some(-1.5),
+        ]);
+        let c = UInt8Array::from(&[Some(255), Some(0), None, Some(1), Some(1), Some(128)]);
+        check(&[&a, &b, &c]);
+        check(&[&b, &a]);
+    }
+
+    #[test]
+    fn strings() {
+        let a = Utf8Array::<i32>::from(&[
+            Some("a"),
+            Some("a\0"),
+            Some(""),
+            None,
+            Some("ab"),
+            Some("a\0b"),
+            Some("a"),
+        ]);
+        let b = BinaryArray::<i64>::from(&[
+            Some(b"\0".as_ref()),
+            Some(b"".as_ref()),
+            None,
+            Some(b"\xff".as_ref()),
+            Some(b"\0\0".as_ref()),
+            Some(b"\x01".as_ref()),
+            None,
+        ]);
+        let c = BooleanArray::from(&[
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+        ]);
+        check(&[&a, &b, &c]);
+        check(&[&c, &a]);
+    }
+
+    #[test]
+    fn dictionary() {
+        let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        a.try_extend(vec![Some("b"), None, Some("a"), Some("b"), Some("c")])
+            .unwrap();
+        let a: DictionaryArray<i32> = a.into();
+        let b = Int64Array::from(&[Some(1), Some(2), None, Some(0), Some(1)]);
+        check(&[&a, &b]);
+    }
+
+    #[test]
+    fn unsupported() {
+        let a = Int32Array::from_slice(&[1]);
+        let b = ListArray::<i32>::new_null(
+            DataType::List(Box::new(Field::new("a", DataType::Int32, true))),
+            1,
+        );
+        let columns = [
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ];
+        assert!(encode(&columns).is_err());
+    }
+
+    #[test]
+    fn dictionary_key_out_of_bounds() {
+        let keys = Int32Array::from_slice(&[0, 2]);
+        let values = Int32Array::from_slice(&[1, 2]);
+        let a = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+        let columns = [SortColumn {
+            values: &a,
+            options: None,
+        }];
+        assert!(encode(&columns).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::error::Result;
+use arrow2::io::ipc::read::MessageDecoder;
+use arrow2::io::ipc::write::{EncodedData, IpcWriteOptions, MessageEncoder};
+use arrow2::record_batch::RecordBatch;
+
+fn dictionary_batch(values: &[&str]) -> Result<RecordBatch> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().map(Some))?;
+    let array: DictionaryArray<i32> = array.into();
+    let ints = Int32Array::from_values(0..values.len() as i32);
+    RecordBatch::try_from_iter(vec![
+        ("a", Arc::new(ints) as Arc<dyn Array>),
+        ("b", Arc::new(array) as Arc<dyn Array>),
+    ])
+}
+
+/// Encodes `batches` into messages, as sent over a transport
+fn encode(batches: &[RecordBatch], options: IpcWriteOptions) -> Result<Vec<EncodedData>> {
+    let mut encoder = MessageEncoder::new(options);
+    let mut messages = vec![encoder.encode_schema(batches[0].schema())];
+    for batch in batches {
+        let (dictionaries, batch) = encoder.encode_batch(batch)?;
+        messages.extend(dictionaries);
+        messages.push(batch);
+    }
+    Ok(messages)
+}
+
+fn decode(decoder: &mut MessageDecoder, messages: &[EncodedData]) -> Result<Vec<RecordBatch>> {
+    let mut batches = vec![];
+    for message in messages {
+        if let Some(batch) = decoder.decode(&message.ipc_message, &message.arrow_data)? {
+            batches.push(batch);
+        }
+    }
+    Ok(batches)
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    // the second batch extends the dictionary of the first, the third replaces it
+    let batches = vec![
+        dictionary_batch(&["a", "b"])?,
+        dictionary_batch(&["a", "b", "c"])?,
+        dictionary_batch(&["d"])?,
+    ];
+    let messages = encode(&batches, IpcWriteOptions::default())?;
+    // the schema, 3 dictionaries and 3 batches
+    assert_eq!(messages.len(), 7);
+
+    let mut decoder = MessageDecoder::new();
+    assert_eq!(decode(&mut decoder, &messages)?, batches);
+    assert_eq!(decoder.schema(), Some(batches[0].schema()));
+    Ok(())
+}
+
+#[test]
+fn round_trip_big_endian() -> Result<()> {
+    let batches = vec![dictionary_batch(&["a", "b"])?];
+    let options = IpcWriteOptions::default().with_little_endian(false);
+    let messages = encode(&batches, options)?;
+
+    let mut decoder = MessageDecoder::new();
+    assert_eq!(decode(&mut decoder, &messages)?, batches);
+    Ok(())
+}
+
+#[test]
+fn known_schema() -> Result<()> {
+    let batches = vec![dictionary_batch(&["a", "b"])?];
+    let messages = encode(&batches, IpcWriteOptions::default())?;
+
+    // the schema is known by the receiver, and its message is not sent
+    let mut decoder = MessageDecoder::with_schema(batches[0].schema().clone(), true);
+    assert_eq!(decode(&mut decoder, &messages[1..])?, batches);
+    Ok(())
+}
+
+#[test]
+fn missing_schema() -> Result<()> {
+    let batches = vec![dictionary_batch(&["a", "b"])?];
+    let messages = encode(&batches, IpcWriteOptions::default())?;
+
+    let mut decoder = MessageDecoder::new();
+    assert!(decode(&mut decoder, &messages[1..]).is_err());
+    Ok(())
+}
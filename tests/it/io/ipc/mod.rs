@@ -1,4 +1,5 @@
 mod common;
+mod message;
 mod read;
 mod write;
 
@@ -1,7 +1,13 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::StreamWriter;
+use arrow2::record_batch::RecordBatch;
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -13,7 +19,7 @@ fn test_file(version: &str, file_name: &str) -> Result<()> {
     ))?;
 
     let metadata = read_stream_metadata(&mut file)?;
-    let reader = StreamReader::new(file, metadata);
+    let reader = StreamReader::new(file, metadata, None);
 
     // read expected JSON output
     let (schema, batches) = read_gzip_json(version, file_name)?;
@@ -99,3 +105,130 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+fn dictionary(values: &[&str]) -> Result<Arc<dyn Array>> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().map(Some))?;
+    let array: DictionaryArray<i32> = array.into();
+    Ok(Arc::new(array))
+}
+
+/// A stream whose batches have two dictionary-encoded columns, with a delta dictionary
+/// in the second batch
+fn dictionary_stream() -> Result<(Vec<RecordBatch>, Vec<u8>)> {
+    let dict_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new_dict("b", dict_type.clone(), true, 1, false),
+        Field::new_dict("c", dict_type, true, 2, false),
+        Field::new("d", DataType::Utf8, true),
+    ]));
+    let batches = vec![
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_slice([1, 2])),
+                dictionary(&["x", "y"])?,
+                dictionary(&["u", "v"])?,
+                Arc::new(Utf8Array::<i32>::from_slice(["e", "f"])),
+            ],
+        )?,
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_slice([3, 4, 5])),
+                dictionary(&["x", "y", "z"])?,
+                dictionary(&["u", "v", "w"])?,
+                Arc::new(Utf8Array::<i32>::from_slice(["g", "h", "i"])),
+            ],
+        )?,
+    ];
+
+    let mut data = vec![];
+    let mut writer = StreamWriter::try_new(&mut data, &schema)?;
+    for batch in &batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    drop(writer);
+    Ok((batches, data))
+}
+
+fn test_stream_projection(projection: Vec<usize>) -> Result<()> {
+    let (batches, data) = dictionary_stream()?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, Some(projection.clone()));
+
+    let fields = batches[0].schema().fields();
+    let expected_fields = projection.iter().map(|i| fields[*i].clone()).collect();
+    assert_eq!(reader.schema().as_ref(), &Schema::new(expected_fields));
+
+    let result = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result.len(), batches.len());
+    for (result, batch) in result.iter().zip(batches.iter()) {
+        let expected = projection
+            .iter()
+            .map(|i| batch.column(*i).clone())
+            .collect::<Vec<_>>();
+        assert_eq!(result.columns(), expected.as_slice());
+    }
+    Ok(())
+}
+
+#[test]
+fn read_projected_stream() -> Result<()> {
+    // skips both dictionaries
+    test_stream_projection(vec![0, 3])?;
+    // skips the dictionary of `b`
+    test_stream_projection(vec![0, 2])?;
+    test_stream_projection(vec![1])?;
+    test_stream_projection(vec![0, 1, 2, 3])
+}
+
+#[test]
+fn read_projected_nested_stream() -> Result<()> {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![Some(3)])])?;
+    let list: ListArray<i32> = list.into();
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>,
+        ),
+        ("b", Arc::new(list)),
+        (
+            "c",
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None])),
+        ),
+    ])?;
+
+    let mut data = vec![];
+    {
+        let mut writer = StreamWriter::try_new(&mut data, batch.schema())?;
+        writer.write(&batch)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+    }
+
+    for projection in [vec![0], vec![1], vec![2], vec![0, 2], vec![1, 2]] {
+        let mut reader = Cursor::new(&data);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let reader = StreamReader::new(reader, metadata, Some(projection.clone()));
+        let result = reader
+            .map(|x| x.map(|x| x.unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(result.len(), 2);
+        let expected = projection
+            .iter()
+            .map(|i| batch.column(*i).clone())
+            .collect::<Vec<_>>();
+        for result in result {
+            assert_eq!(result.columns(), expected.as_slice());
+        }
+    }
+    Ok(())
+}
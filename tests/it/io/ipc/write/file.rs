@@ -201,3 +201,227 @@ fn write_sliced_list() -> Result<()> {
     let batch = RecordBatch::try_from_iter(vec![("a", array)]).unwrap();
     round_trip(batch)
 }
+
+/// Opens `file` to append batches to it, truncating it to its new end when finished.
+fn append(file: &mut Cursor<Vec<u8>>) -> Result<FileWriter<'_, Cursor<Vec<u8>>>> {
+    FileWriter::try_new_append_with(file, |file, length| {
+        file.get_mut().truncate(length as usize);
+        Ok(())
+    })
+}
+
+fn dictionary_batch(values: &[&str]) -> Result<RecordBatch> {
+    use std::sync::Arc;
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().map(Some))?;
+    let array: DictionaryArray<i32> = array.into();
+    let ints = Int32Array::from_slice(&[1, 2, 3][..values.len()]);
+    RecordBatch::try_from_iter(vec![
+        ("a", Arc::new(ints) as Arc<dyn Array>),
+        ("b", Arc::new(array) as Arc<dyn Array>),
+    ])
+}
+
+#[test]
+fn append_and_read_batch() -> Result<()> {
+    let first = dictionary_batch(&["a", "b"])?;
+    let second = dictionary_batch(&["a", "b", "b"])?;
+    let mut file = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = FileWriter::try_new(&mut file, first.schema())?;
+        writer.write(&first)?;
+        writer.write(&second)?;
+        writer.finish()?;
+    }
+
+    let third = dictionary_batch(&["a", "a", "b"])?;
+    {
+        let mut writer = append(&mut file)?;
+        writer.write(&third)?;
+        writer.finish()?;
+    }
+
+    file.set_position(0);
+    let metadata = read_file_metadata(&mut file)?;
+    assert_eq!(metadata.num_batches(), 3);
+    let mut reader = FileReader::new(&mut file, metadata, None);
+    assert_eq!(reader.read_batch(2)?, third);
+    assert_eq!(reader.read_batch(0)?, first);
+    assert!(reader.read_batch(3).is_err());
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![first, second, third]);
+    Ok(())
+}
+
+#[test]
+fn append_replaced_dictionary() -> Result<()> {
+    let first = dictionary_batch(&["a", "b"])?;
+    let mut file = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = FileWriter::try_new(&mut file, first.schema())?;
+        writer.write(&first)?;
+        writer.finish()?;
+    }
+
+    let mut writer = append(&mut file)?;
+    assert!(writer.write(&dictionary_batch(&["c"])?).is_err());
+    Ok(())
+}
+
+#[test]
+fn append_to_empty_file() -> Result<()> {
+    let batch = dictionary_batch(&["a", "b"])?;
+    let mut file = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = FileWriter::try_new(&mut file, batch.schema())?;
+        writer.finish()?;
+    }
+    {
+        let mut writer = append(&mut file)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+    }
+
+    file.set_position(0);
+    let metadata = read_file_metadata(&mut file)?;
+    let reader = FileReader::new(&mut file, metadata, None);
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn append_to_file_with_longer_footer() -> Result<()> {
+    use std::convert::TryInto;
+
+    let first = dictionary_batch(&["a", "b"])?;
+    let mut file = Cursor::new(Vec::<u8>::new());
+    {
+        let mut writer = FileWriter::try_new(&mut file, first.schema())?;
+        writer.write(&first)?;
+        writer.finish()?;
+    }
+    // pad the region between the end-of-stream marker and the footer, as another
+    // producer may do; the file remains valid since the footer is read from the end
+    let data = file.get_mut();
+    let footer_len = i32::from_le_bytes(data[data.len() - 10..data.len() - 6].try_into().unwrap());
+    let footer_start = data.len() - 10 - footer_len as usize;
+    data.splice(footer_start..footer_start, std::iter::repeat(0).take(1024));
+    let padded_len = data.len();
+
+    let second = dictionary_batch(&["a", "b", "b"])?;
+    {
+        let mut writer = append(&mut file)?;
+        writer.write(&second)?;
+    }
+    // the writer is finished (and the file truncated) when dropped
+    assert!(file.get_ref().len() < padded_len);
+
+    file.set_position(0);
+    let metadata = read_file_metadata(&mut file)?;
+    let reader = FileReader::new(&mut file, metadata, None);
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, vec![first, second]);
+    Ok(())
+}
+
+#[test]
+fn append_to_fs_file() -> Result<()> {
+    use std::convert::TryInto;
+
+    let first = dictionary_batch(&["a", "b"])?;
+    let path = std::env::temp_dir().join(format!("arrow2-append-{}.arrow", std::process::id()));
+    {
+        let mut file = std::fs::File::create(&path)?;
+        let mut writer = FileWriter::try_new(&mut file, first.schema())?;
+        writer.write(&first)?;
+        writer.finish()?;
+    }
+    // pad the region before the footer, so that the rewritten end of the file is shorter
+    let mut data = std::fs::read(&path)?;
+    let footer_len = i32::from_le_bytes(data[data.len() - 10..data.len() - 6].try_into().unwrap());
+    let footer_start = data.len() - 10 - footer_len as usize;
+    data.splice(footer_start..footer_start, std::iter::repeat(0).take(1024));
+    std::fs::write(&path, &data)?;
+
+    let second = dictionary_batch(&["a", "b", "b"])?;
+    {
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path)?;
+        let mut writer = FileWriter::try_new_append(&mut file)?;
+        writer.write(&second)?;
+        writer.finish()?;
+    }
+
+    let mut file = std::fs::File::open(&path)?;
+    let metadata = read_file_metadata(&mut file)?;
+    let reader = FileReader::new(&mut file, metadata, None);
+    let batches = reader.collect::<Result<Vec<_>>>();
+    std::fs::remove_file(&path)?;
+    assert_eq!(batches?, vec![first, second]);
+    Ok(())
+}
+
+#[test]
+fn write_big_endian() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::datatypes::{DataType, IntervalUnit};
+    use arrow2::types::{days_ms, months_days_ns};
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1i64), None]),
+        None,
+        Some(vec![Some(-3)]),
+    ])?;
+    let columns = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(-258)])) as Arc<dyn Array>,
+        Arc::new(Float64Array::from_slice(&[0.5, -1.25, 1e10])),
+        Arc::new(
+            Int128Array::from(&[Some(12345), None, Some(-23456)]).to(DataType::Decimal(10, 2)),
+        ),
+        Arc::new(
+            PrimitiveArray::<days_ms>::from_slice(&[
+                days_ms::new(1, 2),
+                days_ms::new(-3, 400),
+                days_ms::new(0, -1),
+            ])
+            .to(DataType::Interval(IntervalUnit::DayTime)),
+        ),
+        Arc::new(
+            PrimitiveArray::<months_days_ns>::from_slice(&[
+                months_days_ns::new(1, 2, 3),
+                months_days_ns::new(-1, 0, 1 << 40),
+                months_days_ns::new(0, 0, -1),
+            ])
+            .to(DataType::Interval(IntervalUnit::MonthDayNano)),
+        ),
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("bcd"), None])),
+        Arc::new(BinaryArray::<i64>::from_slice(&[b"a".as_ref(), b"", b"ef"])),
+        dictionary_batch(&["a", "b", "a"])?.column(1).clone(),
+        list.into_arc(),
+    ];
+    let names = ["a", "b", "c", "d", "e", "f", "g", "h", "i"];
+    let batch = RecordBatch::try_from_iter(names.iter().zip(columns))?;
+
+    let write = |is_little_endian: bool| -> Result<Vec<u8>> {
+        let mut result = Vec::<u8>::new();
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V5)?
+            .with_little_endian(is_little_endian);
+        let mut writer = FileWriter::try_new_with_options(&mut result, batch.schema(), options)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        drop(writer);
+        Ok(result)
+    };
+    let big_endian = write(false)?;
+    assert_ne!(big_endian, write(true)?);
+
+    let mut reader = Cursor::new(big_endian);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(&mut reader, metadata, None);
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, vec![batch]);
+    Ok(())
+}
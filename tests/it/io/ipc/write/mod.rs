@@ -1,2 +1,5 @@
 mod file;
 mod stream;
+#[cfg(feature = "io_ipc_async")]
+mod stream_async;
+mod tensor;
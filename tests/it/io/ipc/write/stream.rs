@@ -25,7 +25,7 @@ fn test_file(version: &str, file_name: &str) {
 
     let mut reader = Cursor::new(result);
     let metadata = read_stream_metadata(&mut reader).unwrap();
-    let reader = StreamReader::new(reader, metadata);
+    let reader = StreamReader::new(reader, metadata, None);
 
     let schema = reader.schema().clone();
 
@@ -132,3 +132,123 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn write_delta_dictionary() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::write::common::{DictionaryEmit, DictionaryTracker};
+    use arrow2::record_batch::RecordBatch;
+
+    let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    let schema = Arc::new(Schema::new(vec![Field::new_dict(
+        "a", data_type, true, 0, false,
+    )]));
+    let dictionary = |keys: &[Option<i32>], values: &[&str]| -> Arc<dyn Array> {
+        Arc::new(DictionaryArray::<i32>::from_data(
+            Int32Array::from(keys),
+            Arc::new(Utf8Array::<i32>::from_slice(values)),
+        ))
+    };
+    let arrays = vec![
+        dictionary(&[Some(0), Some(1)], &["a", "b"]),
+        // extends the previous dictionary
+        dictionary(&[Some(2), None, Some(0)], &["a", "b", "c"]),
+        dictionary(&[Some(3)], &["a", "b", "c", "d"]),
+        // replaces the previous dictionary
+        dictionary(&[Some(0)], &["e"]),
+    ];
+
+    let mut tracker = DictionaryTracker::new(false);
+    let emitted = arrays
+        .iter()
+        .map(|array| tracker.insert_with_delta(0, array))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(matches!(emitted[0], DictionaryEmit::Full(_)));
+    match &emitted[1] {
+        DictionaryEmit::Delta(values) => assert_eq!(
+            values.as_ref(),
+            &Utf8Array::<i32>::from_slice(&["c"]) as &dyn Array
+        ),
+        _ => panic!("the second dictionary must be a delta"),
+    }
+    assert!(matches!(emitted[2], DictionaryEmit::Delta(_)));
+    assert!(matches!(emitted[3], DictionaryEmit::Full(_)));
+    assert!(matches!(
+        tracker.insert_with_delta(0, &arrays[3])?,
+        DictionaryEmit::Skip
+    ));
+    assert!(!tracker.insert(0, &arrays[3])?);
+    assert!(tracker.insert(0, &arrays[2])?);
+
+    let batches = arrays
+        .into_iter()
+        .map(|array| RecordBatch::try_new(schema.clone(), vec![array]))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut result = Vec::<u8>::new();
+    {
+        let mut writer = StreamWriter::try_new(&mut result, &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None);
+    let read = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(read, batches);
+    Ok(())
+}
+
+#[test]
+fn read_delta_dictionary_of_other_type() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::read::read_dictionary;
+    use arrow2::io::ipc::root_as_message;
+    use arrow2::io::ipc::write::MessageEncoder;
+    use arrow2::record_batch::RecordBatch;
+
+    let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    let schema = Arc::new(Schema::new(vec![Field::new_dict(
+        "a", data_type, true, 0, false,
+    )]));
+    let batch = |values: &[&str]| {
+        let array = DictionaryArray::<i32>::from_data(
+            Int32Array::from_slice(&[0]),
+            Arc::new(Utf8Array::<i32>::from_slice(values)),
+        );
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(array)])
+    };
+
+    let mut encoder = MessageEncoder::new(IpcWriteOptions::default());
+    encoder.encode_batch(&batch(&["a"])?)?;
+    let (mut dictionaries, _) = encoder.encode_batch(&batch(&["a", "b"])?)?;
+    let delta = dictionaries.pop().unwrap();
+    let message = root_as_message(&delta.ipc_message).unwrap();
+    let dictionary_batch = message.header_as_dictionary_batch().unwrap();
+    assert!(dictionary_batch.isDelta());
+
+    // the current dictionary is not of the type of the delta
+    let current: Arc<dyn Array> = Arc::new(Int32Array::from_slice(&[1]));
+    let result = read_dictionary(
+        dictionary_batch,
+        &schema,
+        true,
+        &mut [Some(current)],
+        &mut Cursor::new(delta.arrow_data),
+        0,
+    );
+    assert!(result.is_err());
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use futures::{executor::block_on, TryStreamExt};
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{read_stream_metadata, AsyncStreamReader, StreamReader};
+use arrow2::io::ipc::write::{AsyncStreamWriter, StreamWriter};
+use arrow2::record_batch::RecordBatch;
+
+fn batches() -> Result<(Arc<Schema>, Vec<RecordBatch>)> {
+    let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new_dict("b", data_type, true, 0, false),
+    ]));
+    let dictionary = |keys: &[Option<i32>], values: &[&str]| -> Arc<dyn Array> {
+        Arc::new(DictionaryArray::<i32>::from_data(
+            Int32Array::from(keys),
+            Arc::new(Utf8Array::<i32>::from_slice(values)),
+        ))
+    };
+    let batches = vec![
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(&[Some(1), None])),
+                dictionary(&[Some(0), Some(1)], &["a", "b"]),
+            ],
+        )?,
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(&[Some(3)])),
+                dictionary(&[Some(2)], &["a", "b", "c"]),
+            ],
+        )?,
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(&[None, Some(4)])),
+                dictionary(&[None, Some(0)], &["d"]),
+            ],
+        )?,
+    ];
+    Ok((schema, batches))
+}
+
+async fn write_async(schema: &Schema, batches: &[RecordBatch]) -> Result<Vec<u8>> {
+    let mut writer = AsyncStreamWriter::try_new(futures::io::Cursor::new(vec![]), schema).await?;
+    for batch in batches {
+        writer.write(batch).await?;
+    }
+    writer.finish().await?;
+    Ok(writer.into_inner().into_inner())
+}
+
+async fn read_async(data: Vec<u8>) -> Result<(Arc<Schema>, Vec<RecordBatch>)> {
+    let reader = AsyncStreamReader::try_new(futures::io::Cursor::new(data)).await?;
+    let schema = reader.schema().clone();
+    let batches = reader.into_stream().try_collect().await?;
+    Ok((schema, batches))
+}
+
+#[test]
+fn async_roundtrip() -> Result<()> {
+    let (schema, batches) = batches()?;
+
+    let data = block_on(write_async(&schema, &batches))?;
+    let (read_schema, read_batches) = block_on(read_async(data))?;
+
+    assert_eq!(read_schema, schema);
+    assert_eq!(read_batches, batches);
+    Ok(())
+}
+
+#[test]
+fn async_write_sync_read() -> Result<()> {
+    let (schema, batches) = batches()?;
+
+    let data = block_on(write_async(&schema, &batches))?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None);
+    let read_batches = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(read_batches, batches);
+    Ok(())
+}
+
+#[test]
+fn sync_write_async_read() -> Result<()> {
+    let (schema, batches) = batches()?;
+
+    let mut data = vec![];
+    {
+        let mut writer = StreamWriter::try_new(&mut data, &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+    let (read_schema, read_batches) = block_on(read_async(data.clone()))?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(read_batches, batches);
+
+    // without the end-of-stream marker
+    data.truncate(data.len() - 8);
+    let (_, read_batches) = block_on(read_async(data))?;
+    assert_eq!(read_batches, batches);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::io::Cursor;
+
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{read_sparse_tensor, read_tensor};
+use arrow2::io::ipc::write::{write_sparse_tensor, write_tensor, IpcWriteOptions};
+use arrow2::tensor::{SparseTensor, Tensor};
+
+#[test]
+fn tensor() -> Result<()> {
+    let values = Buffer::from(&[1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let names = Some(vec!["rows".to_string(), "columns".to_string()]);
+    let tensor = Tensor::try_new(DataType::Float64, values, vec![2, 3], None, names)?;
+
+    let mut data = vec![];
+    write_tensor(&mut data, &tensor, &IpcWriteOptions::default())?;
+    let read = read_tensor::<_, f64>(&mut Cursor::new(data))?;
+
+    assert_eq!(read.shape(), tensor.shape());
+    assert_eq!(read.names(), tensor.names());
+    assert_eq!(read.strides(), vec![3, 1]);
+    assert_eq!(read.values(), tensor.values());
+    assert_eq!(read.data_type(), &DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn tensor_strided() -> Result<()> {
+    let values = Buffer::from(&[1u8, 2, 3, 4, 5]);
+    let tensor = Tensor::try_new(DataType::UInt8, values, vec![3], Some(vec![2]), None)?;
+
+    let mut data = vec![];
+    write_tensor(&mut data, &tensor, &IpcWriteOptions::default())?;
+    let read = read_tensor::<_, u8>(&mut Cursor::new(data))?;
+
+    assert_eq!(read, tensor);
+    assert_eq!(read.value(&[2]), 5);
+    Ok(())
+}
+
+#[test]
+fn tensor_wrong_type() -> Result<()> {
+    let values = Buffer::from(&[1i32, 2]);
+    let tensor = Tensor::try_new(DataType::Int32, values, vec![2], None, None)?;
+
+    let mut data = vec![];
+    write_tensor(&mut data, &tensor, &IpcWriteOptions::default())?;
+    assert!(read_tensor::<_, i64>(&mut Cursor::new(data.clone())).is_err());
+    assert!(read_sparse_tensor::<_, i32>(&mut Cursor::new(data)).is_err());
+    Ok(())
+}
+
+#[test]
+fn sparse_tensor() -> Result<()> {
+    let values = Buffer::from(&[0i32, 0, 3, 0, 0, 6, 0]);
+    let tensor = Tensor::try_new(DataType::Int32, values, vec![7], None, None)?;
+    let sparse = SparseTensor::from_dense(&tensor);
+
+    let mut data = vec![];
+    write_sparse_tensor(&mut data, &sparse, &IpcWriteOptions::default())?;
+    let read = read_sparse_tensor::<_, i32>(&mut Cursor::new(data))?;
+
+    assert_eq!(read, sparse);
+    assert_eq!(read.to_dense(), tensor);
+    Ok(())
+}
@@ -488,3 +488,135 @@ fn arrow_type() -> Result<()> {
     assert_eq!(new_batches, vec![batch]);
     Ok(())
 }
+
+#[cfg(feature = "compute")]
+#[test]
+fn multi_file_evolved_schema() -> Result<()> {
+    let schema1 = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let batch1 = RecordBatch::try_new(
+        Arc::new(schema1.clone()),
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2])),
+            Arc::new(Utf8Array::<i32>::from(&[Some("x"), None])),
+        ],
+    )?;
+    let schema2 = Schema::new(vec![
+        Field::new("c", DataType::Float64, false),
+        Field::new("b", DataType::LargeUtf8, false),
+        Field::new("a", DataType::Int64, false),
+    ]);
+    let batch2 = RecordBatch::try_new(
+        Arc::new(schema2.clone()),
+        vec![
+            Arc::new(Float64Array::from_slice(&[0.5])),
+            Arc::new(Utf8Array::<i64>::from_slice(&["y"])),
+            Arc::new(Int64Array::from_slice(&[i64::MAX])),
+        ],
+    )?;
+
+    let file1 = integration_write(&schema1, &[batch1])?;
+    let file2 = integration_write(&schema2, &[batch2])?;
+
+    let reader =
+        MultiRecordReader::try_new(vec![Cursor::new(file1), Cursor::new(file2)], None, None)?;
+    let expected_schema = Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::LargeUtf8, true),
+        Field::new("c", DataType::Float64, true),
+    ]);
+    assert_eq!(reader.schema().as_ref(), &expected_schema);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 2);
+    let expected = [
+        (
+            Int64Array::from_slice(&[1, 2]),
+            Utf8Array::<i64>::from(&[Some("x"), None]),
+            Float64Array::from(&[None, None]),
+        ),
+        (
+            Int64Array::from_slice(&[i64::MAX]),
+            Utf8Array::<i64>::from_slice(&["y"]),
+            Float64Array::from_slice(&[0.5]),
+        ),
+    ];
+    for (batch, (a, b, c)) in batches.iter().zip(expected.iter()) {
+        assert_eq!(batch.schema().as_ref(), &expected_schema);
+        assert_eq!(batch.column(0).as_ref(), a as &dyn Array);
+        assert_eq!(batch.column(1).as_ref(), b as &dyn Array);
+        assert_eq!(batch.column(2).as_ref(), c as &dyn Array);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "compute")]
+#[test]
+fn multi_file_projection_and_limit() -> Result<()> {
+    let schema1 = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    let batch1 = RecordBatch::try_new(
+        Arc::new(schema1.clone()),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2]))],
+    )?;
+    let schema2 = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Boolean, false),
+    ]);
+    let batch2 = RecordBatch::try_new(
+        Arc::new(schema2.clone()),
+        vec![
+            Arc::new(Int32Array::from_slice(&[3, 4])),
+            Arc::new(BooleanArray::from_slice(&[true, false])),
+        ],
+    )?;
+
+    let file1 = integration_write(&schema1, &[batch1])?;
+    let file2 = integration_write(&schema2, &[batch2])?;
+
+    // only "b", which the first file does not have
+    let reader = MultiRecordReader::try_new(
+        vec![Cursor::new(file1), Cursor::new(file2)],
+        Some(vec![1]),
+        Some(3),
+    )?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let arrays = batches
+        .iter()
+        .map(|batch| batch.column(0).clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        arrays[0].as_ref(),
+        &BooleanArray::from(&[None, None]) as &dyn Array
+    );
+    assert_eq!(
+        arrays[1].as_ref(),
+        &BooleanArray::from_slice(&[true]) as &dyn Array
+    );
+    assert_eq!(arrays.len(), 2);
+    Ok(())
+}
+
+#[cfg(feature = "compute")]
+#[test]
+fn multi_file_incompatible_types() -> Result<()> {
+    let schema1 = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    let batch1 = RecordBatch::try_new(
+        Arc::new(schema1.clone()),
+        vec![Arc::new(Int32Array::from_slice(&[1]))],
+    )?;
+    let schema2 = Schema::new(vec![Field::new("a", DataType::Utf8, false)]);
+    let batch2 = RecordBatch::try_new(
+        Arc::new(schema2.clone()),
+        vec![Arc::new(Utf8Array::<i32>::from_slice(&["a"]))],
+    )?;
+
+    let file1 = integration_write(&schema1, &[batch1])?;
+    let file2 = integration_write(&schema2, &[batch2])?;
+
+    let result =
+        MultiRecordReader::try_new(vec![Cursor::new(file1), Cursor::new(file2)], None, None);
+    assert!(result.is_err());
+    Ok(())
+}
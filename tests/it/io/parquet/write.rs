@@ -366,3 +366,1529 @@ fn i32_optional_v2_dict() -> Result<()> {
         Encoding::RleDictionary,
     )
 }
+
+#[test]
+fn int64_optional_v1_delta() -> Result<()> {
+    round_trip(
+        0,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn int64_required_v2_delta() -> Result<()> {
+    round_trip(
+        0,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn timestamp_optional_v2_delta() -> Result<()> {
+    round_trip(
+        4,
+        true,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn uint32_optional_v1_delta() -> Result<()> {
+    round_trip(
+        5,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn f64_optional_v1_byte_stream_split() -> Result<()> {
+    round_trip(
+        1,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::ByteStreamSplit,
+    )
+}
+
+#[test]
+fn f64_optional_v2_byte_stream_split() -> Result<()> {
+    round_trip(
+        1,
+        true,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::ByteStreamSplit,
+    )
+}
+
+#[test]
+fn bool_optional_v1_rle() -> Result<()> {
+    round_trip(
+        3,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
+#[test]
+fn bool_required_v2_rle() -> Result<()> {
+    round_trip(
+        3,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
+#[test]
+fn int64_optional_v2_dict() -> Result<()> {
+    round_trip(
+        0,
+        true,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::RleDictionary,
+    )
+}
+
+#[test]
+fn f64_optional_v1_dict() -> Result<()> {
+    round_trip(
+        1,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::RleDictionary,
+    )
+}
+
+#[test]
+fn utf8_optional_v1_dict() -> Result<()> {
+    round_trip(
+        2,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::RleDictionary,
+    )
+}
+
+#[test]
+fn utf8_required_v2_dict() -> Result<()> {
+    round_trip(
+        2,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::RleDictionary,
+    )
+}
+
+#[test]
+fn delta_many_values() -> Result<()> {
+    let mut int32 = (0..1000)
+        .map(|x| {
+            if x % 7 == 0 {
+                None
+            } else {
+                Some(x * x - 500 * x)
+            }
+        })
+        .collect::<Vec<_>>();
+    // deltas that overflow 32 bits
+    int32[1] = Some(i32::MIN);
+    int32[2] = Some(i32::MAX);
+    int32[3] = Some(i32::MIN);
+    let int32 = Int32Array::from(int32);
+    let mut int64 = (0..1000)
+        .map(|x| Some(x * 1_000_000_007))
+        .collect::<Vec<_>>();
+    int64[10] = Some(i64::MIN);
+    int64[11] = Some(i64::MAX);
+    int64[12] = None;
+    let int64 = Int64Array::from(int64);
+    let utf8 = (0..1000).map(|x| "a".repeat(x % 17)).collect::<Vec<_>>();
+    let utf8 = Utf8Array::<i32>::from_slice(&utf8);
+
+    for version in [Version::V1, Version::V2] {
+        round_trip_columns(
+            vec![
+                Arc::new(int32.clone()),
+                Arc::new(int64.clone()),
+                Arc::new(utf8.clone()),
+            ],
+            vec![
+                Encoding::DeltaBinaryPacked,
+                Encoding::DeltaBinaryPacked,
+                Encoding::DeltaLengthByteArray,
+            ],
+            version,
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes a unique and a repeated utf8 column of `length` rows with dictionary encoding,
+/// returning whether each column chunk was dictionary-encoded.
+fn dictionary_encoded(length: usize, dictionary_options: DictionaryOptions) -> Result<Vec<bool>> {
+    let unique = (0..length)
+        .map(|x| format!("{:020}", x))
+        .collect::<Vec<_>>();
+    let unique = Utf8Array::<i32>::from_slice(&unique);
+    let repeated = (0..length)
+        .map(|x| format!("{}", x % 3))
+        .collect::<Vec<_>>();
+    let repeated = Utf8Array::<i32>::from_slice(&repeated);
+    let columns: Vec<Arc<dyn Array>> = vec![Arc::new(unique), Arc::new(repeated)];
+    let schema = Schema::new(vec![
+        Field::new("a0", DataType::Utf8, false),
+        Field::new("a1", DataType::Utf8, false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::RleDictionary; 2],
+    )?;
+    row_groups.set_dictionary_options(dictionary_options);
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let encoded = reader.metadata().row_groups[0]
+        .columns()
+        .iter()
+        .map(|column| {
+            column
+                .column_encoding()
+                .contains(&Encoding::RleDictionary.into())
+        })
+        .collect();
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(encoded)
+}
+
+#[test]
+fn dict_fallback_to_plain() -> Result<()> {
+    // the unique values' dictionary is larger than `max_size`
+    let dictionary_options = DictionaryOptions {
+        max_size: 1024,
+        max_distinct_ratio: 1.0,
+    };
+    assert_eq!(
+        dictionary_encoded(100, dictionary_options)?,
+        vec![false, true]
+    );
+    Ok(())
+}
+
+#[test]
+fn dict_fallback_distinct_ratio() -> Result<()> {
+    // unique values have a distinct ratio of 1
+    assert_eq!(
+        dictionary_encoded(100, DictionaryOptions::default())?,
+        vec![false, true]
+    );
+
+    let dictionary_options = DictionaryOptions {
+        max_distinct_ratio: 1.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        dictionary_encoded(100, dictionary_options)?,
+        vec![true, true]
+    );
+    Ok(())
+}
+
+#[test]
+fn encoding_not_supported() {
+    assert!(!can_encode(&DataType::Float64, Encoding::DeltaBinaryPacked));
+    assert!(!can_encode(&DataType::Int32, Encoding::ByteStreamSplit));
+    assert!(!can_encode(&DataType::Boolean, Encoding::RleDictionary));
+    assert!(can_encode(&DataType::Utf8, Encoding::RleDictionary));
+}
+
+fn round_trip_nested(columns: Vec<Arc<dyn Array>>, version: Version) -> Result<()> {
+    let encodings = vec![Encoding::Plain; columns.len()];
+    round_trip_columns(columns, encodings, version)
+}
+
+/// Writes `columns` to a single row group and reads them back with [`RecordReader`].
+fn round_trip_columns(
+    columns: Vec<Arc<dyn Array>>,
+    encodings: Vec<Encoding>,
+    version: Version,
+) -> Result<()> {
+    let fields = columns
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(&format!("a{}", i), array.data_type().clone(), true))
+        .collect();
+    let schema = Schema::new(fields);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version,
+    };
+
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+    let iter = vec![Ok(batch.clone())];
+
+    let row_groups = RowGroupIterator::try_new(iter.into_iter(), &schema, options, encodings)?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+
+    let data = writer.into_inner();
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(())
+}
+
+fn struct_() -> StructArray {
+    // {a: 1, b: "a", c: true}, None, {a: None, b: "c", c: None}, {a: 4, b: None, c: false}
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int64Array::from(&[Some(1), Some(2), None, Some(4)])),
+            Arc::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("b"),
+                Some("c"),
+                None,
+            ])),
+            Arc::new(BooleanArray::from(&[
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+            ])),
+        ],
+        Some(Bitmap::from([true, false, true, true])),
+    )
+}
+
+#[test]
+fn struct_optional_v1() -> Result<()> {
+    round_trip_nested(vec![Arc::new(struct_())], Version::V1)
+}
+
+#[test]
+fn struct_optional_v2() -> Result<()> {
+    round_trip_nested(vec![Arc::new(struct_())], Version::V2)
+}
+
+#[test]
+fn struct_and_primitive() -> Result<()> {
+    let other = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    round_trip_nested(
+        vec![Arc::new(struct_()), Arc::new(other), Arc::new(struct_())],
+        Version::V1,
+    )
+}
+
+#[test]
+fn struct_required() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Float64, true),
+    ]);
+    let array = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+            Arc::new(Float64Array::from(&[Some(1.0), None, Some(3.0)])),
+        ],
+        None,
+    );
+    round_trip_nested(vec![Arc::new(array)], Version::V2)
+}
+
+#[test]
+fn struct_nested_struct() -> Result<()> {
+    let inner = struct_();
+    let data_type = DataType::Struct(vec![
+        Field::new("inner", inner.data_type().clone(), true),
+        Field::new("d", DataType::Int32, false),
+    ]);
+    let array = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(inner),
+            Arc::new(Int32Array::from_slice(&[1, 2, 3, 4])),
+        ],
+        Some(Bitmap::from([true, true, false, true])),
+    );
+    round_trip_nested(vec![Arc::new(array)], Version::V1)
+}
+
+#[test]
+fn list_struct() -> Result<()> {
+    // [{..}, None], None, [], [{..}, {..}]
+    let values = struct_();
+    let data_type = DataType::List(Box::new(Field::new(
+        "item",
+        values.data_type().clone(),
+        true,
+    )));
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from([0, 2, 2, 2, 4]),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    round_trip_nested(vec![Arc::new(array)], Version::V2)
+}
+
+#[test]
+fn struct_list() -> Result<()> {
+    let list = pyarrow_nested_nullable(0);
+    let other = Int32Array::from(&[
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(6),
+        Some(7),
+        None,
+    ]);
+    let data_type = DataType::Struct(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("other", other.data_type().clone(), true),
+    ]);
+    let array = StructArray::from_data(
+        data_type,
+        vec![list.into(), Arc::new(other)],
+        Some(Bitmap::from([
+            true, true, false, true, true, true, true, false,
+        ])),
+    );
+    round_trip_nested(vec![Arc::new(array)], Version::V1)
+}
+
+/// A `struct<list<int32>, int32>` whose list column has more values than rows.
+fn struct_list_primitive() -> StructArray {
+    // {l: [1, 2, 3], p: 1}, {l: [4], p: 2}
+    let list = ListArray::<i32>::from_data(
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        Buffer::from([0, 3, 4]),
+        Arc::new(Int32Array::from_slice(&[1, 2, 3, 4])),
+        None,
+    );
+    let data_type = DataType::Struct(vec![
+        Field::new("l", list.data_type().clone(), true),
+        Field::new("p", DataType::Int32, true),
+    ]);
+    StructArray::from_data(
+        data_type,
+        vec![Arc::new(list), Arc::new(Int32Array::from_slice(&[1, 2]))],
+        None,
+    )
+}
+
+#[test]
+fn struct_list_primitive_v1() -> Result<()> {
+    round_trip_nested(vec![Arc::new(struct_list_primitive())], Version::V1)
+}
+
+#[test]
+fn struct_list_primitive_v2() -> Result<()> {
+    round_trip_nested(vec![Arc::new(struct_list_primitive())], Version::V2)
+}
+
+#[test]
+fn struct_list_primitive_rows() -> Result<()> {
+    let array: Arc<dyn Array> = Arc::new(struct_list_primitive());
+    let batch = RecordBatch::try_from_iter(vec![("a0", array)])?;
+    let sizes = SizeOptions {
+        max_page_size: 1,
+        ..Default::default()
+    };
+    let data = write_sized(vec![batch], vec![Encoding::Plain], sizes)?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    assert_eq!(metadata.num_rows, 2);
+    assert_eq!(metadata.row_groups[0].num_rows(), 2);
+    // the list column has one value per item, the primitive column one per row
+    let num_values = metadata.row_groups[0]
+        .columns()
+        .iter()
+        .map(|column| column.num_values())
+        .collect::<Vec<_>>();
+    assert_eq!(num_values, vec![4, 2]);
+    Ok(())
+}
+
+#[test]
+fn struct_array_to_page() -> Result<()> {
+    let array = struct_list_primitive();
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = parquet_schema.columns()[0].clone();
+    assert!(array_to_page(&array, descriptor, options, Encoding::Plain).is_err());
+    Ok(())
+}
+
+/// Writes `batches` with `sizes` and returns the written file.
+fn write_sized(
+    batches: Vec<RecordBatch>,
+    encodings: Vec<Encoding>,
+    sizes: SizeOptions,
+) -> Result<Vec<u8>> {
+    write_sized_with(batches, encodings, sizes, &FileOptions::default())
+}
+
+/// Writes `batches` with `sizes` and `file_options` and returns the written file.
+fn write_sized_with(
+    batches: Vec<RecordBatch>,
+    encodings: Vec<Encoding>,
+    sizes: SizeOptions,
+    file_options: &FileOptions,
+) -> Result<Vec<u8>> {
+    let schema = batches[0].schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let mut row_groups =
+        RowGroupIterator::try_new(batches.into_iter().map(Ok), &schema, options, encodings)?;
+    row_groups.set_sizes(sizes)?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file_with(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+        file_options,
+    )?;
+    Ok(writer.into_inner())
+}
+
+fn sized_batch(length: usize) -> Result<RecordBatch> {
+    let int64 = (0..length as i64)
+        .map(|x| if x % 7 == 0 { None } else { Some(x) })
+        .collect::<Vec<_>>();
+    let utf8 = (0..length)
+        .map(|x| Some(format!("{}", x % 5)))
+        .collect::<Vec<_>>();
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int64Array::from(&int64)),
+        Arc::new(Utf8Array::<i32>::from(&utf8)),
+    ];
+    RecordBatch::try_from_iter(vec![("a0", columns[0].clone()), ("a1", columns[1].clone())])
+}
+
+fn slice_batch(batch: &RecordBatch, offset: usize, length: usize) -> RecordBatch {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|array| array.slice(offset, length).into())
+        .collect();
+    RecordBatch::try_new(batch.schema().clone(), columns).unwrap()
+}
+
+/// The number of data pages of each column of each row group.
+fn num_pages(data: &[u8]) -> Result<Vec<Vec<usize>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    metadata
+        .row_groups
+        .iter()
+        .map(|group| {
+            group
+                .columns()
+                .iter()
+                .map(|column| Ok(get_page_iterator(column, &mut reader, None, vec![])?.count()))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn row_group_per_batch() -> Result<()> {
+    let batches = vec![sized_batch(10)?, sized_batch(3)?];
+    let schema = batches[0].schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let row_groups = RowGroupIterator::try_new(
+        batches.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+    assert_eq!(num_pages(&data)?, vec![vec![1, 1], vec![1, 1]]);
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, batches);
+    Ok(())
+}
+
+#[test]
+fn row_group_sizes_zero() -> Result<()> {
+    let batch = sized_batch(10)?;
+    let schema = batch.schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+    assert!(row_groups
+        .set_sizes(SizeOptions {
+            max_row_group_rows: 0,
+            ..Default::default()
+        })
+        .is_err());
+    assert!(row_groups
+        .set_sizes(SizeOptions {
+            max_row_group_size: 0,
+            ..Default::default()
+        })
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn row_groups_split_by_rows() -> Result<()> {
+    let batch = sized_batch(10)?;
+    let sizes = SizeOptions {
+        max_row_group_rows: 4,
+        ..Default::default()
+    };
+    let data = write_sized(vec![batch.clone()], vec![Encoding::Plain; 2], sizes)?;
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = vec![
+        slice_batch(&batch, 0, 4),
+        slice_batch(&batch, 4, 4),
+        slice_batch(&batch, 8, 2),
+    ];
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn row_groups_merge_batches() -> Result<()> {
+    let batch = sized_batch(15)?;
+    let batches = (0..5).map(|i| slice_batch(&batch, i * 3, 3)).collect();
+    let sizes = SizeOptions {
+        max_row_group_rows: 10,
+        ..Default::default()
+    };
+    let data = write_sized(batches, vec![Encoding::RleDictionary; 2], sizes)?;
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![slice_batch(&batch, 0, 10), slice_batch(&batch, 10, 5)]
+    );
+    Ok(())
+}
+
+#[test]
+fn row_groups_split_by_size() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let size = 100 * 8 + 100 * (4 + 1);
+    let sizes = SizeOptions {
+        max_row_group_size: size / 4,
+        ..Default::default()
+    };
+    let data = write_sized(vec![batch.clone()], vec![Encoding::Plain; 2], sizes)?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let rows = metadata
+        .row_groups
+        .iter()
+        .map(|group| group.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![25, 25, 25, 25]);
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = (0..4)
+        .map(|i| slice_batch(&batch, i * 25, 25))
+        .collect::<Vec<_>>();
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn pages_split_by_size() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized(
+        vec![batch.clone()],
+        vec![Encoding::Plain, Encoding::RleDictionary],
+        sizes,
+    )?;
+
+    // 100 * 8 bytes in pages of 10 values; 100 4-byte keys in pages of 20 keys
+    assert_eq!(num_pages(&data)?, vec![vec![10, 5]]);
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn pages_split_nested() -> Result<()> {
+    let array: Arc<dyn Array> = pyarrow_nested_nullable(0).into();
+    let batch = RecordBatch::try_from_iter(vec![("a0", array)])?;
+    let sizes = SizeOptions {
+        max_page_size: 1,
+        ..Default::default()
+    };
+    let data = write_sized(vec![batch.clone()], vec![Encoding::Plain], sizes)?;
+
+    // one page per top-level slot
+    assert_eq!(num_pages(&data)?, vec![vec![batch.num_rows()]]);
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(())
+}
+
+fn indexed() -> FileOptions {
+    FileOptions {
+        page_index: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn page_index() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized_with(
+        vec![batch],
+        vec![Encoding::Plain, Encoding::RleDictionary],
+        sizes,
+        &indexed(),
+    )?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+
+    let pages = read_page_index(&mut reader, row_group, 0)?.unwrap();
+    assert_eq!(pages.len(), 10);
+    assert_eq!(
+        pages[0].offset as i64,
+        row_group.column(0).data_page_offset()
+    );
+    pages.iter().enumerate().for_each(|(index, page)| {
+        assert_eq!(page.first_row, index * 10);
+        assert_eq!(page.num_rows, 10);
+    });
+    pages.windows(2).for_each(|pages| {
+        assert_eq!(pages[0].offset + pages[0].length as u64, pages[1].offset);
+    });
+
+    // values 10..20, 14 is null
+    let statistics = pages[1].statistics.as_ref().unwrap();
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i64>>()
+        .unwrap();
+    assert_eq!(statistics.min_value, Some(10));
+    assert_eq!(statistics.max_value, Some(19));
+    assert_eq!(statistics.null_count, Some(1));
+
+    // the dictionary page is not part of the index
+    let pages = read_page_index(&mut reader, row_group, 1)?.unwrap();
+    assert_eq!(pages.len(), 5);
+    assert_eq!(pages[4].first_row, 80);
+    assert_eq!(pages[4].num_rows, 20);
+    Ok(())
+}
+
+#[test]
+fn page_index_offsets() -> Result<()> {
+    use std::io::{Seek, SeekFrom};
+
+    use arrow2::datatypes::DataType;
+    use arrow2::io::parquet::read::{decompress, streaming_iterator, PageIterator};
+
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized_with(
+        vec![batch.clone()],
+        vec![Encoding::Plain; 2],
+        sizes,
+        &indexed(),
+    )?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+    let column = row_group.column(0);
+
+    // each page is read on its own, from the location declared by the offset index
+    let pages = read_page_index(&mut reader, row_group, 0)?.unwrap();
+    for page in pages {
+        reader.seek(SeekFrom::Start(page.offset))?;
+        let compressed = PageIterator::new(
+            &mut reader,
+            page.num_rows as i64,
+            column.compression(),
+            column.descriptor().clone(),
+            Arc::new(|_, _| true),
+            vec![],
+        )
+        .next()
+        .unwrap()?;
+        assert_eq!(reader.position(), page.offset + page.length as u64);
+
+        let page_data = decompress(compressed, &mut vec![])?;
+        let mut iter = streaming_iterator::convert(std::iter::once(Ok(page_data)));
+        let array = page_iter_to_array(&mut iter, column, DataType::Int64)?;
+        let expected = batch.column(0).slice(page.first_row, page.num_rows);
+        assert_eq!(array.as_ref(), expected.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn page_index_selection() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized_with(vec![batch], vec![Encoding::Plain; 2], sizes, &indexed())?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let pages = read_page_index(&mut reader, &metadata.row_groups[0], 0)?.unwrap();
+
+    let selected = select_pages_by_rows(&pages, &[(15, 10), (99, 1)]);
+    assert_eq!(selected, vec![1, 2, 9]);
+    assert_eq!(selected_rows(&pages, &selected), vec![(10, 20), (90, 10)]);
+
+    let selected = select_pages_by_statistics(&pages, |statistics| {
+        let statistics = statistics
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<i64>>()
+            .unwrap();
+        statistics.max_value.map(|max| max >= 85).unwrap_or(false)
+    });
+    assert_eq!(selected, vec![8, 9]);
+    assert_eq!(selected_rows(&pages, &selected), vec![(80, 20)]);
+    Ok(())
+}
+
+#[test]
+fn page_index_single_page() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let data = write_sized_with(
+        vec![batch],
+        vec![Encoding::Plain; 2],
+        Default::default(),
+        &indexed(),
+    )?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert!(read_page_index(&mut reader, &metadata.row_groups[0], 0)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn page_index_out_of_bounds() -> Result<()> {
+    use arrow2::io::parquet::read::RowGroupMetaData;
+
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized_with(vec![batch], vec![Encoding::Plain; 2], sizes, &indexed())?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let corrupt = |length: i32| {
+        let mut row_group = metadata.row_groups[0].clone().into_thrift();
+        row_group.columns[0].offset_index_length = Some(length);
+        RowGroupMetaData::try_from_thrift(metadata.schema(), row_group)
+    };
+    for length in &[-1, i32::MAX] {
+        let row_group = corrupt(*length)?;
+        assert!(read_page_index(&mut reader, &row_group, 0).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn page_index_opt_in() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized(vec![batch], vec![Encoding::Plain; 2], sizes)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert!(read_page_index(&mut reader, &metadata.row_groups[0], 0)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn page_index_stream() -> Result<()> {
+    use arrow2::io::parquet::write::stream::write_stream_stream_with;
+    use futures::executor::block_on;
+
+    let batch = sized_batch(100)?;
+    let schema = batch.schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+    row_groups.set_sizes(SizeOptions {
+        max_page_size: 80,
+        ..Default::default()
+    })?;
+
+    let mut writer = futures::io::Cursor::new(vec![]);
+    let length = block_on(write_stream_stream_with(
+        &mut writer,
+        futures::stream::iter(row_groups),
+        &schema,
+        parquet_schema,
+        options,
+        None,
+        &indexed(),
+    ))?;
+    let data = writer.into_inner();
+    assert_eq!(length as usize, data.len());
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let pages = read_page_index(&mut reader, &metadata.row_groups[0], 0)?.unwrap();
+    assert_eq!(pages.len(), 10);
+    assert_eq!(pages[9].first_row, 90);
+
+    let reader = RecordReader::try_new(reader, None, None, None, None)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn bloom_filter() -> Result<()> {
+    use arrow2::io::parquet::bloom_filter::BloomFilterOptions;
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let batch = sized_batch(100)?;
+    let schema = batch.schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+    row_groups.set_sizes(SizeOptions {
+        max_row_group_rows: 50,
+        ..Default::default()
+    })?;
+    let filter_options = BloomFilterOptions { ndv: 50, fpp: 0.01 };
+    row_groups.set_bloom_filters(vec![Some(filter_options), None]);
+    let bloom_filters = row_groups.bloom_filters();
+
+    let mut writer = Cursor::new(vec![]);
+    write_file_with_bloom_filters(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+        &bloom_filters,
+    )?;
+    let data = writer.into_inner();
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let filter = read_bloom_filter(&mut reader, &metadata.row_groups[0], 0)?.unwrap();
+    assert!((1..50i64)
+        .filter(|x| x % 7 != 0)
+        .all(|x| filter.contains_scalar(&PrimitiveScalar::from(Some(x)))));
+    assert!(read_bloom_filter(&mut reader, &metadata.row_groups[0], 1)?.is_none());
+
+    // 75 is only in the second row group
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.add_value_filter(0, Arc::new(PrimitiveScalar::<i64>::from(Some(75))));
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![slice_batch(&batch, 50, 50)]);
+
+    // fields without bloom filter do not skip row groups
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.add_value_filter(1, Arc::new(Utf8Scalar::<i32>::from(Some("a"))));
+    assert_eq!(reader.count(), 2);
+    Ok(())
+}
+
+#[test]
+fn bloom_filter_out_of_bounds() -> Result<()> {
+    use arrow2::io::parquet::bloom_filter::BloomFilterOptions;
+    use arrow2::io::parquet::read::RowGroupMetaData;
+
+    let batch = sized_batch(100)?;
+    let schema = batch.schema().as_ref().clone();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+    let filter_options = BloomFilterOptions { ndv: 50, fpp: 0.01 };
+    row_groups.set_bloom_filters(vec![Some(filter_options), None]);
+    let bloom_filters = row_groups.bloom_filters();
+
+    let mut writer = Cursor::new(vec![]);
+    write_file_with_bloom_filters(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+        &bloom_filters,
+    )?;
+    let mut data = writer.into_inner();
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+
+    let corrupt = |offset: i64| {
+        let mut row_group = metadata.row_groups[0].clone().into_thrift();
+        row_group.columns[0]
+            .meta_data
+            .as_mut()
+            .unwrap()
+            .bloom_filter_offset = Some(offset);
+        RowGroupMetaData::try_from_thrift(metadata.schema(), row_group)
+    };
+    for offset in &[-1, data.len() as i64] {
+        let row_group = corrupt(*offset)?;
+        assert!(read_bloom_filter(&mut Cursor::new(&data), &row_group, 0).is_err());
+    }
+
+    // a header declaring `i32::MAX` bytes, appended to the file
+    let offset = metadata.row_groups[0]
+        .column(0)
+        .clone()
+        .into_thrift()
+        .meta_data
+        .unwrap()
+        .bloom_filter_offset
+        .unwrap() as usize;
+    // the header starts with `num_bytes`: a field header and a zigzag varint
+    let varint_length = data[offset + 1..]
+        .iter()
+        .position(|byte| byte & 0x80 == 0)
+        .unwrap()
+        + 1;
+    let mut header = vec![data[offset], 0xfe, 0xff, 0xff, 0xff, 0x0f];
+    header.extend_from_slice(&data[offset + 1 + varint_length..offset + 64]);
+    let corrupt_offset = data.len();
+    data.extend_from_slice(&header);
+    let row_group = corrupt(corrupt_offset as i64)?;
+    assert!(read_bloom_filter(&mut Cursor::new(&data), &row_group, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn parallel_row_groups() -> Result<()> {
+    let batch = sized_batch(100)?;
+    let nested: Arc<dyn Array> = pyarrow_nested_nullable(0).into();
+    let nested = RecordBatch::try_from_iter(vec![("a0", nested)])?;
+    let sizes = SizeOptions {
+        max_row_group_rows: 40,
+        max_page_size: 80,
+        ..Default::default()
+    };
+
+    for (batch, encodings) in [
+        (batch, vec![Encoding::Plain, Encoding::RleDictionary]),
+        (nested, vec![Encoding::Plain]),
+    ] {
+        let data = write_sized(vec![batch.clone()], encodings, sizes)?;
+        let mut reader = Cursor::new(data);
+        let metadata = read_metadata(&mut reader)?;
+
+        let mut batches = vec![];
+        for row_group in 0..metadata.row_groups.len() {
+            let compressed =
+                read_compressed_row_group(&mut reader, &metadata, row_group, None, None)?;
+            batches.push(compressed.decode(|task| {
+                std::thread::spawn(task);
+            })?);
+        }
+        let expected =
+            RecordReader::try_new(reader, None, None, None, None)?.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn parallel_row_group_projection() -> Result<()> {
+    let batch = sized_batch(10)?;
+    let data = write_sized(
+        vec![batch.clone()],
+        vec![Encoding::Plain; 2],
+        Default::default(),
+    )?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let compressed = read_compressed_row_group(&mut reader, &metadata, 0, Some(&[1]), None)?;
+    assert_eq!(compressed.schema().fields().len(), 1);
+    let result = compressed.decode(|task| task())?;
+    assert_eq!(result.column(0), batch.column(1));
+
+    assert!(read_compressed_row_group(&mut reader, &metadata, 1, None, None).is_err());
+    assert!(read_compressed_row_group(&mut reader, &metadata, 0, Some(&[2]), None).is_err());
+    Ok(())
+}
+
+#[test]
+fn async_record_reader() -> Result<()> {
+    use futures::{executor::block_on, TryStreamExt};
+
+    let batch = sized_batch(100)?;
+    let sizes = SizeOptions {
+        max_row_group_rows: 40,
+        max_page_size: 80,
+        ..Default::default()
+    };
+    let data = write_sized(
+        vec![batch.clone()],
+        vec![Encoding::Plain, Encoding::RleDictionary],
+        sizes,
+    )?;
+
+    // all fields and rows
+    let reader = block_on(AsyncRecordReader::try_new(
+        futures::io::Cursor::new(&data),
+        None,
+        None,
+        None,
+        None,
+    ))?;
+    let batches = block_on(reader.into_stream().try_collect::<Vec<_>>())?;
+    let expected = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, expected);
+
+    // projection, limit, row group filter and batch size
+    let mut reader = block_on(AsyncRecordReader::try_new(
+        futures::io::Cursor::new(&data),
+        Some(vec![1]),
+        Some(50),
+        Some(Arc::new(|group, _| group != 0)),
+        None,
+    ))?;
+    reader.set_batch_size(15);
+    let batches = block_on(reader.into_stream().try_collect::<Vec<_>>())?;
+    assert_eq!(
+        batches
+            .iter()
+            .map(|batch| batch.num_rows())
+            .collect::<Vec<_>>(),
+        vec![15, 15, 10, 10]
+    );
+    let columns = batches
+        .iter()
+        .map(|batch| batch.column(0).as_ref())
+        .collect::<Vec<_>>();
+    let result = arrow2::compute::concat::concatenate(&columns)?;
+    assert_eq!(result.as_ref(), batch.column(1).slice(40, 50).as_ref());
+    Ok(())
+}
+
+/// Writes `columns` with all legacy representations and returns the written batch and file.
+fn write_legacy(columns: Vec<Arc<dyn Array>>) -> Result<(RecordBatch, Vec<u8>)> {
+    let fields = columns
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(&format!("a{}", i), array.data_type().clone(), true))
+        .collect();
+    let schema = Schema::new(fields);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let encodings = vec![Encoding::RleDictionary; schema.fields().len()];
+    let row_groups = RowGroupIterator::try_new_with_compatibility(
+        vec![Ok(batch.clone())].into_iter(),
+        &schema,
+        options,
+        encodings,
+        CompatibilityOptions::legacy(),
+    )?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok((batch, writer.into_inner()))
+}
+
+fn read_legacy(data: Vec<u8>) -> Result<(Vec<ParquetType>, Vec<RecordBatch>)> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let types = metadata.schema().fields().to_vec();
+    let batches =
+        RecordReader::try_new(reader, None, None, None, None)?.collect::<Result<Vec<_>>>()?;
+    Ok((types, batches))
+}
+
+#[test]
+fn legacy_flat() -> Result<()> {
+    use arrow2::io::parquet::read::{PhysicalType, PrimitiveConvertedType};
+
+    let timestamps = [Some(-1), None, Some(1_628_000_000_123), Some(0)];
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int64Array::from(&timestamps).to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("+00:00".to_string()),
+        ))),
+        Arc::new(
+            Int64Array::from(&[Some(-1), None, Some(1_628_000_000), Some(0)])
+                .to(DataType::Timestamp(TimeUnit::Second, None)),
+        ),
+        Arc::new(Int64Array::from(&timestamps).to(DataType::Timestamp(TimeUnit::Nanosecond, None))),
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("a"),
+            None,
+            Some("b"),
+            Some("a"),
+        ])),
+        Arc::new(Int32Array::from(&[Some(1), None, Some(3), Some(-4)]).to(DataType::Date32)),
+        Arc::new(
+            Int32Array::from(&[Some(1), None, Some(3), Some(-4)])
+                .to(DataType::Time32(TimeUnit::Millisecond)),
+        ),
+        Arc::new(
+            Int64Array::from(&[Some(1), None, Some(3), Some(-4)])
+                .to(DataType::Time64(TimeUnit::Microsecond)),
+        ),
+    ];
+    let (batch, data) = write_legacy(columns)?;
+    let (types, batches) = read_legacy(data)?;
+    assert_eq!(batches, vec![batch]);
+
+    let expected = [
+        (PhysicalType::Int96, None),
+        (PhysicalType::Int96, None),
+        (PhysicalType::Int96, None),
+        (PhysicalType::ByteArray, Some(PrimitiveConvertedType::Utf8)),
+        (PhysicalType::Int32, Some(PrimitiveConvertedType::Date)),
+        (
+            PhysicalType::Int32,
+            Some(PrimitiveConvertedType::TimeMillis),
+        ),
+        (
+            PhysicalType::Int64,
+            Some(PrimitiveConvertedType::TimeMicros),
+        ),
+    ];
+    for (type_, expected) in types.iter().zip(expected.iter()) {
+        match type_ {
+            ParquetType::PrimitiveType {
+                physical_type,
+                converted_type,
+                logical_type,
+                ..
+            } => {
+                assert_eq!(&(*physical_type, converted_type.clone()), expected);
+                assert_eq!(logical_type, &None);
+            }
+            _ => panic!(),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn legacy_int96_out_of_range() -> Result<()> {
+    // the julian day of `i64::MAX` seconds does not fit in INT96 timestamps
+    let array = Int64Array::from(&[Some(0), Some(i64::MAX)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(write_legacy(vec![Arc::new(array)]).is_err());
+
+    // the values of null slots are not converted
+    let array = Int64Array::from_data(
+        DataType::Timestamp(TimeUnit::Second, None),
+        vec![0, i64::MAX].into(),
+        Some(vec![true, false].into()),
+    );
+    let (batch, data) = write_legacy(vec![Arc::new(array)])?;
+    let (_, batches) = read_legacy(data)?;
+    assert_eq!(batches[0].column(0).null_count(), 1);
+    assert_eq!(batches[0].num_rows(), batch.num_rows());
+    Ok(())
+}
+
+#[test]
+fn legacy_int96_dictionary() -> Result<()> {
+    let values = Int64Array::from_slice(&[1_628_000_000_123, -1])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let keys = Int32Array::from(&[Some(1), None, Some(0), Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let (_, data) = write_legacy(vec![Arc::new(array)])?;
+
+    // dictionary-encoded INT96 columns are not read, only their types
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    match &metadata.schema().fields()[0] {
+        ParquetType::PrimitiveType { physical_type, .. } => {
+            assert_eq!(
+                physical_type,
+                &arrow2::io::parquet::read::PhysicalType::Int96
+            )
+        }
+        _ => panic!(),
+    }
+    assert_eq!(metadata.row_groups[0].column(0).num_values(), 4);
+    Ok(())
+}
+
+#[test]
+fn legacy_converted_timestamps() -> Result<()> {
+    use arrow2::io::parquet::read::PrimitiveConvertedType;
+
+    let field = Field::new("a", DataType::Timestamp(TimeUnit::Microsecond, None), true);
+    let compatibility = CompatibilityOptions {
+        legacy_converted_types: true,
+        ..Default::default()
+    };
+    match to_parquet_type_with(&field, compatibility)? {
+        ParquetType::PrimitiveType {
+            converted_type,
+            logical_type,
+            ..
+        } => {
+            assert_eq!(
+                converted_type,
+                Some(PrimitiveConvertedType::TimestampMicros)
+            );
+            assert_eq!(logical_type, None);
+        }
+        _ => panic!(),
+    }
+    Ok(())
+}
+
+fn legacy_list(values: Arc<dyn Array>) -> Result<()> {
+    use parquet2::schema::{types::GroupConvertedType, Repetition};
+
+    // [a, b], None, [], [c, d]
+    let data_type = DataType::List(Box::new(Field::new(
+        "item",
+        values.data_type().clone(),
+        true,
+    )));
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from([0, 2, 2, 2, 4]),
+        values,
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    let (batch, data) = write_legacy(vec![Arc::new(array)])?;
+    let (types, batches) = read_legacy(data)?;
+    assert_eq!(batches, vec![batch]);
+
+    match &types[0] {
+        ParquetType::GroupType {
+            converted_type,
+            logical_type,
+            fields,
+            ..
+        } => {
+            assert_eq!(converted_type, &Some(GroupConvertedType::List));
+            assert_eq!(logical_type, &None);
+            assert_eq!(fields[0].name(), "array");
+            assert_eq!(
+                fields[0].get_basic_info().repetition(),
+                &Repetition::Repeated
+            );
+        }
+        _ => panic!(),
+    }
+    Ok(())
+}
+
+#[test]
+fn legacy_list_int64() -> Result<()> {
+    legacy_list(Arc::new(Int64Array::from_slice(&[1, 2, 3, 4])))
+}
+
+#[test]
+fn legacy_list_timestamp() -> Result<()> {
+    legacy_list(Arc::new(
+        Int64Array::from_slice(&[1, -2, 3, 4]).to(DataType::Timestamp(TimeUnit::Microsecond, None)),
+    ))
+}
+
+#[test]
+fn legacy_list_struct() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let values = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int64Array::from(&[Some(1), Some(2), None, Some(4)])),
+            Arc::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("b"),
+                Some("c"),
+                None,
+            ])),
+        ],
+        None,
+    );
+    legacy_list(Arc::new(values))
+}
+
+#[test]
+fn legacy_list_null_items() {
+    let values = Arc::new(Int64Array::from(&[Some(1), None, Some(3), Some(4)]));
+    assert!(legacy_list(values).is_err());
+}
@@ -5,6 +5,7 @@ mod buffer;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
+mod tensor;
 
 mod io;
 mod test_util;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::tensor::{SparseTensor, Tensor};
+
+#[test]
+fn strided() -> Result<()> {
+    // the transpose of [[1, 2, 3], [4, 5, 6]]
+    let values = Buffer::from(&[1i32, 2, 3, 4, 5, 6]);
+    let tensor = Tensor::try_new(DataType::Int32, values, vec![3, 2], Some(vec![1, 3]), None)?;
+    assert!(!tensor.is_row_major());
+    assert_eq!(tensor.value(&[0, 1]), 4);
+    assert_eq!(tensor.value(&[2, 0]), 3);
+    assert_eq!(tensor.len(), 6);
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    let values = Buffer::from(&[1i32, 2, 3]);
+    assert!(Tensor::try_new(DataType::Int32, values.clone(), vec![2, 2], None, None).is_err());
+    assert!(Tensor::try_new(DataType::Int64, values.clone(), vec![3], None, None).is_err());
+    assert!(Tensor::try_new(
+        DataType::Int32,
+        values.clone(),
+        vec![3],
+        None,
+        Some(vec!["a".to_string(), "b".to_string()])
+    )
+    .is_err());
+    assert!(SparseTensor::try_new(
+        DataType::Int32,
+        values,
+        Buffer::from(&[0i64, 1, 3]),
+        vec![3],
+        None,
+        true
+    )
+    .is_err());
+}
+
+#[test]
+fn from_fixed_size_list() -> Result<()> {
+    let values = Arc::new(Float32Array::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    let data_type =
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Float32, false)), 2);
+    let array = FixedSizeListArray::from_data(data_type, values, None);
+
+    let tensor = Tensor::<f32>::from_fixed_size_list(&array.slice(1, 2))?;
+    assert_eq!(tensor.shape(), &[2, 2]);
+    assert_eq!(tensor.values().as_slice(), &[3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(tensor.value(&[1, 0]), 5.0);
+
+    // wrong type
+    assert!(Tensor::<f64>::from_fixed_size_list(&array).is_err());
+    // nulls
+    let array = array.with_validity(Some([true, false, true].into()));
+    assert!(Tensor::<f32>::from_fixed_size_list(&array).is_err());
+    Ok(())
+}
+
+#[test]
+fn sparse_from_dense() -> Result<()> {
+    let values = Buffer::from(&[0i64, 7, 0, 0, 0, 0, 8, 0]);
+    let names = Some(vec!["x".to_string(), "y".to_string(), "z".to_string()]);
+    let tensor = Tensor::try_new(DataType::Int64, values, vec![2, 2, 2], None, names)?;
+
+    let sparse = SparseTensor::from_dense(&tensor);
+    assert_eq!(sparse.values().as_slice(), &[7, 8]);
+    assert_eq!(sparse.indices().as_slice(), &[0, 0, 1, 1, 1, 0]);
+    assert!(sparse.is_canonical());
+    assert_eq!(sparse.to_dense(), tensor);
+    Ok(())
+}